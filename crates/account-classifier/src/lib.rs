@@ -0,0 +1,386 @@
+use pyo3::{exceptions::PyValueError, prelude::*, types::PyBytes};
+use solana_sdk::{
+    address_lookup_table, bpf_loader_upgradeable, pubkey, pubkey::Pubkey as PubkeyOriginal, stake,
+    system_program, vote,
+};
+use solders_anchor_idl::{idl::sighash, Idl};
+use solders_pubkey::Pubkey;
+use solders_rpc_filter::{Memcmp, MemcmpEncodedBytes, RpcFilterType};
+
+const TOKEN_PROGRAM_ID: PubkeyOriginal = pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+const TOKEN_2022_PROGRAM_ID: PubkeyOriginal =
+    pubkey!("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb");
+/// Token-2022 accounts with extensions store their type at this offset.
+const TOKEN_2022_ACCOUNT_TYPE_OFFSET: usize = 165;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Rule {
+    name: String,
+    owner: Option<PubkeyOriginal>,
+    offset: usize,
+    tag: Vec<u8>,
+    data_size: Option<usize>,
+}
+
+impl Rule {
+    fn tagged(name: &str, owner: PubkeyOriginal, offset: usize, tag: &[u8]) -> Self {
+        Self {
+            name: name.to_owned(),
+            owner: Some(owner),
+            offset,
+            tag: tag.to_vec(),
+            data_size: None,
+        }
+    }
+
+    fn sized(name: &str, owner: PubkeyOriginal, data_size: usize) -> Self {
+        Self {
+            name: name.to_owned(),
+            owner: Some(owner),
+            offset: 0,
+            tag: vec![],
+            data_size: Some(data_size),
+        }
+    }
+
+    fn matches(&self, data: &[u8], owner: Option<&PubkeyOriginal>) -> bool {
+        let owner_ok = match &self.owner {
+            Some(expected) => owner == Some(expected),
+            None => true,
+        };
+        let size_ok = self.data_size.map_or(true, |size| data.len() == size);
+        let tag_ok = data
+            .get(self.offset..)
+            .map_or(false, |rest| rest.starts_with(&self.tag));
+        owner_ok && size_ok && tag_ok
+    }
+
+    fn memcmp(&self) -> Option<Memcmp> {
+        (!self.tag.is_empty()).then(|| {
+            Memcmp::new(
+                self.offset,
+                MemcmpEncodedBytes::Bytes(self.tag.clone()),
+                None,
+            )
+        })
+    }
+}
+
+fn u32_tag(n: u32) -> [u8; 4] {
+    n.to_le_bytes()
+}
+
+fn native_rules() -> Vec<Rule> {
+    let mut rules = vec![];
+    for (i, variant) in ["Uninitialized", "Initialized", "Stake", "RewardsPool"]
+        .iter()
+        .enumerate()
+    {
+        rules.push(Rule::tagged(
+            &format!("Stake::{variant}"),
+            stake::program::ID,
+            0,
+            &u32_tag(i as u32),
+        ));
+    }
+    for (i, variant) in ["V0_23_5", "V1_14_11", "Current"].iter().enumerate() {
+        rules.push(Rule::tagged(
+            &format!("Vote::{variant}"),
+            vote::program::ID,
+            0,
+            &u32_tag(i as u32),
+        ));
+    }
+    for (i, variant) in ["Uninitialized", "Buffer", "Program", "ProgramData"]
+        .iter()
+        .enumerate()
+    {
+        rules.push(Rule::tagged(
+            &format!("UpgradeableLoader::{variant}"),
+            bpf_loader_upgradeable::ID,
+            0,
+            &u32_tag(i as u32),
+        ));
+    }
+    for (i, variant) in ["Uninitialized", "LookupTable"].iter().enumerate() {
+        rules.push(Rule::tagged(
+            &format!("AddressLookupTable::{variant}"),
+            address_lookup_table::program::ID,
+            0,
+            &u32_tag(i as u32),
+        ));
+    }
+    // Nonce accounts: a u32 version followed by a u32 state, 1 meaning initialized.
+    let mut nonce = Rule::tagged("System::Nonce", system_program::ID, 4, &u32_tag(1));
+    nonce.data_size = Some(80);
+    rules.push(nonce);
+    let token_programs = [
+        ("Token", TOKEN_PROGRAM_ID),
+        ("Token2022", TOKEN_2022_PROGRAM_ID),
+    ];
+    // A multisig is long enough to reach the Token-2022 account type offset, so it is
+    // checked first. Token-2022 never gives an account with extensions the multisig size.
+    for (prefix, owner) in token_programs {
+        rules.push(Rule::sized(&format!("{prefix}::Multisig"), owner, 355));
+    }
+    rules.push(Rule::tagged(
+        "Token2022::Mint",
+        TOKEN_2022_PROGRAM_ID,
+        TOKEN_2022_ACCOUNT_TYPE_OFFSET,
+        &[1],
+    ));
+    rules.push(Rule::tagged(
+        "Token2022::Account",
+        TOKEN_2022_PROGRAM_ID,
+        TOKEN_2022_ACCOUNT_TYPE_OFFSET,
+        &[2],
+    ));
+    for (prefix, owner) in token_programs {
+        rules.push(Rule::sized(&format!("{prefix}::Mint"), owner, 82));
+        rules.push(Rule::sized(&format!("{prefix}::Account"), owner, 165));
+    }
+    rules
+}
+
+fn data_and_owner(account: &PyAny) -> PyResult<(Vec<u8>, Option<PubkeyOriginal>)> {
+    if let Ok(data) = account.downcast::<PyBytes>() {
+        return Ok((data.as_bytes().to_vec(), None));
+    }
+    let inner = if account.hasattr("account")? {
+        account.getattr("account")?
+    } else {
+        account
+    };
+    let data: Vec<u8> = inner.getattr("data")?.extract()?;
+    let owner: Pubkey = inner.getattr("owner")?.extract()?;
+    Ok((data, Some(owner.0)))
+}
+
+/// The 8-byte discriminator Anchor prefixes to account data: ``sha256("account:<name>")[:8]``.
+///
+/// Args:
+///     name (str): The account struct name, e.g. ``"Counter"``.
+///
+/// Returns:
+///     bytes: The discriminator.
+///
+#[pyfunction]
+pub fn anchor_account_discriminator<'a>(py: Python<'a>, name: &str) -> &'a PyBytes {
+    PyBytes::new(py, &sighash("account", name))
+}
+
+/// Build a ``Memcmp`` filter that matches accounts whose data starts with a discriminator.
+///
+/// Args:
+///     discriminator (bytes): The expected bytes.
+///     offset (int): Where the discriminator starts in the account data.
+///
+/// Returns:
+///     Memcmp: The filter.
+///
+#[pyfunction]
+#[pyo3(signature = (discriminator, offset=0))]
+pub fn discriminator_memcmp(discriminator: Vec<u8>, offset: usize) -> Memcmp {
+    Memcmp::new(offset, MemcmpEncodedBytes::Bytes(discriminator), None)
+}
+
+/// Classifies accounts by the discriminator or type tag at the start of their data.
+///
+/// Rules are checked in registration order and the first match wins.
+/// Native program accounts are named ``"<Program>::<Type>"``, e.g. ``"Token::Mint"``,
+/// ``"Stake::Stake"`` or ``"UpgradeableLoader::ProgramData"``, and only match when the
+/// owner is known.
+///
+/// Args:
+///     include_native (bool): Whether to start with rules for the native and SPL Token programs.
+///
+/// Example:
+///     >>> from solders.account_classifier import AccountClassifier, anchor_account_discriminator
+///     >>> classifier = AccountClassifier()
+///     >>> classifier.register_anchor("Counter")
+///     >>> classifier.classify(anchor_account_discriminator("Counter") + bytes(8))
+///     'Counter'
+///     >>> classifier.memcmp("Counter").offset
+///     0
+///
+#[pyclass(module = "solders.account_classifier", subclass)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AccountClassifier {
+    rules: Vec<Rule>,
+}
+
+impl AccountClassifier {
+    fn rule(&self, name: &str) -> PyResult<&Rule> {
+        self.rules
+            .iter()
+            .find(|r| r.name == name)
+            .ok_or_else(|| PyValueError::new_err(format!("Unknown account type {name}")))
+    }
+}
+
+#[pymethods]
+impl AccountClassifier {
+    #[new]
+    #[pyo3(signature = (include_native=true))]
+    pub fn new(include_native: bool) -> Self {
+        let rules = if include_native {
+            native_rules()
+        } else {
+            vec![]
+        };
+        Self { rules }
+    }
+
+    /// Register an account type by discriminator.
+    ///
+    /// Args:
+    ///     name (str): The account type name.
+    ///     discriminator (bytes): The bytes identifying the account type.
+    ///     owner (Optional[Pubkey]): Only match accounts owned by this program.
+    ///     offset (int): Where the discriminator starts in the account data.
+    ///     data_size (Optional[int]): Only match accounts with exactly this much data.
+    ///
+    #[pyo3(signature = (name, discriminator, owner=None, offset=0, data_size=None))]
+    pub fn register(
+        &mut self,
+        name: String,
+        discriminator: Vec<u8>,
+        owner: Option<Pubkey>,
+        offset: usize,
+        data_size: Option<usize>,
+    ) {
+        self.rules.push(Rule {
+            name,
+            owner: owner.map(|o| o.0),
+            offset,
+            tag: discriminator,
+            data_size,
+        });
+    }
+
+    /// Register an Anchor account type by name, using ``sha256("account:<name>")[:8]``.
+    ///
+    /// Args:
+    ///     name (str): The account struct name.
+    ///     owner (Optional[Pubkey]): Only match accounts owned by this program.
+    ///
+    #[pyo3(signature = (name, owner=None))]
+    pub fn register_anchor(&mut self, name: String, owner: Option<Pubkey>) {
+        let discriminator = sighash("account", &name);
+        self.register(name, discriminator, owner, 0, None);
+    }
+
+    /// Register all the account types in an Anchor IDL.
+    ///
+    /// Args:
+    ///     idl (Idl): The IDL.
+    ///     owner (Optional[Pubkey]): Only match accounts owned by this program.
+    ///         Defaults to the IDL address.
+    ///
+    #[pyo3(signature = (idl, owner=None))]
+    pub fn add_idl(&mut self, idl: &Idl, owner: Option<Pubkey>) {
+        let owner = owner.map(|o| o.0).or(idl.0.address);
+        for account in &idl.0.accounts {
+            self.rules.push(Rule {
+                name: account.name.clone(),
+                owner,
+                offset: 0,
+                tag: account.discriminator.clone(),
+                data_size: None,
+            });
+        }
+    }
+
+    /// The registered account type names, in registration order.
+    ///
+    /// Returns:
+    ///     List[str]: The names.
+    ///
+    pub fn names(&self) -> Vec<String> {
+        let mut names: Vec<String> = vec![];
+        for rule in &self.rules {
+            if !names.contains(&rule.name) {
+                names.push(rule.name.clone());
+            }
+        }
+        names
+    }
+
+    /// Classify an account.
+    ///
+    /// Args:
+    ///     account (Union[Account, RpcKeyedAccount, bytes]): The account, or just its data.
+    ///     owner (Optional[Pubkey]): The owning program. Overrides the account's owner,
+    ///         and is needed to match owner-scoped types when only data is given.
+    ///
+    /// Returns:
+    ///     Optional[str]: The account type, or ``None`` if nothing matches.
+    ///
+    #[pyo3(signature = (account, owner=None))]
+    pub fn classify(&self, account: &PyAny, owner: Option<Pubkey>) -> PyResult<Option<String>> {
+        let (data, account_owner) = data_and_owner(account)?;
+        let owner = owner.map(|o| o.0).or(account_owner);
+        Ok(self
+            .rules
+            .iter()
+            .find(|r| r.matches(&data, owner.as_ref()))
+            .map(|r| r.name.clone()))
+    }
+
+    /// Build a ``Memcmp`` filter matching an account type's discriminator.
+    ///
+    /// Args:
+    ///     name (str): The account type name.
+    ///
+    /// Returns:
+    ///     Memcmp: The filter.
+    ///
+    /// Raises:
+    ///     ValueError: If the type is unknown or is only identified by its data size.
+    ///
+    pub fn memcmp(&self, name: &str) -> PyResult<Memcmp> {
+        self.rule(name)?
+            .memcmp()
+            .ok_or_else(|| PyValueError::new_err(format!("{name} is identified by data size only")))
+    }
+
+    /// Build the ``getProgramAccounts`` filters matching an account type.
+    ///
+    /// Args:
+    ///     name (str): The account type name.
+    ///
+    /// Returns:
+    ///     List[Union[int, Memcmp]]: A data size filter, if the type has a fixed size,
+    ///     followed by a ``Memcmp`` filter, if the type has a discriminator.
+    ///
+    /// Raises:
+    ///     ValueError: If the type is unknown.
+    ///
+    pub fn filters(&self, name: &str) -> PyResult<Vec<RpcFilterType>> {
+        let rule = self.rule(name)?;
+        let mut filters = vec![];
+        if let Some(size) = rule.data_size {
+            filters.push(RpcFilterType::DataSize(size as u64));
+        }
+        if let Some(memcmp) = rule.memcmp() {
+            filters.push(RpcFilterType::Memcmp(memcmp));
+        }
+        Ok(filters)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("AccountClassifier(names={:?})", self.names())
+    }
+
+    fn __eq__(&self, other: &Self) -> bool {
+        self == other
+    }
+}
+
+pub fn create_account_classifier_mod(py: Python<'_>) -> PyResult<&PyModule> {
+    let m = PyModule::new(py, "account_classifier")?;
+    m.add_class::<AccountClassifier>()?;
+    m.add_function(wrap_pyfunction!(anchor_account_discriminator, m)?)?;
+    m.add_function(wrap_pyfunction!(discriminator_memcmp, m)?)?;
+    Ok(m)
+}
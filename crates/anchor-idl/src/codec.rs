@@ -0,0 +1,414 @@
+//! Borsh encoding and decoding of Python values, driven by IDL types.
+use pyo3::{
+    exceptions::PyValueError,
+    prelude::*,
+    types::{PyBytes, PyDict, PyList, PyString, PyTuple},
+};
+use solana_sdk::pubkey::Pubkey as PubkeyOriginal;
+use solders_pubkey::Pubkey;
+
+use crate::idl::{IdlEnumVariant, IdlFields, IdlModel, IdlType, IdlTypeDef, IdlTypeDefTy};
+
+/// How deeply defined types may nest, so recursive types can't overflow the stack.
+const MAX_DEPTH: usize = 64;
+
+fn check_depth(def: &IdlTypeDef, depth: usize) -> PyResult<()> {
+    if depth > MAX_DEPTH {
+        return Err(PyValueError::new_err(format!(
+            "{} nests defined types more than {MAX_DEPTH} levels deep",
+            def.name
+        )));
+    }
+    Ok(())
+}
+
+fn encode_error(ty: &IdlType, value: &PyAny, e: impl std::fmt::Display) -> PyErr {
+    PyValueError::new_err(format!("Cannot encode {value} as {ty:?}: {e}"))
+}
+
+fn extract_pubkey(value: &PyAny) -> PyResult<PubkeyOriginal> {
+    if let Ok(p) = value.extract::<Pubkey>() {
+        return Ok(p.0);
+    }
+    let s: &str = value.extract()?;
+    s.parse()
+        .map_err(|e| PyValueError::new_err(format!("Invalid pubkey {s}: {e}")))
+}
+
+fn encode_len(len: usize, out: &mut Vec<u8>) -> PyResult<()> {
+    let len = u32::try_from(len)
+        .map_err(|_| PyValueError::new_err(format!("Length {len} does not fit in a u32")))?;
+    out.extend_from_slice(&len.to_le_bytes());
+    Ok(())
+}
+
+/// Get a struct field from a dict, or from an attribute of any other object.
+fn get_field<'a>(value: &'a PyAny, name: &str) -> PyResult<&'a PyAny> {
+    if let Ok(dict) = value.downcast::<PyDict>() {
+        return dict
+            .get_item(name)?
+            .ok_or_else(|| PyValueError::new_err(format!("Missing field {name} in {value}")));
+    }
+    value.getattr(name)
+}
+
+fn encode_fields(
+    idl: &IdlModel,
+    fields: &IdlFields,
+    value: &PyAny,
+    out: &mut Vec<u8>,
+    depth: usize,
+) -> PyResult<()> {
+    match fields {
+        IdlFields::Named(named) => {
+            for (name, ty) in named {
+                encode(idl, ty, get_field(value, name)?, out, depth)?;
+            }
+        }
+        IdlFields::Tuple(types) => {
+            let items: Vec<&PyAny> = value.iter()?.collect::<PyResult<_>>()?;
+            if items.len() != types.len() {
+                return Err(PyValueError::new_err(format!(
+                    "Expected {} tuple fields, got {}",
+                    types.len(),
+                    items.len()
+                )));
+            }
+            for (ty, item) in types.iter().zip(items) {
+                encode(idl, ty, item, out, depth)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn encode_enum(
+    idl: &IdlModel,
+    def: &IdlTypeDef,
+    variants: &[IdlEnumVariant],
+    value: &PyAny,
+    out: &mut Vec<u8>,
+    depth: usize,
+) -> PyResult<()> {
+    // A unit variant can be given by name, any variant as {name: fields}.
+    let (name, fields_value): (String, Option<&PyAny>) = if let Ok(s) = value.downcast::<PyString>()
+    {
+        (s.to_str()?.to_string(), None)
+    } else if let Ok(dict) = value.downcast::<PyDict>() {
+        match dict.iter().collect::<Vec<_>>().as_slice() {
+            [(k, v)] => (k.extract()?, Some(*v)),
+            _ => {
+                return Err(PyValueError::new_err(format!(
+                    "Expected a dict with a single variant of {}, got {value}",
+                    def.name
+                )))
+            }
+        }
+    } else {
+        return Err(PyValueError::new_err(format!(
+            "Expected a variant of {}, got {value}",
+            def.name
+        )));
+    };
+    let (index, variant) = variants
+        .iter()
+        .enumerate()
+        .find(|(_, v)| v.name == name)
+        .ok_or_else(|| PyValueError::new_err(format!("Unknown variant {name} of {}", def.name)))?;
+    let index = u8::try_from(index)
+        .map_err(|_| PyValueError::new_err(format!("Too many variants in {}", def.name)))?;
+    out.push(index);
+    match (&variant.fields, fields_value) {
+        (None, _) => Ok(()),
+        (Some(fields), Some(v)) => encode_fields(idl, fields, v, out, depth),
+        (Some(_), None) => Err(PyValueError::new_err(format!(
+            "Variant {name} of {} has fields",
+            def.name
+        ))),
+    }
+}
+
+pub fn encode_defined(
+    idl: &IdlModel,
+    def: &IdlTypeDef,
+    value: &PyAny,
+    out: &mut Vec<u8>,
+    depth: usize,
+) -> PyResult<()> {
+    check_depth(def, depth)?;
+    if let Some(serialization) = &def.serialization {
+        return Err(PyValueError::new_err(format!(
+            "{} uses {serialization} serialization, only borsh is supported",
+            def.name
+        )));
+    }
+    match &def.ty {
+        IdlTypeDefTy::Struct(fields) => encode_fields(idl, fields, value, out, depth + 1),
+        IdlTypeDefTy::Enum(variants) => encode_enum(idl, def, variants, value, out, depth + 1),
+        IdlTypeDefTy::Alias(ty) => encode(idl, ty, value, out, depth + 1),
+    }
+}
+
+pub fn encode(
+    idl: &IdlModel,
+    ty: &IdlType,
+    value: &PyAny,
+    out: &mut Vec<u8>,
+    depth: usize,
+) -> PyResult<()> {
+    macro_rules! encode_num {
+        ($t:ty) => {{
+            let n: $t = value.extract().map_err(|e| encode_error(ty, value, e))?;
+            out.extend_from_slice(&n.to_le_bytes());
+        }};
+    }
+    match ty {
+        IdlType::Bool => {
+            let b: bool = value.extract().map_err(|e| encode_error(ty, value, e))?;
+            out.push(u8::from(b));
+        }
+        IdlType::U8 => encode_num!(u8),
+        IdlType::I8 => encode_num!(i8),
+        IdlType::U16 => encode_num!(u16),
+        IdlType::I16 => encode_num!(i16),
+        IdlType::U32 => encode_num!(u32),
+        IdlType::I32 => encode_num!(i32),
+        IdlType::F32 => encode_num!(f32),
+        IdlType::U64 => encode_num!(u64),
+        IdlType::I64 => encode_num!(i64),
+        IdlType::F64 => encode_num!(f64),
+        IdlType::U128 => encode_num!(u128),
+        IdlType::I128 => encode_num!(i128),
+        IdlType::Bytes => {
+            let bytes: Vec<u8> = value.extract().map_err(|e| encode_error(ty, value, e))?;
+            encode_len(bytes.len(), out)?;
+            out.extend_from_slice(&bytes);
+        }
+        IdlType::String => {
+            let s: &str = value.extract().map_err(|e| encode_error(ty, value, e))?;
+            encode_len(s.len(), out)?;
+            out.extend_from_slice(s.as_bytes());
+        }
+        IdlType::Pubkey => {
+            let pubkey = extract_pubkey(value).map_err(|e| encode_error(ty, value, e))?;
+            out.extend_from_slice(pubkey.as_ref());
+        }
+        IdlType::Option(inner) => {
+            if value.is_none() {
+                out.push(0);
+            } else {
+                out.push(1);
+                encode(idl, inner, value, out, depth)?;
+            }
+        }
+        IdlType::Vec(inner) => {
+            let items: Vec<&PyAny> = value
+                .iter()
+                .map_err(|e| encode_error(ty, value, e))?
+                .collect::<PyResult<_>>()?;
+            encode_len(items.len(), out)?;
+            for item in items {
+                encode(idl, inner, item, out, depth)?;
+            }
+        }
+        IdlType::Array(inner, len) => {
+            let items: Vec<&PyAny> = value
+                .iter()
+                .map_err(|e| encode_error(ty, value, e))?
+                .collect::<PyResult<_>>()?;
+            if items.len() != *len {
+                return Err(encode_error(
+                    ty,
+                    value,
+                    format!("expected {len} items, got {}", items.len()),
+                ));
+            }
+            for item in items {
+                encode(idl, inner, item, out, depth)?;
+            }
+        }
+        IdlType::Defined(name) => encode_defined(idl, idl.type_def(name)?, value, out, depth)?,
+    }
+    Ok(())
+}
+
+/// A cursor over the data being decoded.
+pub struct Reader<'a> {
+    data: &'a [u8],
+    offset: usize,
+    /// How many defined types are being decoded.
+    depth: usize,
+}
+
+impl<'a> Reader<'a> {
+    pub fn new(data: &'a [u8]) -> Self {
+        Self {
+            data,
+            offset: 0,
+            depth: 0,
+        }
+    }
+
+    /// The number of bytes not yet consumed.
+    pub fn remaining(&self) -> usize {
+        self.data.len() - self.offset
+    }
+
+    fn take(&mut self, n: usize) -> PyResult<&'a [u8]> {
+        let end = self
+            .offset
+            .checked_add(n)
+            .filter(|end| *end <= self.data.len());
+        match end {
+            Some(end) => {
+                let slice = &self.data[self.offset..end];
+                self.offset = end;
+                Ok(slice)
+            }
+            None => Err(PyValueError::new_err(format!(
+                "Unexpected end of data: needed {n} bytes at offset {}, but the data is {} bytes long",
+                self.offset,
+                self.data.len()
+            ))),
+        }
+    }
+
+    fn take_array<const N: usize>(&mut self) -> PyResult<[u8; N]> {
+        let mut arr = [0u8; N];
+        arr.copy_from_slice(self.take(N)?);
+        Ok(arr)
+    }
+
+    fn take_len(&mut self) -> PyResult<usize> {
+        Ok(u32::from_le_bytes(self.take_array()?) as usize)
+    }
+}
+
+fn decode_fields(
+    py: Python<'_>,
+    idl: &IdlModel,
+    fields: &IdlFields,
+    reader: &mut Reader,
+) -> PyResult<PyObject> {
+    match fields {
+        IdlFields::Named(named) => {
+            let dict = PyDict::new(py);
+            for (name, ty) in named {
+                dict.set_item(name, decode(py, idl, ty, reader)?)?;
+            }
+            Ok(dict.into_py(py))
+        }
+        IdlFields::Tuple(types) => {
+            let items = types
+                .iter()
+                .map(|ty| decode(py, idl, ty, reader))
+                .collect::<PyResult<Vec<_>>>()?;
+            Ok(PyTuple::new(py, items).into_py(py))
+        }
+    }
+}
+
+fn decode_enum(
+    py: Python<'_>,
+    idl: &IdlModel,
+    def: &IdlTypeDef,
+    variants: &[IdlEnumVariant],
+    reader: &mut Reader,
+) -> PyResult<PyObject> {
+    let [index] = reader.take_array::<1>()?;
+    let variant = variants.get(usize::from(index)).ok_or_else(|| {
+        PyValueError::new_err(format!("Invalid variant index {index} for {}", def.name))
+    })?;
+    let fields = match &variant.fields {
+        Some(fields) => decode_fields(py, idl, fields, reader)?,
+        None => py.None(),
+    };
+    let dict = PyDict::new(py);
+    dict.set_item(&variant.name, fields)?;
+    Ok(dict.into_py(py))
+}
+
+pub fn decode_defined(
+    py: Python<'_>,
+    idl: &IdlModel,
+    def: &IdlTypeDef,
+    reader: &mut Reader,
+) -> PyResult<PyObject> {
+    check_depth(def, reader.depth)?;
+    if let Some(serialization) = &def.serialization {
+        return Err(PyValueError::new_err(format!(
+            "{} uses {serialization} serialization, only borsh is supported",
+            def.name
+        )));
+    }
+    reader.depth += 1;
+    let decoded = match &def.ty {
+        IdlTypeDefTy::Struct(fields) => decode_fields(py, idl, fields, reader),
+        IdlTypeDefTy::Enum(variants) => decode_enum(py, idl, def, variants, reader),
+        IdlTypeDefTy::Alias(ty) => decode(py, idl, ty, reader),
+    };
+    reader.depth -= 1;
+    decoded
+}
+
+pub fn decode(
+    py: Python<'_>,
+    idl: &IdlModel,
+    ty: &IdlType,
+    reader: &mut Reader,
+) -> PyResult<PyObject> {
+    macro_rules! decode_num {
+        ($t:ty) => {
+            <$t>::from_le_bytes(reader.take_array()?).into_py(py)
+        };
+    }
+    Ok(match ty {
+        IdlType::Bool => match reader.take_array::<1>()? {
+            [0] => false.into_py(py),
+            [1] => true.into_py(py),
+            [b] => return Err(PyValueError::new_err(format!("Invalid bool {b}"))),
+        },
+        IdlType::U8 => decode_num!(u8),
+        IdlType::I8 => decode_num!(i8),
+        IdlType::U16 => decode_num!(u16),
+        IdlType::I16 => decode_num!(i16),
+        IdlType::U32 => decode_num!(u32),
+        IdlType::I32 => decode_num!(i32),
+        IdlType::F32 => decode_num!(f32),
+        IdlType::U64 => decode_num!(u64),
+        IdlType::I64 => decode_num!(i64),
+        IdlType::F64 => decode_num!(f64),
+        IdlType::U128 => decode_num!(u128),
+        IdlType::I128 => decode_num!(i128),
+        IdlType::Bytes => {
+            let len = reader.take_len()?;
+            PyBytes::new(py, reader.take(len)?).into_py(py)
+        }
+        IdlType::String => {
+            let len = reader.take_len()?;
+            let s = std::str::from_utf8(reader.take(len)?)
+                .map_err(|e| PyValueError::new_err(format!("Invalid string: {e}")))?;
+            s.into_py(py)
+        }
+        IdlType::Pubkey => Pubkey(PubkeyOriginal::new_from_array(reader.take_array()?)).into_py(py),
+        IdlType::Option(inner) => match reader.take_array::<1>()? {
+            [0] => py.None(),
+            [1] => decode(py, idl, inner, reader)?,
+            [b] => return Err(PyValueError::new_err(format!("Invalid option tag {b}"))),
+        },
+        IdlType::Vec(inner) => {
+            let len = reader.take_len()?;
+            let items = (0..len)
+                .map(|_| decode(py, idl, inner, reader))
+                .collect::<PyResult<Vec<_>>>()?;
+            PyList::new(py, items).into_py(py)
+        }
+        IdlType::Array(inner, len) => {
+            let items = (0..*len)
+                .map(|_| decode(py, idl, inner, reader))
+                .collect::<PyResult<Vec<_>>>()?;
+            PyList::new(py, items).into_py(py)
+        }
+        IdlType::Defined(name) => decode_defined(py, idl, idl.type_def(name)?, reader)?,
+    })
+}
@@ -0,0 +1,319 @@
+use std::{collections::HashMap, str::FromStr, sync::Arc};
+
+use pyo3::{exceptions::PyValueError, prelude::*, types::PyBytes};
+use pythonize::depythonize;
+use serde_json::Value;
+use solana_sdk::pubkey::Pubkey as PubkeyOriginal;
+use solders_pubkey::Pubkey;
+use solders_traits_core::handle_py_value_err;
+
+use crate::{
+    codec::{decode_defined, Reader},
+    idl::{parse_type_def_ty, IdlModel, IdlTypeDef},
+    Idl,
+};
+
+const PROGRAM_DATA: &str = "Program data: ";
+/// The prefix of the instruction data of an ``emit_cpi!`` self-invocation.
+/// This is ``sha256("anchor:event")[..8]`` in little-endian order.
+pub const EVENT_IX_TAG_LE: [u8; 8] = [0xe4, 0x45, 0xa5, 0x2e, 0x51, 0xcb, 0x9a, 0x1d];
+
+#[derive(Clone, Debug)]
+enum EventSchema {
+    Borsh {
+        idl: Arc<IdlModel>,
+        type_name: String,
+    },
+    Callable(PyObject),
+}
+
+#[derive(Clone, Debug)]
+struct EventEntry {
+    discriminator: Vec<u8>,
+    name: String,
+    program_id: Option<PubkeyOriginal>,
+    schema: EventSchema,
+}
+
+impl EventEntry {
+    fn decode(&self, py: Python<'_>, data: &[u8]) -> PyResult<PyObject> {
+        match &self.schema {
+            EventSchema::Borsh { idl, type_name } => {
+                decode_defined(py, idl, idl.type_def(type_name)?, &mut Reader::new(data))
+            }
+            EventSchema::Callable(decoder) => decoder.call1(py, (PyBytes::new(py, data),)),
+        }
+    }
+}
+
+/// An event emitted by a program.
+///
+/// Args:
+///     name (str): The event name.
+///     data (Any): The decoded event payload.
+///     program_id (Pubkey): The program that emitted the event.
+///
+#[pyclass(module = "solders.anchor_idl", subclass)]
+#[derive(Clone, Debug)]
+pub struct AnchorEvent {
+    #[pyo3(get)]
+    name: String,
+    #[pyo3(get)]
+    data: PyObject,
+    #[pyo3(get)]
+    program_id: Pubkey,
+}
+
+#[pymethods]
+impl AnchorEvent {
+    #[new]
+    pub fn new(name: String, data: PyObject, program_id: Pubkey) -> Self {
+        Self {
+            name,
+            data,
+            program_id,
+        }
+    }
+
+    fn __repr__(&self, py: Python<'_>) -> PyResult<String> {
+        Ok(format!(
+            "AnchorEvent(name={:?}, data={}, program_id={})",
+            self.name,
+            self.data.as_ref(py).repr()?,
+            self.program_id.0
+        ))
+    }
+
+    fn __eq__(&self, py: Python<'_>, other: &Self) -> PyResult<bool> {
+        Ok(self.name == other.name
+            && self.program_id == other.program_id
+            && self.data.as_ref(py).eq(other.data.as_ref(py))?)
+    }
+}
+
+/// Decodes events from transaction logs and ``emit_cpi!`` event instructions.
+///
+/// Events are matched by their discriminator and, where one is known, by the emitting program.
+/// Register schemas from an :class:`Idl` with :meth:`add_idl`, or by hand with :meth:`register`.
+///
+/// Example:
+///     >>> from solders.anchor_idl import EventDecoder
+///     >>> decoder = EventDecoder()
+///     >>> decoder.register(b"\x01" * 8, "Ping", {"kind": "struct", "fields": [{"name": "n", "type": "u8"}]})
+///     >>> logs = [
+///     ...     "Program 11111111111111111111111111111111 invoke [1]",
+///     ...     "Program data: AQEBAQEBAQEH",
+///     ...     "Program 11111111111111111111111111111111 success",
+///     ... ]
+///     >>> [(e.name, e.data) for e in decoder.decode_logs(logs)]
+///     [('Ping', {'n': 7})]
+///
+#[pyclass(module = "solders.anchor_idl", subclass)]
+#[derive(Clone, Debug, Default)]
+pub struct EventDecoder {
+    entries: Vec<EventEntry>,
+}
+
+impl EventDecoder {
+    fn decode_payload(
+        &self,
+        py: Python<'_>,
+        program_id: PubkeyOriginal,
+        data: &[u8],
+    ) -> PyResult<Option<AnchorEvent>> {
+        let entry = self.entries.iter().find(|e| {
+            e.program_id.map_or(true, |p| p == program_id) && data.starts_with(&e.discriminator)
+        });
+        entry
+            .map(|e| {
+                let decoded = e.decode(py, &data[e.discriminator.len()..])?;
+                Ok(AnchorEvent::new(
+                    e.name.clone(),
+                    decoded,
+                    Pubkey(program_id),
+                ))
+            })
+            .transpose()
+    }
+}
+
+#[pymethods]
+impl EventDecoder {
+    #[new]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register all the events defined in an IDL.
+    ///
+    /// Args:
+    ///     idl (Idl): The IDL.
+    ///     program_id (Optional[Pubkey]): Only match events emitted by this program.
+    ///         Defaults to the IDL address. If neither is set, events from any program match.
+    ///
+    #[pyo3(signature = (idl, program_id=None))]
+    pub fn add_idl(&mut self, idl: &Idl, program_id: Option<Pubkey>) {
+        let program_id = program_id.map(|p| p.0).or(idl.0.address);
+        let model = Arc::new(idl.0.clone());
+        for event in &model.events {
+            self.entries.push(EventEntry {
+                discriminator: event.discriminator.clone(),
+                name: event.name.clone(),
+                program_id,
+                schema: EventSchema::Borsh {
+                    idl: Arc::clone(&model),
+                    type_name: event.name.clone(),
+                },
+            });
+        }
+    }
+
+    /// Register a single event by discriminator.
+    ///
+    /// Args:
+    ///     discriminator (bytes): The bytes that prefix the event payload.
+    ///     name (str): The event name.
+    ///     schema (Union[dict, str, Callable[[bytes], Any]]): Either an IDL type definition
+    ///         such as ``{"kind": "struct", "fields": [...]}`` (as a dict or JSON string),
+    ///         or a callable that receives the payload without the discriminator.
+    ///     program_id (Optional[Pubkey]): Only match events emitted by this program.
+    ///
+    /// Raises:
+    ///     ValueError: If the schema is not a valid type definition.
+    ///
+    #[pyo3(signature = (discriminator, name, schema, program_id=None))]
+    pub fn register(
+        &mut self,
+        discriminator: Vec<u8>,
+        name: String,
+        schema: &PyAny,
+        program_id: Option<Pubkey>,
+    ) -> PyResult<()> {
+        let schema = if schema.is_callable() {
+            EventSchema::Callable(schema.into())
+        } else {
+            let value: Value = match schema.extract::<&str>() {
+                Ok(raw) => serde_json::from_str(raw)
+                    .map_err(|e| PyValueError::new_err(format!("Invalid schema JSON: {e}")))?,
+                Err(_) => handle_py_value_err(depythonize::<Value>(schema))?,
+            };
+            let def = IdlTypeDef {
+                name: name.clone(),
+                ty: parse_type_def_ty(&value)?,
+                serialization: None,
+            };
+            let idl = IdlModel::from_types(HashMap::from([(name.clone(), def)]));
+            EventSchema::Borsh {
+                idl: Arc::new(idl),
+                type_name: name.clone(),
+            }
+        };
+        self.entries.push(EventEntry {
+            discriminator,
+            name,
+            program_id: program_id.map(|p| p.0),
+            schema,
+        });
+        Ok(())
+    }
+
+    /// Decode the events emitted via ``Program data:`` log lines.
+    ///
+    /// Each event is attributed to the innermost program running when it was logged.
+    /// Lines that don't match a registered event are skipped.
+    ///
+    /// Args:
+    ///     log_messages (Sequence[str]): The transaction logs.
+    ///
+    /// Returns:
+    ///     List[AnchorEvent]: The events in emission order.
+    ///
+    /// Raises:
+    ///     ValueError: If a matching event payload can't be decoded.
+    ///
+    pub fn decode_logs(
+        &self,
+        py: Python<'_>,
+        log_messages: Vec<String>,
+    ) -> PyResult<Vec<AnchorEvent>> {
+        let mut stack: Vec<PubkeyOriginal> = vec![];
+        let mut events = vec![];
+        for line in &log_messages {
+            if let Some(encoded) = line.strip_prefix(PROGRAM_DATA) {
+                if let Some(program_id) = stack.last() {
+                    for field in encoded.split_whitespace() {
+                        if let Ok(data) = base64::decode(field) {
+                            events.extend(self.decode_payload(py, *program_id, &data)?);
+                        }
+                    }
+                }
+            } else if let Some((id, tail)) = line
+                .strip_prefix("Program ")
+                .and_then(|rest| rest.split_once(' '))
+            {
+                let program_id = match PubkeyOriginal::from_str(id) {
+                    Ok(p) => p,
+                    Err(_) => continue,
+                };
+                if tail.starts_with("invoke [") {
+                    stack.push(program_id);
+                } else if (tail == "success" || tail.starts_with("failed: "))
+                    && stack.last() == Some(&program_id)
+                {
+                    stack.pop();
+                }
+            }
+        }
+        Ok(events)
+    }
+
+    /// Decode the events in a transaction's ``log_messages``.
+    ///
+    /// Args:
+    ///     meta (Union[BanksTransactionMeta, UiTransactionStatusMeta]): The transaction metadata.
+    ///
+    /// Returns:
+    ///     List[AnchorEvent]: The events in emission order.
+    ///
+    pub fn decode_meta(&self, py: Python<'_>, meta: &PyAny) -> PyResult<Vec<AnchorEvent>> {
+        let logs: Option<Vec<String>> = meta.getattr("log_messages")?.extract()?;
+        self.decode_logs(py, logs.unwrap_or_default())
+    }
+
+    /// Decode the events emitted as ``emit_cpi!`` self-invocations.
+    ///
+    /// Args:
+    ///     instructions (Sequence[Union[Instruction, InstructionNode]]): The inner instructions,
+    ///         e.g. from ``InstructionNode.walk_all()``. Anything with ``program_id``
+    ///         and ``data`` attributes is accepted.
+    ///
+    /// Returns:
+    ///     List[AnchorEvent]: The events in the order of the given instructions.
+    ///
+    /// Raises:
+    ///     ValueError: If a matching event payload can't be decoded.
+    ///
+    pub fn decode_instructions(
+        &self,
+        py: Python<'_>,
+        instructions: Vec<&PyAny>,
+    ) -> PyResult<Vec<AnchorEvent>> {
+        let mut events = vec![];
+        for ix in instructions {
+            let program_id: Pubkey = ix.getattr("program_id")?.extract()?;
+            let data: Option<Vec<u8>> = ix.getattr("data")?.extract()?;
+            if let Some(payload) = data
+                .as_deref()
+                .and_then(|d| d.strip_prefix(EVENT_IX_TAG_LE.as_slice()))
+            {
+                events.extend(self.decode_payload(py, program_id.0, payload)?);
+            }
+        }
+        Ok(events)
+    }
+
+    fn __repr__(&self) -> String {
+        let names: Vec<&str> = self.entries.iter().map(|e| e.name.as_str()).collect();
+        format!("EventDecoder(events={names:?})")
+    }
+}
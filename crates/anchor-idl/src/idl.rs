@@ -0,0 +1,514 @@
+//! A normalized model of Anchor IDLs.
+//!
+//! Both the legacy IDL format and the format introduced in Anchor 0.30 are
+//! parsed into the same types, so the codec doesn't need to care which one it got.
+use std::collections::HashMap;
+
+use pyo3::{exceptions::PyValueError, prelude::*};
+use serde_json::Value;
+use solana_sdk::{hash::hashv, pubkey::Pubkey as PubkeyOriginal};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum IdlType {
+    Bool,
+    U8,
+    I8,
+    U16,
+    I16,
+    U32,
+    I32,
+    F32,
+    U64,
+    I64,
+    F64,
+    U128,
+    I128,
+    Bytes,
+    String,
+    Pubkey,
+    Option(Box<IdlType>),
+    Vec(Box<IdlType>),
+    Array(Box<IdlType>, usize),
+    Defined(String),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum IdlFields {
+    Named(Vec<(String, IdlType)>),
+    Tuple(Vec<IdlType>),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IdlEnumVariant {
+    pub name: String,
+    pub fields: Option<IdlFields>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum IdlTypeDefTy {
+    Struct(IdlFields),
+    Enum(Vec<IdlEnumVariant>),
+    Alias(IdlType),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IdlTypeDef {
+    pub name: String,
+    pub ty: IdlTypeDefTy,
+    /// ``None`` means Borsh. Other serializations can't be encoded or decoded.
+    pub serialization: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum IdlAccountItem {
+    Single {
+        name: String,
+        writable: bool,
+        signer: bool,
+        optional: bool,
+        address: Option<PubkeyOriginal>,
+    },
+    Composite {
+        name: String,
+        accounts: Vec<IdlAccountItem>,
+    },
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IdlInstruction {
+    pub name: String,
+    pub discriminator: Vec<u8>,
+    pub accounts: Vec<IdlAccountItem>,
+    pub args: Vec<(String, IdlType)>,
+}
+
+/// An account or event: a discriminator followed by a defined type.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IdlDiscriminated {
+    pub name: String,
+    pub discriminator: Vec<u8>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IdlErrorCode {
+    pub code: u32,
+    pub name: String,
+    /// Defaults to the name when the IDL gives no message.
+    pub msg: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IdlModel {
+    pub address: Option<PubkeyOriginal>,
+    pub name: String,
+    pub version: String,
+    pub legacy: bool,
+    pub instructions: Vec<IdlInstruction>,
+    pub accounts: Vec<IdlDiscriminated>,
+    pub events: Vec<IdlDiscriminated>,
+    pub types: HashMap<String, IdlTypeDef>,
+    pub errors: Vec<IdlErrorCode>,
+}
+
+fn invalid(msg: impl std::fmt::Display) -> PyErr {
+    PyValueError::new_err(format!("Invalid IDL: {msg}"))
+}
+
+/// Convert an identifier to snake case the way Anchor does when hashing names.
+pub fn to_snake_case(s: &str) -> String {
+    let chars: Vec<char> = s.chars().collect();
+    let mut out = String::with_capacity(s.len() + 4);
+    for (i, c) in chars.iter().enumerate() {
+        if c.is_uppercase() {
+            let prev = i.checked_sub(1).map(|j| chars[j]);
+            let next = chars.get(i + 1);
+            let boundary = match prev {
+                Some('_') => false,
+                Some(p) if p.is_lowercase() || p.is_ascii_digit() => true,
+                Some(p) if p.is_uppercase() => next.map_or(false, |n| n.is_lowercase()),
+                _ => false,
+            };
+            if boundary {
+                out.push('_');
+            }
+            out.extend(c.to_lowercase());
+        } else {
+            out.push(*c);
+        }
+    }
+    out
+}
+
+/// Whether two identifiers name the same thing, ignoring camel/snake case differences.
+pub fn names_match(a: &str, b: &str) -> bool {
+    a == b || to_snake_case(a) == to_snake_case(b)
+}
+
+/// The first 8 bytes of ``sha256("<namespace>:<name>")``.
+pub fn sighash(namespace: &str, name: &str) -> Vec<u8> {
+    hashv(&[namespace.as_bytes(), b":", name.as_bytes()]).to_bytes()[..8].to_vec()
+}
+
+fn get_str<'a>(value: &'a Value, key: &str) -> Option<&'a str> {
+    value.get(key).and_then(Value::as_str)
+}
+
+fn get_array<'a>(value: &'a Value, key: &str) -> &'a [Value] {
+    value
+        .get(key)
+        .and_then(Value::as_array)
+        .map_or(&[], Vec::as_slice)
+}
+
+fn get_bool(value: &Value, keys: &[&str]) -> bool {
+    keys.iter()
+        .find_map(|k| value.get(*k).and_then(Value::as_bool))
+        .unwrap_or(false)
+}
+
+fn parse_name(value: &Value) -> PyResult<String> {
+    get_str(value, "name")
+        .map(str::to_string)
+        .ok_or_else(|| invalid(format!("missing name in {value}")))
+}
+
+fn parse_discriminator(value: &Value) -> PyResult<Option<Vec<u8>>> {
+    value
+        .get("discriminator")
+        .map(|d| {
+            d.as_array()
+                .and_then(|bytes| {
+                    bytes
+                        .iter()
+                        .map(|b| b.as_u64().and_then(|b| u8::try_from(b).ok()))
+                        .collect::<Option<Vec<u8>>>()
+                })
+                .ok_or_else(|| invalid(format!("bad discriminator {d}")))
+        })
+        .transpose()
+}
+
+fn parse_pubkey(s: &str) -> PyResult<PubkeyOriginal> {
+    s.parse()
+        .map_err(|e| invalid(format!("bad address {s}: {e}")))
+}
+
+pub fn parse_type(value: &Value) -> PyResult<IdlType> {
+    if let Some(s) = value.as_str() {
+        return Ok(match s {
+            "bool" => IdlType::Bool,
+            "u8" => IdlType::U8,
+            "i8" => IdlType::I8,
+            "u16" => IdlType::U16,
+            "i16" => IdlType::I16,
+            "u32" => IdlType::U32,
+            "i32" => IdlType::I32,
+            "f32" => IdlType::F32,
+            "u64" => IdlType::U64,
+            "i64" => IdlType::I64,
+            "f64" => IdlType::F64,
+            "u128" => IdlType::U128,
+            "i128" => IdlType::I128,
+            "bytes" => IdlType::Bytes,
+            "string" => IdlType::String,
+            "publicKey" | "pubkey" => IdlType::Pubkey,
+            other => return Err(invalid(format!("unsupported type {other}"))),
+        });
+    }
+    if let Some(inner) = value.get("option") {
+        return Ok(IdlType::Option(Box::new(parse_type(inner)?)));
+    }
+    if let Some(inner) = value.get("vec") {
+        return Ok(IdlType::Vec(Box::new(parse_type(inner)?)));
+    }
+    if let Some(array) = value.get("array").and_then(Value::as_array) {
+        return match array.as_slice() {
+            [inner, len] => {
+                let len = len
+                    .as_u64()
+                    .and_then(|l| usize::try_from(l).ok())
+                    .ok_or_else(|| invalid(format!("unsupported array length {len}")))?;
+                Ok(IdlType::Array(Box::new(parse_type(inner)?), len))
+            }
+            _ => Err(invalid(format!("bad array type {value}"))),
+        };
+    }
+    if let Some(defined) = value.get("defined") {
+        // Legacy: {"defined": "Name"}. 0.30: {"defined": {"name": "Name", "generics": [...]}}.
+        if let Some(name) = defined.as_str() {
+            return Ok(IdlType::Defined(name.to_string()));
+        }
+        if !get_array(defined, "generics").is_empty() {
+            return Err(invalid(format!("generic types are not supported: {value}")));
+        }
+        return Ok(IdlType::Defined(parse_name(defined)?));
+    }
+    Err(invalid(format!("unsupported type {value}")))
+}
+
+/// Parse struct or variant fields. Returns ``None`` for an empty field list.
+fn parse_fields(fields: &[Value]) -> PyResult<Option<IdlFields>> {
+    if fields.is_empty() {
+        return Ok(None);
+    }
+    let named = fields
+        .iter()
+        .all(|f| f.is_object() && f.get("name").is_some() && f.get("type").is_some());
+    if named {
+        let parsed = fields
+            .iter()
+            .map(|f| Ok((parse_name(f)?, parse_type(&f["type"])?)))
+            .collect::<PyResult<Vec<_>>>()?;
+        Ok(Some(IdlFields::Named(parsed)))
+    } else {
+        let parsed = fields
+            .iter()
+            .map(parse_type)
+            .collect::<PyResult<Vec<_>>>()?;
+        Ok(Some(IdlFields::Tuple(parsed)))
+    }
+}
+
+pub fn parse_type_def_ty(ty: &Value) -> PyResult<IdlTypeDefTy> {
+    match get_str(ty, "kind") {
+        Some("struct") => Ok(IdlTypeDefTy::Struct(
+            parse_fields(get_array(ty, "fields"))?.unwrap_or(IdlFields::Named(vec![])),
+        )),
+        Some("enum") => {
+            let variants = get_array(ty, "variants")
+                .iter()
+                .map(|v| {
+                    Ok(IdlEnumVariant {
+                        name: parse_name(v)?,
+                        fields: parse_fields(get_array(v, "fields"))?,
+                    })
+                })
+                .collect::<PyResult<Vec<_>>>()?;
+            Ok(IdlTypeDefTy::Enum(variants))
+        }
+        Some("type") => {
+            let alias = ty
+                .get("alias")
+                .ok_or_else(|| invalid(format!("missing alias in {ty}")))?;
+            Ok(IdlTypeDefTy::Alias(parse_type(alias)?))
+        }
+        _ => Err(invalid(format!("unsupported type definition {ty}"))),
+    }
+}
+
+pub(crate) fn parse_type_def(value: &Value) -> PyResult<IdlTypeDef> {
+    let name = parse_name(value)?;
+    let ty = value
+        .get("type")
+        .ok_or_else(|| invalid(format!("missing type for {name}")))?;
+    let serialization = get_str(value, "serialization")
+        .filter(|s| *s != "borsh")
+        .map(str::to_string);
+    Ok(IdlTypeDef {
+        name,
+        ty: parse_type_def_ty(ty)?,
+        serialization,
+    })
+}
+
+fn parse_account_item(value: &Value) -> PyResult<IdlAccountItem> {
+    let name = parse_name(value)?;
+    if let Some(accounts) = value.get("accounts").and_then(Value::as_array) {
+        return Ok(IdlAccountItem::Composite {
+            name,
+            accounts: accounts
+                .iter()
+                .map(parse_account_item)
+                .collect::<PyResult<Vec<_>>>()?,
+        });
+    }
+    Ok(IdlAccountItem::Single {
+        name,
+        writable: get_bool(value, &["writable", "isMut"]),
+        signer: get_bool(value, &["signer", "isSigner"]),
+        optional: get_bool(value, &["optional", "isOptional"]),
+        address: get_str(value, "address").map(parse_pubkey).transpose()?,
+    })
+}
+
+fn parse_instruction(value: &Value, legacy: bool) -> PyResult<IdlInstruction> {
+    let name = parse_name(value)?;
+    let discriminator = match parse_discriminator(value)? {
+        Some(d) => d,
+        None if legacy => sighash("global", &to_snake_case(&name)),
+        None => return Err(invalid(format!("missing discriminator for {name}"))),
+    };
+    let accounts = get_array(value, "accounts")
+        .iter()
+        .map(parse_account_item)
+        .collect::<PyResult<Vec<_>>>()?;
+    let args = get_array(value, "args")
+        .iter()
+        .map(|a| {
+            let ty = a
+                .get("type")
+                .ok_or_else(|| invalid(format!("missing type in {a}")))?;
+            Ok((parse_name(a)?, parse_type(ty)?))
+        })
+        .collect::<PyResult<Vec<_>>>()?;
+    Ok(IdlInstruction {
+        name,
+        discriminator,
+        accounts,
+        args,
+    })
+}
+
+/// Parse an account or event entry. Legacy IDLs declare the type inline,
+/// so it is added to ``types``.
+fn parse_discriminated(
+    value: &Value,
+    namespace: &str,
+    legacy: bool,
+    types: &mut HashMap<String, IdlTypeDef>,
+) -> PyResult<IdlDiscriminated> {
+    let name = parse_name(value)?;
+    let discriminator = match parse_discriminator(value)? {
+        Some(d) => d,
+        None if legacy => sighash(namespace, &name),
+        None => return Err(invalid(format!("missing discriminator for {name}"))),
+    };
+    if let Some(ty) = value.get("type") {
+        types.insert(
+            name.clone(),
+            IdlTypeDef {
+                name: name.clone(),
+                ty: parse_type_def_ty(ty)?,
+                serialization: None,
+            },
+        );
+    } else if let Some(fields) = value.get("fields").and_then(Value::as_array) {
+        // Legacy events list their fields directly.
+        let fields = fields
+            .iter()
+            .map(|f| {
+                let ty = f
+                    .get("type")
+                    .ok_or_else(|| invalid(format!("missing type in {f}")))?;
+                Ok((parse_name(f)?, parse_type(ty)?))
+            })
+            .collect::<PyResult<Vec<_>>>()?;
+        types.insert(
+            name.clone(),
+            IdlTypeDef {
+                name: name.clone(),
+                ty: IdlTypeDefTy::Struct(IdlFields::Named(fields)),
+                serialization: None,
+            },
+        );
+    }
+    Ok(IdlDiscriminated {
+        name,
+        discriminator,
+    })
+}
+
+fn parse_error_code(value: &Value) -> PyResult<IdlErrorCode> {
+    let code = value
+        .get("code")
+        .and_then(Value::as_u64)
+        .and_then(|c| u32::try_from(c).ok())
+        .ok_or_else(|| invalid(format!("bad error code in {value}")))?;
+    let name = parse_name(value)?;
+    let msg = get_str(value, "msg").unwrap_or(&name).to_string();
+    Ok(IdlErrorCode { code, name, msg })
+}
+
+impl IdlModel {
+    /// A nameless model holding only type definitions, for use with the codec.
+    pub fn from_types(types: HashMap<String, IdlTypeDef>) -> Self {
+        Self {
+            address: None,
+            name: String::new(),
+            version: String::new(),
+            legacy: false,
+            instructions: vec![],
+            accounts: vec![],
+            events: vec![],
+            types,
+            errors: vec![],
+        }
+    }
+
+    pub fn parse(raw: &str) -> PyResult<Self> {
+        let value: Value = serde_json::from_str(raw).map_err(invalid)?;
+        // 0.30+ IDLs have a top-level address and a metadata.spec field.
+        let legacy = value.get("metadata").and_then(|m| m.get("spec")).is_none();
+        let address = get_str(&value, "address")
+            .or_else(|| value.get("metadata").and_then(|m| get_str(m, "address")))
+            .map(parse_pubkey)
+            .transpose()?;
+        let metadata = value.get("metadata");
+        let name = metadata
+            .and_then(|m| get_str(m, "name"))
+            .or_else(|| get_str(&value, "name"))
+            .unwrap_or_default()
+            .to_string();
+        let version = metadata
+            .and_then(|m| get_str(m, "version"))
+            .or_else(|| get_str(&value, "version"))
+            .unwrap_or_default()
+            .to_string();
+        let mut types = get_array(&value, "types")
+            .iter()
+            .map(|t| parse_type_def(t).map(|def| (def.name.clone(), def)))
+            .collect::<PyResult<HashMap<_, _>>>()?;
+        let instructions = get_array(&value, "instructions")
+            .iter()
+            .map(|ix| parse_instruction(ix, legacy))
+            .collect::<PyResult<Vec<_>>>()?;
+        let accounts = get_array(&value, "accounts")
+            .iter()
+            .map(|a| parse_discriminated(a, "account", legacy, &mut types))
+            .collect::<PyResult<Vec<_>>>()?;
+        let events = get_array(&value, "events")
+            .iter()
+            .map(|e| parse_discriminated(e, "event", legacy, &mut types))
+            .collect::<PyResult<Vec<_>>>()?;
+        let errors = get_array(&value, "errors")
+            .iter()
+            .map(parse_error_code)
+            .collect::<PyResult<Vec<_>>>()?;
+        Ok(Self {
+            address,
+            name,
+            version,
+            legacy,
+            instructions,
+            accounts,
+            events,
+            types,
+            errors,
+        })
+    }
+
+    pub fn instruction(&self, name: &str) -> PyResult<&IdlInstruction> {
+        self.instructions
+            .iter()
+            .find(|ix| ix.name == name)
+            .or_else(|| {
+                self.instructions
+                    .iter()
+                    .find(|ix| names_match(&ix.name, name))
+            })
+            .ok_or_else(|| PyValueError::new_err(format!("Unknown instruction {name}")))
+    }
+
+    pub fn account(&self, name: &str) -> PyResult<&IdlDiscriminated> {
+        self.accounts
+            .iter()
+            .find(|a| a.name == name)
+            .ok_or_else(|| PyValueError::new_err(format!("Unknown account {name}")))
+    }
+
+    pub fn type_def(&self, name: &str) -> PyResult<&IdlTypeDef> {
+        self.types
+            .get(name)
+            .ok_or_else(|| PyValueError::new_err(format!("Unknown type {name}")))
+    }
+}
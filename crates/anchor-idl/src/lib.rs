@@ -0,0 +1,449 @@
+use pyo3::{exceptions::PyValueError, prelude::*, types::PyBytes, types::PyDict};
+use solana_sdk::{
+    instruction::{AccountMeta as AccountMetaOriginal, Instruction as InstructionOriginal},
+    pubkey::Pubkey as PubkeyOriginal,
+};
+use solders_instruction::{AccountMeta, Instruction};
+use solders_pubkey::Pubkey;
+use solders_traits::InstructionDecodeError;
+
+pub mod codec;
+pub mod events;
+pub mod idl;
+use codec::{decode, decode_defined, encode, encode_defined, Reader};
+pub use events::{AnchorEvent, EventDecoder};
+use idl::{names_match, IdlAccountItem, IdlModel};
+
+fn collect_account_metas(
+    items: &[IdlAccountItem],
+    accounts: &PyAny,
+    program_id: &PubkeyOriginal,
+    metas: &mut Vec<AccountMetaOriginal>,
+) -> PyResult<()> {
+    let dict = accounts.downcast::<PyDict>()?;
+    let lookup = |name: &str| -> PyResult<Option<&PyAny>> {
+        if let Some(v) = dict.get_item(name)? {
+            return Ok(Some(v));
+        }
+        for (k, v) in dict.iter() {
+            if names_match(k.extract()?, name) {
+                return Ok(Some(v));
+            }
+        }
+        Ok(None)
+    };
+    for item in items {
+        match item {
+            IdlAccountItem::Single {
+                name,
+                writable,
+                signer,
+                optional,
+                address,
+            } => {
+                let pubkey = match (lookup(name)?.filter(|v| !v.is_none()), address) {
+                    (Some(v), _) => v.extract::<Pubkey>()?.0,
+                    (None, Some(a)) => *a,
+                    // Anchor passes the program ID in place of a missing optional account.
+                    (None, None) if *optional => {
+                        metas.push(AccountMetaOriginal::new_readonly(*program_id, false));
+                        continue;
+                    }
+                    (None, None) => {
+                        return Err(PyValueError::new_err(format!("Missing account {name}")))
+                    }
+                };
+                metas.push(if *writable {
+                    AccountMetaOriginal::new(pubkey, *signer)
+                } else {
+                    AccountMetaOriginal::new_readonly(pubkey, *signer)
+                });
+            }
+            IdlAccountItem::Composite { name, accounts } => {
+                let nested = lookup(name)?.ok_or_else(|| {
+                    PyValueError::new_err(format!("Missing account group {name}"))
+                })?;
+                collect_account_metas(accounts, nested, program_id, metas)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// An Anchor IDL, used to build instructions and to encode and decode
+/// instruction and account data.
+///
+/// Both the legacy IDL format and the format introduced in Anchor 0.30 are supported.
+/// Values are Borsh-encoded. Structs are given as dicts (or objects with matching
+/// attributes) and decoded as dicts. Enums are given as ``{"Variant": fields}``,
+/// or just ``"Variant"`` for variants without fields, and decoded as ``{"Variant": fields}``
+/// where ``fields`` is ``None`` for variants without fields.
+/// Names can be given in snake case even if the IDL uses camel case.
+///
+/// Example:
+///     >>> from solders.anchor_idl import Idl
+///     >>> raw = '{"version": "0.1.0", "name": "counter", "instructions": [{"name": "increment", "accounts": [{"name": "counter", "isMut": true, "isSigner": false}], "args": [{"name": "by", "type": "u64"}]}]}'
+///     >>> idl = Idl.from_json(raw)
+///     >>> idl.instruction_names()
+///     ['increment']
+///     >>> idl.decode_instruction(idl.encode_instruction_data("increment", {"by": 2}))
+///     ('increment', {'by': 2})
+///
+#[pyclass(module = "solders.anchor_idl", subclass)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Idl(pub IdlModel);
+
+impl Idl {
+    pub fn encode_instruction_data_inner(&self, name: &str, args: &PyAny) -> PyResult<Vec<u8>> {
+        let ix = self.0.instruction(name)?;
+        let mut out = ix.discriminator.clone();
+        for (arg_name, ty) in &ix.args {
+            let value = match args.downcast::<PyDict>() {
+                Ok(dict) => dict
+                    .iter()
+                    .find_map(|(k, v)| {
+                        k.extract::<&str>()
+                            .ok()
+                            .filter(|k| names_match(k, arg_name))
+                            .map(|_| v)
+                    })
+                    .ok_or_else(|| {
+                        PyValueError::new_err(format!("Missing argument {arg_name} for {name}"))
+                    })?,
+                Err(_) => args.getattr(arg_name.as_str())?,
+            };
+            encode(&self.0, ty, value, &mut out, 0)?;
+        }
+        Ok(out)
+    }
+}
+
+#[pymethods]
+impl Idl {
+    /// Parse an IDL from JSON.
+    ///
+    /// Args:
+    ///     raw (str): The IDL JSON.
+    ///
+    /// Returns:
+    ///     Idl: The parsed IDL.
+    ///
+    /// Raises:
+    ///     ValueError: If the IDL is invalid or uses unsupported types such as generics.
+    ///
+    #[staticmethod]
+    pub fn from_json(raw: &str) -> PyResult<Self> {
+        IdlModel::parse(raw).map(Self)
+    }
+
+    /// Optional[Pubkey]: The program address declared in the IDL.
+    #[getter]
+    pub fn address(&self) -> Option<Pubkey> {
+        self.0.address.map(Pubkey)
+    }
+
+    /// str: The program name.
+    #[getter]
+    pub fn name(&self) -> String {
+        self.0.name.clone()
+    }
+
+    /// str: The program version.
+    #[getter]
+    pub fn version(&self) -> String {
+        self.0.version.clone()
+    }
+
+    /// bool: Whether the IDL uses the format from before Anchor 0.30.
+    #[getter]
+    pub fn is_legacy(&self) -> bool {
+        self.0.legacy
+    }
+
+    /// The names of the program's instructions.
+    ///
+    /// Returns:
+    ///     List[str]: The instruction names.
+    ///
+    pub fn instruction_names(&self) -> Vec<String> {
+        self.0
+            .instructions
+            .iter()
+            .map(|ix| ix.name.clone())
+            .collect()
+    }
+
+    /// The names of the program's accounts.
+    ///
+    /// Returns:
+    ///     List[str]: The account names.
+    ///
+    pub fn account_names(&self) -> Vec<String> {
+        self.0.accounts.iter().map(|a| a.name.clone()).collect()
+    }
+
+    /// The names of the program's events.
+    ///
+    /// Returns:
+    ///     List[str]: The event names.
+    ///
+    pub fn event_names(&self) -> Vec<String> {
+        self.0.events.iter().map(|e| e.name.clone()).collect()
+    }
+
+    /// The names of the types defined by the IDL.
+    ///
+    /// Returns:
+    ///     List[str]: The type names, sorted.
+    ///
+    pub fn type_names(&self) -> Vec<String> {
+        let mut names: Vec<String> = self.0.types.keys().cloned().collect();
+        names.sort();
+        names
+    }
+
+    /// The discriminator that prefixes an instruction's data.
+    ///
+    /// Args:
+    ///     name (str): The instruction name.
+    ///
+    /// Returns:
+    ///     bytes: The discriminator.
+    ///
+    pub fn instruction_discriminator<'a>(
+        &self,
+        py: Python<'a>,
+        name: &str,
+    ) -> PyResult<&'a PyBytes> {
+        Ok(PyBytes::new(py, &self.0.instruction(name)?.discriminator))
+    }
+
+    /// The discriminator that prefixes an account's data.
+    ///
+    /// Args:
+    ///     name (str): The account name.
+    ///
+    /// Returns:
+    ///     bytes: The discriminator.
+    ///
+    pub fn account_discriminator<'a>(&self, py: Python<'a>, name: &str) -> PyResult<&'a PyBytes> {
+        Ok(PyBytes::new(py, &self.0.account(name)?.discriminator))
+    }
+
+    /// Encode instruction data: the discriminator followed by the Borsh-encoded args.
+    ///
+    /// Args:
+    ///     name (str): The instruction name.
+    ///     args (Dict[str, Any]): The instruction args.
+    ///
+    /// Returns:
+    ///     bytes: The instruction data.
+    ///
+    pub fn encode_instruction_data<'a>(
+        &self,
+        py: Python<'a>,
+        name: &str,
+        args: &PyAny,
+    ) -> PyResult<&'a PyBytes> {
+        Ok(PyBytes::new(
+            py,
+            &self.encode_instruction_data_inner(name, args)?,
+        ))
+    }
+
+    /// Build an instruction.
+    ///
+    /// Account metas follow the order and flags of the IDL accounts list.
+    /// Accounts of a composite account group are given as a nested dict.
+    /// Accounts with a fixed address in the IDL may be omitted, and missing
+    /// optional accounts are replaced with the program ID as Anchor does.
+    ///
+    /// Args:
+    ///     name (str): The instruction name.
+    ///     args (Dict[str, Any]): The instruction args.
+    ///     accounts (Dict[str, Pubkey]): The instruction accounts, by name.
+    ///     remaining_accounts (Optional[Sequence[AccountMeta]]): Extra accounts appended after the IDL accounts.
+    ///     program_id (Optional[Pubkey]): The program ID. Defaults to the address in the IDL.
+    ///
+    /// Returns:
+    ///     Instruction: The instruction.
+    ///
+    #[pyo3(signature = (name, args, accounts, remaining_accounts=None, program_id=None))]
+    pub fn build_instruction(
+        &self,
+        name: &str,
+        args: &PyAny,
+        accounts: &PyAny,
+        remaining_accounts: Option<Vec<AccountMeta>>,
+        program_id: Option<Pubkey>,
+    ) -> PyResult<Instruction> {
+        let program_id = program_id.map(|p| p.0).or(self.0.address).ok_or_else(|| {
+            PyValueError::new_err("The IDL has no address, so program_id must be given")
+        })?;
+        let ix = self.0.instruction(name)?;
+        let data = self.encode_instruction_data_inner(name, args)?;
+        let mut metas = Vec::new();
+        collect_account_metas(&ix.accounts, accounts, &program_id, &mut metas)?;
+        metas.extend(
+            remaining_accounts
+                .unwrap_or_default()
+                .into_iter()
+                .map(AccountMetaOriginal::from),
+        );
+        Ok(Instruction(InstructionOriginal {
+            program_id,
+            accounts: metas,
+            data,
+        }))
+    }
+
+    /// Decode instruction data.
+    ///
+    /// Args:
+    ///     data (bytes): The instruction data, starting with the discriminator.
+    ///
+    /// Returns:
+    ///     Tuple[str, Dict[str, Any]]: The instruction name and its args.
+    ///
+    /// Raises:
+    ///     InstructionDecodeError: If the data doesn't match any instruction.
+    ///
+    pub fn decode_instruction(&self, py: Python<'_>, data: &[u8]) -> PyResult<(String, PyObject)> {
+        let ix = self
+            .0
+            .instructions
+            .iter()
+            .find(|ix| data.starts_with(&ix.discriminator))
+            .ok_or_else(|| InstructionDecodeError::new_err("Unknown instruction discriminator"))?;
+        let mut reader = Reader::new(&data[ix.discriminator.len()..]);
+        let args = PyDict::new(py);
+        for (arg_name, ty) in &ix.args {
+            let value = decode(py, &self.0, ty, &mut reader)
+                .map_err(|e| InstructionDecodeError::new_err(e.to_string()))?;
+            args.set_item(arg_name, value)?;
+        }
+        Ok((ix.name.clone(), args.into_py(py)))
+    }
+
+    /// Encode account data: the discriminator followed by the Borsh-encoded account.
+    ///
+    /// Args:
+    ///     name (str): The account name.
+    ///     value (Any): The account value.
+    ///
+    /// Returns:
+    ///     bytes: The account data.
+    ///
+    pub fn encode_account<'a>(
+        &self,
+        py: Python<'a>,
+        name: &str,
+        value: &PyAny,
+    ) -> PyResult<&'a PyBytes> {
+        let account = self.0.account(name)?;
+        let mut out = account.discriminator.clone();
+        encode_defined(&self.0, self.0.type_def(&account.name)?, value, &mut out, 0)?;
+        Ok(PyBytes::new(py, &out))
+    }
+
+    /// Decode account data.
+    ///
+    /// Trailing bytes after the account are ignored, since accounts are often
+    /// allocated with extra space.
+    ///
+    /// Args:
+    ///     data (bytes): The account data, starting with the discriminator.
+    ///     name (Optional[str]): The expected account name. If not given, the account
+    ///         is identified by its discriminator.
+    ///
+    /// Returns:
+    ///     Tuple[str, Any]: The account name and its decoded value.
+    ///
+    /// Raises:
+    ///     ValueError: If the discriminator doesn't match or the data is invalid.
+    ///
+    #[pyo3(signature = (data, name=None))]
+    pub fn decode_account(
+        &self,
+        py: Python<'_>,
+        data: &[u8],
+        name: Option<&str>,
+    ) -> PyResult<(String, PyObject)> {
+        let account = match name {
+            Some(n) => {
+                let account = self.0.account(n)?;
+                if !data.starts_with(&account.discriminator) {
+                    return Err(PyValueError::new_err(format!(
+                        "Account discriminator does not match {n}"
+                    )));
+                }
+                account
+            }
+            None => self
+                .0
+                .accounts
+                .iter()
+                .find(|a| data.starts_with(&a.discriminator))
+                .ok_or_else(|| PyValueError::new_err("Unknown account discriminator"))?,
+        };
+        let mut reader = Reader::new(&data[account.discriminator.len()..]);
+        let value = decode_defined(py, &self.0, self.0.type_def(&account.name)?, &mut reader)?;
+        Ok((account.name.clone(), value))
+    }
+
+    /// Borsh-encode a value of a type defined in the IDL.
+    ///
+    /// Args:
+    ///     name (str): The type name.
+    ///     value (Any): The value.
+    ///
+    /// Returns:
+    ///     bytes: The encoded value, without a discriminator.
+    ///
+    pub fn encode_type<'a>(
+        &self,
+        py: Python<'a>,
+        name: &str,
+        value: &PyAny,
+    ) -> PyResult<&'a PyBytes> {
+        let mut out = Vec::new();
+        encode_defined(&self.0, self.0.type_def(name)?, value, &mut out, 0)?;
+        Ok(PyBytes::new(py, &out))
+    }
+
+    /// Decode a Borsh-encoded value of a type defined in the IDL.
+    ///
+    /// Args:
+    ///     name (str): The type name.
+    ///     data (bytes): The encoded value, without a discriminator.
+    ///
+    /// Returns:
+    ///     Any: The decoded value.
+    ///
+    pub fn decode_type(&self, py: Python<'_>, name: &str, data: &[u8]) -> PyResult<PyObject> {
+        decode_defined(py, &self.0, self.0.type_def(name)?, &mut Reader::new(data))
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "Idl(name={:?}, version={:?}, address={})",
+            self.0.name,
+            self.0.version,
+            self.0
+                .address
+                .map_or_else(|| "None".to_string(), |a| a.to_string())
+        )
+    }
+
+    fn __eq__(&self, other: &Self) -> bool {
+        self == other
+    }
+}
+
+pub fn create_anchor_idl_mod(py: Python<'_>) -> PyResult<&PyModule> {
+    let m = PyModule::new(py, "anchor_idl")?;
+    m.add_class::<Idl>()?;
+    m.add_class::<AnchorEvent>()?;
+    m.add_class::<EventDecoder>()?;
+    Ok(m)
+}
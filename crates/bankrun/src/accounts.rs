@@ -0,0 +1,165 @@
+//! Multi-account lookups.
+//!
+//! The banks server can only look accounts up by address, so bankrun keeps an index
+//! of every address it has come across: genesis accounts, sysvars, features,
+//! accounts written through the ``ProgramTestContext`` and the writable accounts
+//! of every transaction sent. Any account a transaction creates or modifies is one
+//! of its writable accounts, so scanning the index finds every account a test can
+//! create or modify. Accounts that only the runtime changes (e.g. through epoch
+//! rewards) are covered only if their address was already indexed.
+use std::{
+    collections::BTreeSet,
+    sync::{Arc, Mutex},
+};
+
+use pyo3::{exceptions::PyValueError, prelude::*};
+use solana_banks_client::BanksClientError as BanksClientErrorOriginal;
+use solana_program_test::BanksClient as BanksClientOriginal;
+use solana_rpc_client_api::filter::RpcFilterType as RpcFilterTypeOriginal;
+use solana_sdk::{
+    account::{Account as AccountOriginal, AccountSharedData},
+    address_lookup_table::state::AddressLookupTable,
+    commitment_config::CommitmentLevel as CommitmentLevelOriginal,
+    feature_set::FEATURE_NAMES,
+    pubkey::Pubkey as PubkeyOriginal,
+    sysvar,
+};
+use solders_rpc_filter::RpcFilterType;
+
+use crate::TransactionType;
+
+/// The addresses bankrun knows about, shared between a ``ProgramTestContext``
+/// and the clients it hands out.
+#[derive(Clone, Default, Debug)]
+pub struct AccountIndex(Arc<Mutex<BTreeSet<PubkeyOriginal>>>);
+
+impl AccountIndex {
+    /// An index holding the sysvars, the known features and the given addresses.
+    pub fn with_genesis(addresses: impl IntoIterator<Item = PubkeyOriginal>) -> Self {
+        let index = Self::default();
+        {
+            let mut inner = index.lock();
+            inner.extend(sysvar::ALL_IDS.iter().copied());
+            inner.extend(FEATURE_NAMES.keys().copied());
+            inner.extend(addresses);
+        }
+        index
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, BTreeSet<PubkeyOriginal>> {
+        self.0.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    pub fn extend(&self, addresses: &[PubkeyOriginal]) {
+        self.lock().extend(addresses.iter().copied());
+    }
+
+    fn addresses(&self) -> Vec<PubkeyOriginal> {
+        self.lock().iter().copied().collect()
+    }
+}
+
+/// The addresses a transaction may write to, including those loaded from lookup tables.
+pub async fn writable_addresses(
+    client: &mut BanksClientOriginal,
+    transaction: &TransactionType,
+) -> Result<Vec<PubkeyOriginal>, BanksClientErrorOriginal> {
+    match transaction {
+        TransactionType::Legacy(t) => {
+            let msg = &t.0.message;
+            Ok(msg
+                .account_keys
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| msg.is_writable(*i))
+                .map(|(_, k)| *k)
+                .collect())
+        }
+        TransactionType::Versioned(t) => {
+            let msg = &t.0.message;
+            let mut addresses: Vec<PubkeyOriginal> = msg
+                .static_account_keys()
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| msg.is_maybe_writable(*i))
+                .map(|(_, k)| *k)
+                .collect();
+            for lookup in msg.address_table_lookups().unwrap_or_default() {
+                let table = client
+                    .get_account_with_commitment(
+                        lookup.account_key,
+                        CommitmentLevelOriginal::Processed,
+                    )
+                    .await?;
+                if let Some(table) = table {
+                    if let Ok(parsed) = AddressLookupTable::deserialize(&table.data) {
+                        addresses.extend(
+                            lookup
+                                .writable_indexes
+                                .iter()
+                                .filter_map(|i| parsed.addresses.get(usize::from(*i))),
+                        );
+                    }
+                }
+            }
+            Ok(addresses)
+        }
+    }
+}
+
+/// Convert and validate ``getProgramAccounts`` filters.
+pub fn convert_filters(
+    filters: Option<Vec<RpcFilterType>>,
+) -> PyResult<Vec<RpcFilterTypeOriginal>> {
+    filters
+        .unwrap_or_default()
+        .into_iter()
+        .map(|f| {
+            let filter = RpcFilterTypeOriginal::from(f);
+            filter
+                .verify()
+                .map_err(|e| PyValueError::new_err(format!("Invalid filter: {e}")))?;
+            Ok(filter)
+        })
+        .collect()
+}
+
+pub async fn get_multiple_accounts(
+    client: &mut BanksClientOriginal,
+    addresses: &[PubkeyOriginal],
+    commitment: CommitmentLevelOriginal,
+) -> Result<Vec<Option<AccountOriginal>>, BanksClientErrorOriginal> {
+    let mut accounts = Vec::with_capacity(addresses.len());
+    for address in addresses {
+        accounts.push(
+            client
+                .get_account_with_commitment(*address, commitment)
+                .await?,
+        );
+    }
+    Ok(accounts)
+}
+
+/// The indexed accounts owned by ``program_id`` that pass every filter, in address order.
+pub async fn get_program_accounts(
+    client: &mut BanksClientOriginal,
+    index: &AccountIndex,
+    program_id: PubkeyOriginal,
+    filters: &[RpcFilterTypeOriginal],
+    commitment: CommitmentLevelOriginal,
+) -> Result<Vec<(PubkeyOriginal, AccountOriginal)>, BanksClientErrorOriginal> {
+    let addresses = index.addresses();
+    let accounts = get_multiple_accounts(client, &addresses, commitment).await?;
+    Ok(addresses
+        .into_iter()
+        .zip(accounts)
+        .filter_map(|(address, account)| {
+            let account = account.filter(|a| a.owner == program_id)?;
+            let shared = AccountSharedData::from(account);
+            filters
+                .iter()
+                .all(|f| f.allows(&shared))
+                .then(|| (address, shared.into()))
+        })
+        .collect())
+}
@@ -0,0 +1,146 @@
+//! Reading the test setup of an Anchor workspace from its ``Anchor.toml``.
+use std::{
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use pyo3::{
+    exceptions::{PyFileNotFoundError, PyValueError},
+    prelude::*,
+};
+use solana_sdk::{account::Account as AccountOriginal, pubkey::Pubkey as PubkeyOriginal};
+use toml::{Table, Value};
+
+use crate::{
+    fixtures::{read_account_file, read_accounts},
+    programs,
+};
+
+/// The programs and accounts an ``Anchor.toml`` asks to be loaded for tests.
+#[derive(Debug, Default)]
+pub struct AnchorTestConfig {
+    /// Workspace programs, by name, to be found in ``target/deploy``.
+    pub programs: Vec<(String, PubkeyOriginal)>,
+    /// Accounts from ``[[test.genesis]]``, ``[[test.validator.account]]`` and ``[test.validator] clone``.
+    pub accounts: Vec<(PubkeyOriginal, AccountOriginal)>,
+}
+
+fn parse_pubkey(raw: &Value, context: &str) -> PyResult<PubkeyOriginal> {
+    raw.as_str()
+        .and_then(|s| PubkeyOriginal::from_str(s).ok())
+        .ok_or_else(|| PyValueError::new_err(format!("Invalid pubkey in `{context}`: {raw}")))
+}
+
+fn entries<'a>(table: &'a Table, path: &[&str]) -> PyResult<&'a [Value]> {
+    let mut current = table.get(path[0]);
+    for key in &path[1..] {
+        current = current.and_then(|v| v.get(key));
+    }
+    match current {
+        None => Ok(&[]),
+        Some(Value::Array(arr)) => Ok(arr),
+        Some(_) => Err(PyValueError::new_err(format!(
+            "`{}` must be an array of tables.",
+            path.join(".")
+        ))),
+    }
+}
+
+fn field<'a>(entry: &'a Value, key: &str, context: &str) -> PyResult<&'a Value> {
+    entry
+        .get(key)
+        .ok_or_else(|| PyValueError::new_err(format!("Missing `{key}` in `{context}` entry.")))
+}
+
+fn genesis_program(root: &Path, entry: &Value) -> PyResult<Vec<(PubkeyOriginal, AccountOriginal)>> {
+    let context = "test.genesis";
+    let program_id = parse_pubkey(field(entry, "address", context)?, context)?;
+    let program_path = field(entry, "program", context)?
+        .as_str()
+        .map(|p| root.join(p))
+        .ok_or_else(|| PyValueError::new_err("`program` in `test.genesis` must be a string."))?;
+    let elf = std::fs::read(&program_path)
+        .map_err(|e| PyFileNotFoundError::new_err(format!("{}: {e}", program_path.display())))?;
+    let upgradeable = entry
+        .get("upgradeable")
+        .and_then(Value::as_bool)
+        .unwrap_or(false);
+    let accounts = if upgradeable {
+        vec![
+            (
+                program_id,
+                programs::upgradeable_program_account(&program_id),
+            ),
+            (
+                programs::programdata_address(&program_id),
+                programs::programdata_account(&elf, elf.len(), 0, None),
+            ),
+        ]
+    } else {
+        vec![(program_id, programs::program_account(&elf))]
+    };
+    Ok(accounts
+        .into_iter()
+        .map(|(address, account)| (address, account.into()))
+        .collect())
+}
+
+/// Read the test setup from the ``Anchor.toml`` in ``root``.
+///
+/// Program ids are taken from ``[programs.<cluster>]``. Cloned accounts are looked up as
+/// ``<address>.json`` in ``clone_dir``, and skipped if no ``clone_dir`` is given.
+pub fn read_anchor_toml(
+    root: &Path,
+    cluster: &str,
+    clone_dir: Option<&Path>,
+) -> PyResult<AnchorTestConfig> {
+    let toml_path = root.join("Anchor.toml");
+    let toml_str = std::fs::read_to_string(toml_path)
+        .map_err(|e| PyFileNotFoundError::new_err(e.to_string()))?;
+    let parsed = Table::from_str(&toml_str)
+        .map_err(|e| PyValueError::new_err(format!("Failed to parse Anchor.toml: {e}")))?;
+    let programs_key = format!("programs.{cluster}");
+    let programs_table = parsed
+        .get("programs")
+        .and_then(|x| x.get(cluster))
+        .ok_or_else(|| PyValueError::new_err(format!("`{programs_key}` not found in Anchor.toml")))?
+        .as_table()
+        .ok_or_else(|| PyValueError::new_err(format!("Failed to parse `{programs_key}` table.")))?;
+    let mut config = AnchorTestConfig::default();
+    for (name, val) in programs_table {
+        config
+            .programs
+            .push((name.clone(), parse_pubkey(val, &programs_key)?));
+    }
+    for entry in entries(&parsed, &["test", "genesis"])? {
+        config.accounts.extend(genesis_program(root, entry)?);
+    }
+    let context = "test.validator.account";
+    for entry in entries(&parsed, &["test", "validator", "account"])? {
+        // Like ``solana-test-validator --account <address> <filename>``, the entry's address
+        // takes precedence over the pubkey stored in the file.
+        let address = parse_pubkey(field(entry, "address", context)?, context)?;
+        let filename = field(entry, "filename", context)?.as_str().ok_or_else(|| {
+            PyValueError::new_err("`filename` in `test.validator.account` must be a string.")
+        })?;
+        let (_, account) = read_account_file(&root.join(filename))?;
+        config.accounts.push((address, account));
+    }
+    if let Some(dir) = clone_dir {
+        let context = "test.validator.clone";
+        for entry in entries(&parsed, &["test", "validator", "clone"])? {
+            let address = parse_pubkey(field(entry, "address", context)?, context)?;
+            let file: PathBuf = dir.join(format!("{address}.json"));
+            if !file.is_file() {
+                return Err(PyFileNotFoundError::new_err(format!(
+                    "Cloned account {address} not found at {}. \
+                    Save it with `solana account {address} --output json -o {}`.",
+                    file.display(),
+                    file.display()
+                )));
+            }
+            config.accounts.extend(read_accounts(&file)?);
+        }
+    }
+    Ok(config)
+}
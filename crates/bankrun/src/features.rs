@@ -0,0 +1,66 @@
+//! Runtime feature gates.
+use std::collections::{HashMap, HashSet};
+
+use pyo3::{exceptions::PyValueError, prelude::*};
+use solana_banks_client::BanksClientError as BanksClientErrorOriginal;
+use solana_program_test::BanksClient as BanksClientOriginal;
+use solana_sdk::{
+    clock::Slot, commitment_config::CommitmentLevel as CommitmentLevelOriginal, feature,
+    feature_set::FEATURE_NAMES, pubkey::Pubkey as PubkeyOriginal,
+};
+use solders_pubkey::Pubkey;
+
+fn check_known(features: &[Pubkey]) -> PyResult<()> {
+    match features.iter().find(|f| !FEATURE_NAMES.contains_key(&f.0)) {
+        Some(unknown) => Err(PyValueError::new_err(format!(
+            "{} is not a known feature id.",
+            unknown.0
+        ))),
+        None => Ok(()),
+    }
+}
+
+/// Work out which features to leave out of genesis.
+///
+/// All known features are active by default. If ``activated`` is given,
+/// every known feature not in it is deactivated.
+pub fn features_to_deactivate(
+    activated: Option<Vec<Pubkey>>,
+    deactivated: Option<Vec<Pubkey>>,
+) -> PyResult<Vec<PubkeyOriginal>> {
+    let deactivated = deactivated.unwrap_or_default();
+    check_known(&deactivated)?;
+    let mut to_deactivate: HashSet<PubkeyOriginal> = deactivated.iter().map(|f| f.0).collect();
+    if let Some(activated) = activated {
+        check_known(&activated)?;
+        let keep: HashSet<PubkeyOriginal> = activated.iter().map(|f| f.0).collect();
+        if let Some(both) = keep.intersection(&to_deactivate).next() {
+            return Err(PyValueError::new_err(format!(
+                "Feature {both} is both activated and deactivated."
+            )));
+        }
+        to_deactivate.extend(FEATURE_NAMES.keys().filter(|f| !keep.contains(f)));
+    }
+    let mut sorted: Vec<PubkeyOriginal> = to_deactivate.into_iter().collect();
+    sorted.sort();
+    Ok(sorted)
+}
+
+/// The known features that are active, with the slot each was activated at.
+pub async fn active_features(
+    client: &mut BanksClientOriginal,
+) -> Result<HashMap<PubkeyOriginal, Slot>, BanksClientErrorOriginal> {
+    let mut active = HashMap::new();
+    for feature_id in FEATURE_NAMES.keys() {
+        let account = client
+            .get_account_with_commitment(*feature_id, CommitmentLevelOriginal::Processed)
+            .await?;
+        if let Some(slot) = account
+            .and_then(|a| feature::from_account(&a))
+            .and_then(|f| f.activated_at)
+        {
+            active.insert(*feature_id, slot);
+        }
+    }
+    Ok(active)
+}
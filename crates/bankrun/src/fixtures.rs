@@ -0,0 +1,98 @@
+//! Account fixtures in the JSON format written by ``solana account --output json``
+//! and read by ``solana-test-validator --account``.
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use pyo3::{
+    exceptions::{PyFileNotFoundError, PyValueError},
+    prelude::*,
+};
+use serde::{Deserialize, Serialize};
+use solana_account_decoder::{UiAccount, UiAccountEncoding};
+use solana_sdk::{account::Account as AccountOriginal, pubkey::Pubkey as PubkeyOriginal};
+use solders_account::Account;
+use solders_pubkey::Pubkey;
+
+#[derive(Serialize, Deserialize, Debug)]
+struct CliAccount {
+    pubkey: String,
+    account: UiAccount,
+}
+
+/// An account passed to ``start``: either an address and account, or a fixture path.
+#[derive(FromPyObject, Debug)]
+pub enum AccountSource {
+    Account(Pubkey, Account),
+    Path(PathBuf),
+}
+
+pub fn resolve_accounts(
+    sources: Vec<AccountSource>,
+) -> PyResult<Vec<(PubkeyOriginal, AccountOriginal)>> {
+    let mut accounts = vec![];
+    for source in sources {
+        match source {
+            AccountSource::Account(address, account) => {
+                accounts.push((address.0, AccountOriginal::from(account)))
+            }
+            AccountSource::Path(path) => accounts.extend(read_accounts(&path)?),
+        }
+    }
+    Ok(accounts)
+}
+
+fn not_found(path: &Path, e: std::io::Error) -> PyErr {
+    PyFileNotFoundError::new_err(format!("{}: {e}", path.display()))
+}
+
+/// Read a single account JSON file.
+pub fn read_account_file(path: &Path) -> PyResult<(PubkeyOriginal, AccountOriginal)> {
+    let raw = fs::read_to_string(path).map_err(|e| not_found(path, e))?;
+    let invalid = |msg: String| PyValueError::new_err(format!("{}: {msg}", path.display()));
+    let parsed: CliAccount = serde_json::from_str(&raw).map_err(|e| invalid(e.to_string()))?;
+    let address = PubkeyOriginal::from_str(&parsed.pubkey).map_err(|e| invalid(e.to_string()))?;
+    let account = parsed
+        .account
+        .decode::<AccountOriginal>()
+        .ok_or_else(|| invalid("unsupported account data encoding".to_string()))?;
+    Ok((address, account))
+}
+
+/// Read an account JSON file, or every ``.json`` file in a directory in file name order.
+pub fn read_accounts(path: &Path) -> PyResult<Vec<(PubkeyOriginal, AccountOriginal)>> {
+    if !path.is_dir() {
+        return Ok(vec![read_account_file(path)?]);
+    }
+    let mut files = fs::read_dir(path)
+        .map_err(|e| not_found(path, e))?
+        .map(|entry| entry.map(|e| e.path()))
+        .collect::<Result<Vec<PathBuf>, _>>()
+        .map_err(|e| not_found(path, e))?;
+    files.retain(|f| f.is_file() && f.extension().map_or(false, |ext| ext == "json"));
+    files.sort();
+    files.iter().map(|f| read_account_file(f)).collect()
+}
+
+/// Write an account JSON file. If ``path`` is a directory, the file is named ``<address>.json``.
+pub fn write_account(
+    path: &Path,
+    address: &PubkeyOriginal,
+    account: &AccountOriginal,
+) -> PyResult<PathBuf> {
+    let file = if path.is_dir() {
+        path.join(format!("{address}.json"))
+    } else {
+        path.to_path_buf()
+    };
+    let cli_account = CliAccount {
+        pubkey: address.to_string(),
+        account: UiAccount::encode(address, account, UiAccountEncoding::Base64, None, None),
+    };
+    let raw = serde_json::to_string_pretty(&cli_account)
+        .map_err(|e| PyValueError::new_err(e.to_string()))?;
+    fs::write(&file, raw).map_err(|e| not_found(&file, e))?;
+    Ok(file)
+}
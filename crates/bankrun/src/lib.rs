@@ -1,59 +1,255 @@
-use std::{path::PathBuf, str::FromStr};
-
-use derive_more::{From, Into};
+use accounts::{convert_filters, writable_addresses, AccountIndex};
+use anchor::read_anchor_toml;
+use features::features_to_deactivate;
+use fixtures::{resolve_accounts, AccountSource};
 use pyo3::{
     exceptions::{PyFileNotFoundError, PyValueError},
     prelude::*,
 };
+use snapshot::{BankSnapshot, SnapshotJournal};
 use solana_banks_client::BanksClientError as BanksClientErrorOriginal;
 use solders_account::Account;
+use solders_address_lookup_table_account::SlotHashes;
 use solders_banks_interface::{
     transaction_status_from_banks, BanksTransactionMeta, BanksTransactionResultWithMeta,
 };
 use solders_commitment_config::CommitmentLevel;
+use solders_custom_error::default_resolver;
 use solders_hash::Hash as SolderHash;
 use solders_keypair::Keypair;
 use solders_message::Message;
-use solders_primitives::{clock::Clock, rent::Rent};
+use solders_primitives::{
+    clock::Clock, epoch_rewards::EpochRewards, epoch_schedule::EpochSchedule, rent::Rent,
+    stake_history::StakeHistory,
+};
 use solders_pubkey::Pubkey;
+use solders_rpc_filter::RpcFilterType;
 use solders_signature::Signature;
-use solders_traits::{to_py_err, BanksClientError};
+use solders_traits::{to_py_err, BanksClientError, TransactionError};
 use solders_traits_core::to_py_value_err;
 use solders_transaction::{Transaction, VersionedTransaction};
+use std::{collections::HashMap, path::PathBuf};
+use sync_client::{block_on, SyncBanksClient};
 use tarpc::context::current;
-use toml::Table;
 use {
     solana_program_test::{
-        BanksClient as BanksClientOriginal, ProgramTest,
+        programs::spl_programs, BanksClient as BanksClientOriginal, ProgramTest,
         ProgramTestContext as ProgramTestContextOriginal,
     },
+    solana_rpc_client_api::filter::RpcFilterType as RpcFilterTypeOriginal,
     solana_sdk::{
         account::{Account as AccountOriginal, AccountSharedData},
-        clock::Clock as ClockOriginal,
+        bpf_loader_upgradeable,
+        clock::{Clock as ClockOriginal, Epoch},
         commitment_config::CommitmentLevel as CommitmentLevelOriginal,
+        epoch_schedule::EpochSchedule as EpochScheduleOriginal,
+        native_token::LAMPORTS_PER_SOL,
+        pubkey::Pubkey as PubkeyOriginal,
+        rent::Rent as RentOriginal,
+        signature::{Keypair as KeypairOriginal, Signer},
+        slot_hashes::SlotHashes as SlotHashesOriginal,
         slot_history::Slot,
+        transaction::Transaction as TransactionOriginal,
     },
 };
 
+mod accounts;
+mod anchor;
+mod features;
+mod fixtures;
+mod programs;
+mod snapshot;
+mod sync_client;
+
 macro_rules! async_res {
     ($fut:expr) => {
         $fut.await.map_err(to_py_err)
     };
 }
 
+#[derive(FromPyObject, Debug)]
+pub enum ProgramSource<'a> {
+    Bytes(&'a [u8]),
+    Path(PathBuf),
+}
+
+impl ProgramSource<'_> {
+    fn read(self) -> PyResult<Vec<u8>> {
+        match self {
+            Self::Bytes(b) => Ok(b.to_vec()),
+            Self::Path(path) => std::fs::read(&path)
+                .map_err(|e| PyFileNotFoundError::new_err(format!("{}: {e}", path.display()))),
+        }
+    }
+}
+
 #[derive(FromPyObject, Clone, PartialEq, Eq, Debug)]
 pub enum TransactionType {
     Legacy(Transaction),
     Versioned(VersionedTransaction),
 }
 
+/// Build a ``TransactionError`` that carries the metadata of the failed transaction
+/// in its ``meta`` attribute.
+fn transaction_error_with_meta(message: String, meta: Option<BanksTransactionMeta>) -> PyErr {
+    Python::with_gil(|py| {
+        let err = TransactionError::new_err(message);
+        match err.value(py).setattr("meta", meta.into_py(py)) {
+            Ok(()) => err,
+            Err(e) => e,
+        }
+    })
+}
+
+/// Index the accounts a transaction may write to and record their state in any live snapshots.
+async fn before_transaction(
+    client: &mut BanksClientOriginal,
+    journal: &SnapshotJournal,
+    index: &AccountIndex,
+    transaction: &TransactionType,
+) -> PyResult<()> {
+    let addresses = async_res!(writable_addresses(client, transaction))?;
+    index.extend(&addresses);
+    async_res!(journal.record_current(client, &addresses))
+}
+
+async fn send_transaction_inner(
+    mut client: BanksClientOriginal,
+    journal: SnapshotJournal,
+    index: AccountIndex,
+    transaction: TransactionType,
+) -> PyResult<()> {
+    before_transaction(&mut client, &journal, &index, &transaction).await?;
+    let res = match transaction {
+        TransactionType::Legacy(t) => client.send_transaction(t.0).await,
+        TransactionType::Versioned(t) => client.send_transaction(t.0).await,
+    };
+    res.map_err(to_py_err)
+}
+
+async fn process_transaction_inner(
+    mut client: BanksClientOriginal,
+    journal: SnapshotJournal,
+    index: AccountIndex,
+    transaction: TransactionType,
+) -> PyResult<BanksTransactionMeta> {
+    before_transaction(&mut client, &journal, &index, &transaction).await?;
+    let awaited = match transaction {
+        TransactionType::Legacy(t) => client.process_transaction_with_metadata(t.0).await,
+        TransactionType::Versioned(t) => client.process_transaction_with_metadata(t.0).await,
+    };
+    let res = awaited.map_err(to_py_err)?;
+    match res.result {
+        Err(e) => {
+            let log_messages = res
+                .metadata
+                .as_ref()
+                .map(|m| m.log_messages.clone())
+                .unwrap_or_default();
+            Err(transaction_error_with_meta(
+                default_resolver().describe_transaction_error(&e, &log_messages),
+                res.metadata.map(BanksTransactionMeta::from),
+            ))
+        }
+        Ok(()) => Ok(BanksTransactionMeta::from(res.metadata.unwrap())),
+    }
+}
+
+async fn process_transaction_with_metadata_inner(
+    mut client: BanksClientOriginal,
+    journal: SnapshotJournal,
+    index: AccountIndex,
+    transaction: TransactionType,
+) -> PyResult<BanksTransactionResultWithMeta> {
+    before_transaction(&mut client, &journal, &index, &transaction).await?;
+    let awaited = match transaction {
+        TransactionType::Legacy(t) => client.process_transaction_with_metadata(t.0).await,
+        TransactionType::Versioned(t) => client.process_transaction_with_metadata(t.0).await,
+    };
+    awaited
+        .map(BanksTransactionResultWithMeta::from)
+        .map_err(to_py_err)
+}
+
+async fn simulate_transaction_inner(
+    mut client: BanksClientOriginal,
+    transaction: TransactionType,
+    commitment: CommitmentLevelOriginal,
+) -> PyResult<BanksTransactionResultWithMeta> {
+    let awaited = match transaction {
+        TransactionType::Legacy(t) => {
+            client
+                .simulate_transaction_with_commitment(t.0, commitment)
+                .await
+        }
+        TransactionType::Versioned(t) => {
+            client
+                .simulate_transaction_with_commitment(t.0, commitment)
+                .await
+        }
+    };
+    awaited
+        .map(BanksTransactionResultWithMeta::from)
+        .map_err(to_py_err)
+}
+
+async fn get_multiple_accounts_inner(
+    mut client: BanksClientOriginal,
+    addresses: Vec<Pubkey>,
+    commitment: CommitmentLevelOriginal,
+) -> PyResult<Vec<Option<Account>>> {
+    let addresses_inner: Vec<PubkeyOriginal> = addresses.iter().map(|a| a.0).collect();
+    let accounts = async_res!(accounts::get_multiple_accounts(
+        &mut client,
+        &addresses_inner,
+        commitment
+    ))?;
+    Ok(accounts
+        .into_iter()
+        .map(|acc| acc.map(Account::from))
+        .collect())
+}
+
+async fn get_program_accounts_inner(
+    mut client: BanksClientOriginal,
+    index: AccountIndex,
+    program_id: Pubkey,
+    filters: Vec<RpcFilterTypeOriginal>,
+    commitment: CommitmentLevelOriginal,
+) -> PyResult<Vec<(Pubkey, Account)>> {
+    let accounts = async_res!(accounts::get_program_accounts(
+        &mut client,
+        &index,
+        program_id.0,
+        &filters,
+        commitment
+    ))?;
+    Ok(accounts
+        .into_iter()
+        .map(|(address, account)| (address.into(), account.into()))
+        .collect())
+}
+
+async fn get_latest_blockhash_inner(
+    mut client: BanksClientOriginal,
+    commitment: CommitmentLevelOriginal,
+) -> PyResult<(SolderHash, u64)> {
+    match async_res!(client.get_latest_blockhash_with_commitment(commitment))? {
+        Some((blockhash, last_valid_block_height)) => {
+            Ok((SolderHash::from(blockhash), last_valid_block_height))
+        }
+        None => Err(to_py_err(BanksClientErrorOriginal::ClientError(
+            "valid blockhash not found",
+        ))),
+    }
+}
+
 /// A client for the ledger state, from the perspective of an arbitrary validator.
 ///
 /// The client is used to send transactions and query account data, among other things.
 /// Use ``bankrun.start()`` to initialize a BanksClient.
 #[pyclass(module = "solders.bankrun", subclass)]
-#[derive(From, Into)]
-pub struct BanksClient(BanksClientOriginal);
+pub struct BanksClient(BanksClientOriginal, SnapshotJournal, AccountIndex);
 
 #[pymethods]
 impl BanksClient {
@@ -67,54 +263,69 @@ impl BanksClient {
         py: Python<'p>,
         transaction: TransactionType,
     ) -> PyResult<&'p PyAny> {
-        let mut underlying = self.0.clone();
+        let underlying = self.0.clone();
+        let journal = self.1.clone();
+        let index = self.2.clone();
         pyo3_asyncio::tokio::future_into_py(py, async move {
-            let res = match transaction {
-                TransactionType::Legacy(t) => underlying.send_transaction(t.0).await,
-                TransactionType::Versioned(t) => underlying.send_transaction(t.0).await,
-            };
-            let pyobj: PyResult<PyObject> =
-                Python::with_gil(|py| res.map_err(to_py_err).map(|x| x.into_py(py)));
+            let res = send_transaction_inner(underlying, journal, index, transaction).await;
+            let pyobj: PyResult<PyObject> = Python::with_gil(|py| res.map(|x| x.into_py(py)));
             pyobj
         })
     }
 
     /// Process a transaction and return the transaction metadata, raising any errors.
     ///
+    /// If the transaction fails, the raised ``TransactionError`` has a ``meta`` attribute
+    /// holding the ``BanksTransactionMeta`` of the failed transaction (logs, return data
+    /// and compute units consumed), or ``None`` if the transaction was not executed.
+    ///
     /// Args:
     ///     transaction (Transaction | VersionedTransaction): The transaction to send.
     ///
     /// Returns:
-    ///     BanksTransactionResultWithMeta: The transaction result and metadata.
+    ///     BanksTransactionMeta: The transaction metadata.
     ///
     pub fn process_transaction<'p>(
         &'p mut self,
         py: Python<'p>,
         transaction: TransactionType,
     ) -> PyResult<&'p PyAny> {
-        let mut underlying = self.0.clone();
+        let underlying = self.0.clone();
+        let journal = self.1.clone();
+        let index = self.2.clone();
         pyo3_asyncio::tokio::future_into_py(py, async move {
-            let awaited = match transaction {
-                TransactionType::Legacy(t) => {
-                    underlying.process_transaction_with_metadata(t.0).await
-                }
-                TransactionType::Versioned(t) => {
-                    underlying.process_transaction_with_metadata(t.0).await
-                }
-            };
-            let res = awaited.map_err(to_py_err);
-            let meta = match res {
-                Ok(r) => match r.result {
-                    Err(e) => Err(to_py_err(e)),
-                    Ok(()) => Ok(BanksTransactionMeta::from(r.metadata.unwrap())),
-                },
-                Err(e) => Err(e),
-            };
+            let meta = process_transaction_inner(underlying, journal, index, transaction).await;
             let pyobj: PyResult<PyObject> = Python::with_gil(|py| meta.map(|x| x.into_py(py)));
             pyobj
         })
     }
 
+    /// Process a transaction and return the result and metadata, without raising
+    /// if the transaction fails.
+    ///
+    /// Args:
+    ///     transaction (Transaction | VersionedTransaction): The transaction to send.
+    ///
+    /// Returns:
+    ///     BanksTransactionResultWithMeta: The transaction result and metadata.
+    ///
+    pub fn process_transaction_with_metadata<'p>(
+        &'p mut self,
+        py: Python<'p>,
+        transaction: TransactionType,
+    ) -> PyResult<&'p PyAny> {
+        let underlying = self.0.clone();
+        let journal = self.1.clone();
+        let index = self.2.clone();
+        pyo3_asyncio::tokio::future_into_py(py, async move {
+            let res =
+                process_transaction_with_metadata_inner(underlying, journal, index, transaction)
+                    .await;
+            let pyobj: PyResult<PyObject> = Python::with_gil(|py| res.map(|x| x.into_py(py)));
+            pyobj
+        })
+    }
+
     /// Simulate a transaction at the given commitment level.
     ///
     /// Args:
@@ -131,24 +342,10 @@ impl BanksClient {
         commitment: Option<CommitmentLevel>,
     ) -> PyResult<&'p PyAny> {
         let commitment_inner = CommitmentLevelOriginal::from(commitment.unwrap_or_default());
-        let mut underlying = self.0.clone();
+        let underlying = self.0.clone();
         pyo3_asyncio::tokio::future_into_py(py, async move {
-            let awaited = match transaction {
-                TransactionType::Legacy(t) => {
-                    underlying
-                        .simulate_transaction_with_commitment(t.0, commitment_inner)
-                        .await
-                }
-                TransactionType::Versioned(t) => {
-                    underlying
-                        .simulate_transaction_with_commitment(t.0, commitment_inner)
-                        .await
-                }
-            };
-            let res = awaited.map_err(to_py_err);
-            let pyobj: PyResult<PyObject> = Python::with_gil(|py| {
-                res.map(|x| BanksTransactionResultWithMeta::from(x).into_py(py))
-            });
+            let res = simulate_transaction_inner(underlying, transaction, commitment_inner).await;
+            let pyobj: PyResult<PyObject> = Python::with_gil(|py| res.map(|x| x.into_py(py)));
             pyobj
         })
     }
@@ -181,6 +378,77 @@ impl BanksClient {
         })
     }
 
+    /// Return the accounts at the given addresses, like ``get_account`` for each of them.
+    ///
+    /// Args:
+    ///     addresses (Sequence[Pubkey]): The account addresses to look up.
+    ///     commitment (Optional[CommitmentLevel]): The commitment level to use.
+    ///
+    /// Returns:
+    ///     List[Optional[Account]]: The account objects, with None for accounts that don't exist.
+    ///
+    pub fn get_multiple_accounts<'p>(
+        &mut self,
+        py: Python<'p>,
+        addresses: Vec<Pubkey>,
+        commitment: Option<CommitmentLevel>,
+    ) -> PyResult<&'p PyAny> {
+        let commitment_inner = CommitmentLevelOriginal::from(commitment.unwrap_or_default());
+        let underlying = self.0.clone();
+        pyo3_asyncio::tokio::future_into_py(py, async move {
+            let res = get_multiple_accounts_inner(underlying, addresses, commitment_inner).await;
+            let pyobj: PyResult<PyObject> = Python::with_gil(|py| res.map(|x| x.into_py(py)));
+            pyobj
+        })
+    }
+
+    /// Return the accounts owned by a program, like the ``getProgramAccounts`` RPC method.
+    ///
+    /// The banks server can't scan the bank, so this looks at every account bankrun has seen:
+    /// the genesis accounts (including the validator's vote and stake accounts and the
+    /// bundled SPL programs), sysvars, feature accounts, accounts written through the
+    /// ``ProgramTestContext`` and the writable accounts of every transaction sent through
+    /// a client. That covers every account a test can create or modify. Accounts that only
+    /// the runtime touches, such as those created by epoch rewards, are only found if they
+    /// were already indexed.
+    ///
+    /// Args:
+    ///     program_id (Pubkey): The owner of the accounts.
+    ///     filters (Optional[Sequence[int | Memcmp | RpcFilterTypeFieldless]]): Filters the accounts
+    ///         must all pass, as in ``RpcProgramAccountsConfig``.
+    ///     commitment (Optional[CommitmentLevel]): The commitment level to use.
+    ///
+    /// Returns:
+    ///     List[Tuple[Pubkey, Account]]: The matching accounts, ordered by address.
+    ///
+    /// Raises:
+    ///     ValueError: If a filter is invalid.
+    ///
+    pub fn get_program_accounts<'p>(
+        &mut self,
+        py: Python<'p>,
+        program_id: Pubkey,
+        filters: Option<Vec<RpcFilterType>>,
+        commitment: Option<CommitmentLevel>,
+    ) -> PyResult<&'p PyAny> {
+        let filters_inner = convert_filters(filters)?;
+        let commitment_inner = CommitmentLevelOriginal::from(commitment.unwrap_or_default());
+        let underlying = self.0.clone();
+        let index = self.2.clone();
+        pyo3_asyncio::tokio::future_into_py(py, async move {
+            let res = get_program_accounts_inner(
+                underlying,
+                index,
+                program_id,
+                filters_inner,
+                commitment_inner,
+            )
+            .await;
+            let pyobj: PyResult<PyObject> = Python::with_gil(|py| res.map(|x| x.into_py(py)));
+            pyobj
+        })
+    }
+
     /// Return the status of a transaction with a signature matching the transaction's first
     /// signature.
     ///
@@ -357,21 +625,11 @@ impl BanksClient {
         py: Python<'p>,
         commitment: Option<CommitmentLevel>,
     ) -> PyResult<&'p PyAny> {
-        let mut underlying = self.0.clone();
+        let underlying = self.0.clone();
         let commitment_inner = CommitmentLevelOriginal::from(commitment.unwrap_or_default());
         pyo3_asyncio::tokio::future_into_py(py, async move {
-            let res = async_res!(underlying.get_latest_blockhash_with_commitment(commitment_inner));
-            let flattened = match res {
-                Ok(v) => match v {
-                    Some(x) => Ok(x),
-                    None => Err(to_py_err(BanksClientErrorOriginal::ClientError(
-                        "valid blockhash not found",
-                    ))),
-                },
-                Err(e) => Err(e),
-            };
-            let pyobj: PyResult<PyObject> =
-                Python::with_gil(|py| flattened.map(|x| (SolderHash::from(x.0), x.1).into_py(py)));
+            let res = get_latest_blockhash_inner(underlying, commitment_inner).await;
+            let pyobj: PyResult<PyObject> = Python::with_gil(|py| res.map(|x| x.into_py(py)));
             pyobj
         })
     }
@@ -407,15 +665,63 @@ impl BanksClient {
     }
 }
 
+/// The balance of the payer created by ``ProgramTest``.
+const DEFAULT_PAYER_LAMPORTS: u64 = 1_000_000 * LAMPORTS_PER_SOL;
+
+/// Start settings applied before and after ``ProgramTest`` sets up the bank.
+struct StartOptions {
+    payer: Option<Keypair>,
+    payer_lamports: Option<u64>,
+    rent: Option<RentOriginal>,
+    deactivated_features: Vec<PubkeyOriginal>,
+}
+
+impl StartOptions {
+    fn payer_account(lamports: u64) -> AccountOriginal {
+        AccountOriginal::new(lamports, 0, &PubkeyOriginal::default())
+    }
+
+    /// Fund a caller-supplied payer in the genesis accounts and leave out deactivated features.
+    fn prepare(&self, pt: &mut ProgramTest) {
+        for feature_id in &self.deactivated_features {
+            pt.deactivate_feature(*feature_id);
+        }
+        if let Some(payer) = &self.payer {
+            pt.add_account(
+                payer.0.pubkey(),
+                Self::payer_account(self.payer_lamports.unwrap_or(DEFAULT_PAYER_LAMPORTS)),
+            );
+        }
+    }
+
+    fn apply(self, inner: &mut ProgramTestContextOriginal) {
+        match self.payer {
+            Some(payer) => inner.payer = payer.0,
+            None => {
+                if let Some(lamports) = self.payer_lamports {
+                    let payer = inner.payer.pubkey();
+                    inner.set_account(&payer, &Self::payer_account(lamports).into());
+                }
+            }
+        }
+        if let Some(rent) = self.rent {
+            inner.set_sysvar(&rent);
+        }
+    }
+}
+
 fn new_bankrun(
     programs: Vec<(&str, Pubkey)>,
     compute_max_units: Option<u64>,
     transaction_account_lock_limit: Option<usize>,
-    accounts: Vec<(Pubkey, Account)>,
-) -> ProgramTest {
+    accounts: Vec<(PubkeyOriginal, AccountOriginal)>,
+    options: &StartOptions,
+) -> (ProgramTest, AccountIndex) {
     let mut pt = ProgramTest::default();
     pt.prefer_bpf(true);
+    let mut genesis_addresses: Vec<PubkeyOriginal> = accounts.iter().map(|(a, _)| *a).collect();
     for prog in programs {
+        genesis_addresses.push(prog.1.into());
         pt.add_program(prog.0, prog.1.into(), None);
     }
     if let Some(cmu) = compute_max_units {
@@ -424,10 +730,68 @@ fn new_bankrun(
     if let Some(lock_lim) = transaction_account_lock_limit {
         pt.set_transaction_account_lock_limit(lock_lim);
     }
-    for acc in accounts {
-        pt.add_account(acc.0.into(), acc.1.into());
+    for (address, account) in accounts {
+        pt.add_account(address, account);
     }
-    pt
+    options.prepare(&mut pt);
+    (pt, AccountIndex::with_genesis(genesis_addresses))
+}
+
+fn start_bankrun(
+    py: Python<'_>,
+    pt: ProgramTest,
+    index: AccountIndex,
+    options: StartOptions,
+) -> PyResult<&PyAny> {
+    pyo3_asyncio::tokio::future_into_py(py, async move {
+        let mut inner = pt.start_with_context().await;
+        options.apply(&mut inner);
+        let res: PyResult<PyObject> =
+            Python::with_gil(|py| Ok(ProgramTestContext::new(inner, index).into_py(py)));
+        res
+    })
+}
+
+fn start_bankrun_sync(
+    py: Python<'_>,
+    pt: ProgramTest,
+    index: AccountIndex,
+    options: StartOptions,
+) -> ProgramTestContext {
+    let inner = block_on(py, async move {
+        let mut inner = pt.start_with_context().await;
+        options.apply(&mut inner);
+        inner
+    });
+    ProgramTestContext::new(inner, index)
+}
+
+#[allow(clippy::too_many_arguments)]
+fn new_bankrun_with_options(
+    programs: Option<Vec<(&str, Pubkey)>>,
+    accounts: Option<Vec<AccountSource>>,
+    compute_max_units: Option<u64>,
+    transaction_account_lock_limit: Option<usize>,
+    payer: Option<Keypair>,
+    payer_lamports: Option<u64>,
+    rent: Option<Rent>,
+    activated_features: Option<Vec<Pubkey>>,
+    deactivated_features: Option<Vec<Pubkey>>,
+) -> PyResult<(ProgramTest, AccountIndex, StartOptions)> {
+    let options = StartOptions {
+        payer,
+        payer_lamports,
+        rent: rent.map(|r| r.0),
+        deactivated_features: features_to_deactivate(activated_features, deactivated_features)?,
+    };
+    let (pt, index) = new_bankrun(
+        programs.unwrap_or_default(),
+        compute_max_units,
+        transaction_account_lock_limit,
+        resolve_accounts(accounts.unwrap_or_default())?,
+        &options,
+    );
+    Ok((pt, index, options))
 }
 
 /// Start a bankrun!
@@ -439,35 +803,92 @@ fn new_bankrun(
 ///     programs (Optional[Sequence[Tuple[str, Pubkey]]]): A sequence of (program_name, program_id) tuples
 ///         indicating which programs to deploy to the test environment. See the main bankrun docs for more explanation
 ///         on how to add programs.
-///     accounts (Optional[Sequence[Tuple[Pubkey, Account]]]): A sequence of (address, account_object) tuples, indicating
-///         what data to write to the given addresses.
+///     accounts (Optional[Sequence[Union[Tuple[Pubkey, Account], str, os.PathLike]]]): A sequence of (address, account_object) tuples, indicating
+///         what data to write to the given addresses. Paths to account JSON files, as written by
+///         ``solana account --output json`` or ``ProgramTestContext.dump_account``, or to directories of them, may be given too.
 ///     compute_max_units (Optional[int]): Override the default compute unit limit for a transaction.
 ///     transaction_account_lock_limit (Optional[int]): Override the default transaction account lock limit.
-///
+///     payer (Optional[Keypair]): Use this keypair as the payer instead of a random one.
+///     payer_lamports (Optional[int]): The starting balance of the payer. Defaults to 1,000,000 SOL.
+///     rent (Optional[Rent]): Overwrite the rent sysvar once the bank has started, like ``ProgramTestContext.set_rent``.
+///         Only programs see the new rent: the runtime keeps enforcing rent exemption with the default rent.
+///         Note the fee rate, ticks per slot and epoch schedule of the bank itself are fixed by ``solana-program-test``.
+///     activated_features (Optional[Sequence[Pubkey]]): If given, only these runtime features are active.
+///         By default all features are active.
+///     deactivated_features (Optional[Sequence[Pubkey]]): Runtime features to deactivate.
 ///
 /// Returns:
 ///     ProgramTestContext: a container for stuff you'll need to send transactions and interact with the test environment.
+///
+/// Raises:
+///     ValueError: If a feature id is unknown, or both activated and deactivated.
 ///     
 #[pyfunction]
+#[allow(clippy::too_many_arguments)]
 pub fn start<'p>(
     py: Python<'p>,
     programs: Option<Vec<(&str, Pubkey)>>,
-    accounts: Option<Vec<(Pubkey, Account)>>,
+    accounts: Option<Vec<AccountSource>>,
     compute_max_units: Option<u64>,
     transaction_account_lock_limit: Option<usize>,
+    payer: Option<Keypair>,
+    payer_lamports: Option<u64>,
+    rent: Option<Rent>,
+    activated_features: Option<Vec<Pubkey>>,
+    deactivated_features: Option<Vec<Pubkey>>,
 ) -> PyResult<&'p PyAny> {
-    let pt = new_bankrun(
-        programs.unwrap_or_default(),
+    let (pt, index, options) = new_bankrun_with_options(
+        programs,
+        accounts,
         compute_max_units,
         transaction_account_lock_limit,
-        accounts.unwrap_or_default(),
-    );
-    pyo3_asyncio::tokio::future_into_py(py, async move {
-        let inner = pt.start_with_context().await;
-        let res: PyResult<PyObject> =
-            Python::with_gil(|py| Ok(ProgramTestContext(inner).into_py(py)));
-        res
-    })
+        payer,
+        payer_lamports,
+        rent,
+        activated_features,
+        deactivated_features,
+    )?;
+    start_bankrun(py, pt, index, options)
+}
+
+/// Start a bankrun without ``asyncio``.
+///
+/// Takes the same arguments as ``start``, but blocks until the bank is ready and
+/// returns the ``ProgramTestContext`` directly. Use its ``sync_banks_client``
+/// to talk to the bank without awaiting anything.
+///
+/// Returns:
+///     ProgramTestContext: a container for stuff you'll need to send transactions and interact with the test environment.
+///
+/// Raises:
+///     ValueError: If a feature id is unknown, or both activated and deactivated.
+///
+#[pyfunction]
+#[allow(clippy::too_many_arguments)]
+pub fn start_sync(
+    py: Python<'_>,
+    programs: Option<Vec<(&str, Pubkey)>>,
+    accounts: Option<Vec<AccountSource>>,
+    compute_max_units: Option<u64>,
+    transaction_account_lock_limit: Option<usize>,
+    payer: Option<Keypair>,
+    payer_lamports: Option<u64>,
+    rent: Option<Rent>,
+    activated_features: Option<Vec<Pubkey>>,
+    deactivated_features: Option<Vec<Pubkey>>,
+) -> PyResult<ProgramTestContext> {
+    let (pt, index, options) = new_bankrun_with_options(
+        programs,
+        accounts,
+        compute_max_units,
+        transaction_account_lock_limit,
+        payer,
+        payer_lamports,
+        rent,
+        activated_features,
+        deactivated_features,
+    )?;
+    Ok(start_bankrun_sync(py, pt, index, options))
 }
 
 /// Start a bankrun in an Anchor workspace, with all the workspace programs deployed.
@@ -475,82 +896,210 @@ pub fn start<'p>(
 /// This will spin up a BanksServer and a BanksClient,
 /// deploy programs and add accounts as instructed.
 ///
+/// Besides the workspace programs, the programs in ``[[test.genesis]]`` and the accounts in
+/// ``[[test.validator.account]]`` are loaded, with paths relative to the workspace root.
+/// Accounts listed in ``[test.validator] clone`` are loaded from ``clone_dir``.
+///
 /// Args:
 ///     path (pathlib.Path): Path to root of the Anchor project.
 ///     extra_programs (Optional[Sequence[Tuple[str, Pubkey]]]): A sequence of (program_name, program_id) tuples
 ///         indicating extra programs to deploy alongside the Anchor workspace programs. See the main bankrun docs for more explanation
 ///         on how to add programs.
-///     accounts (Optional[Sequence[Tuple[Pubkey, Account]]]): A sequence of (address, account_object) tuples, indicating
-///         what data to write to the given addresses.
+///     accounts (Optional[Sequence[Union[Tuple[Pubkey, Account], str, os.PathLike]]]): A sequence of (address, account_object) tuples, indicating
+///         what data to write to the given addresses. Paths to account JSON files, as written by
+///         ``solana account --output json`` or ``ProgramTestContext.dump_account``, or to directories of them, may be given too.
+///         These take precedence over accounts from Anchor.toml.
 ///     compute_max_units (Optional[int]): Override the default compute unit limit for a transaction.
 ///     transaction_account_lock_limit (Optional[int]): Override the default transaction account lock limit.
+///     cluster (str): Which ``[programs.<cluster>]`` table to take the workspace program ids from,
+///         e.g. ``"devnet"`` or ``"mainnet"``. Defaults to ``"localnet"``.
+///     clone_dir (Optional[pathlib.Path]): A directory holding ``<address>.json`` account files
+///         for the accounts to clone. If not given, clone entries are skipped.
+///     payer (Optional[Keypair]): Use this keypair as the payer instead of a random one.
+///     payer_lamports (Optional[int]): The starting balance of the payer. Defaults to 1,000,000 SOL.
+///     rent (Optional[Rent]): Overwrite the rent sysvar once the bank has started, like ``ProgramTestContext.set_rent``.
+///         Only programs see the new rent: the runtime keeps enforcing rent exemption with the default rent.
+///     activated_features (Optional[Sequence[Pubkey]]): If given, only these runtime features are active.
+///         By default all features are active.
+///     deactivated_features (Optional[Sequence[Pubkey]]): Runtime features to deactivate.
 ///
 /// Returns:
 ///     ProgramTestContext: a container for stuff you'll need to send transactions and interact with the test environment.
+///
+/// Raises:
+///     FileNotFoundError: If Anchor.toml, or a program or account file it refers to, can't be found.
+///     ValueError: If Anchor.toml is invalid, or a feature id is unknown.
 ///     
 #[pyfunction]
+#[pyo3(signature = (path, extra_programs=None, accounts=None, compute_max_units=None, transaction_account_lock_limit=None, cluster="localnet", clone_dir=None, payer=None, payer_lamports=None, rent=None, activated_features=None, deactivated_features=None))]
+#[allow(clippy::too_many_arguments)]
 pub fn start_anchor<'p>(
     py: Python<'p>,
     path: PathBuf,
     extra_programs: Option<Vec<(&str, Pubkey)>>,
-    accounts: Option<Vec<(Pubkey, Account)>>,
+    accounts: Option<Vec<AccountSource>>,
     compute_max_units: Option<u64>,
     transaction_account_lock_limit: Option<usize>,
+    cluster: &str,
+    clone_dir: Option<PathBuf>,
+    payer: Option<Keypair>,
+    payer_lamports: Option<u64>,
+    rent: Option<Rent>,
+    activated_features: Option<Vec<Pubkey>>,
+    deactivated_features: Option<Vec<Pubkey>>,
 ) -> PyResult<&'p PyAny> {
+    let options = StartOptions {
+        payer,
+        payer_lamports,
+        rent: rent.map(|r| r.0),
+        deactivated_features: features_to_deactivate(activated_features, deactivated_features)?,
+    };
+    let config = read_anchor_toml(&path, cluster, clone_dir.as_deref())?;
     let mut programs = extra_programs.unwrap_or_default();
-    let mut anchor_toml_path = path.clone();
-    let mut sbf_out_dir = path;
-    sbf_out_dir.push("target/deploy");
-    anchor_toml_path.push("Anchor.toml");
-    let toml_str = std::fs::read_to_string(anchor_toml_path)
-        .map_err(|e| PyFileNotFoundError::new_err(e.to_string()))?;
-    let parsed_toml = Table::from_str(&toml_str).unwrap();
-    let toml_programs_raw = parsed_toml
-        .get("programs")
-        .and_then(|x| x.get("localnet"))
-        .ok_or_else(|| PyValueError::new_err("`programs.localnet` not found in Anchor.toml"))?;
-    let toml_programs_parsed = toml_programs_raw
-        .as_table()
-        .ok_or_else(|| PyValueError::new_err("Failed to parse `programs.localnet` table."))?;
-    for (key, val) in toml_programs_parsed {
-        let pubkey_with_quotes = val.to_string();
-        let pubkey_str = &pubkey_with_quotes[1..pubkey_with_quotes.len() - 1];
-        let pk = Pubkey::new_from_str(pubkey_str).map_err(|_| {
-            PyValueError::new_err(format!(
-                "Invalid pubkey in `programs.localnet` table. {}",
-                val
-            ))
-        })?;
-        programs.push((key, pk));
-    }
-    std::env::set_var("SBF_OUT_DIR", sbf_out_dir);
-    let pt = new_bankrun(
+    programs.extend(
+        config
+            .programs
+            .iter()
+            .map(|(name, program_id)| (name.as_str(), Pubkey(*program_id))),
+    );
+    let mut all_accounts = config.accounts;
+    all_accounts.extend(resolve_accounts(accounts.unwrap_or_default())?);
+    std::env::set_var("SBF_OUT_DIR", path.join("target/deploy"));
+    let (pt, index) = new_bankrun(
         programs,
         compute_max_units,
         transaction_account_lock_limit,
-        accounts.unwrap_or_default(),
+        all_accounts,
+        &options,
     );
-    pyo3_asyncio::tokio::future_into_py(py, async move {
-        let inner = pt.start_with_context().await;
-        let res: PyResult<PyObject> =
-            Python::with_gil(|py| Ok(ProgramTestContext(inner).into_py(py)));
-        res
-    })
+    start_bankrun(py, pt, index, options)
 }
 
 /// The result of calling `bankrun.start()`.
 ///
 /// Contains a BanksClient, a recent blockhash and a funded payer keypair.
 #[pyclass(module = "solders.bankrun", subclass)]
-#[derive(From, Into)]
-pub struct ProgramTestContext(pub ProgramTestContextOriginal);
+pub struct ProgramTestContext(
+    pub ProgramTestContextOriginal,
+    SnapshotJournal,
+    AccountIndex,
+);
+
+impl ProgramTestContext {
+    pub fn new(inner: ProgramTestContextOriginal, index: AccountIndex) -> Self {
+        let genesis_addresses: Vec<PubkeyOriginal> =
+            inner.genesis_config().accounts.keys().copied().collect();
+        index.extend(&genesis_addresses);
+        // The bundled SPL programs are stored in the bank after genesis. Only their
+        // addresses are needed here, so the rent used to build the accounts doesn't matter.
+        let spl_addresses: Vec<PubkeyOriginal> = spl_programs(&RentOriginal::default())
+            .into_iter()
+            .map(|(address, _)| address)
+            .collect();
+        index.extend(&spl_addresses);
+        index.extend(&[inner.payer.pubkey()]);
+        Self(inner, SnapshotJournal::default(), index)
+    }
+
+    /// Index accounts that are about to be overwritten and record their current state
+    /// in any live snapshots.
+    fn record_before_write(&self, addresses: &[PubkeyOriginal]) -> PyResult<()> {
+        self.2.extend(addresses);
+        if !self.1.is_active() {
+            return Ok(());
+        }
+        let mut client = self.0.banks_client.clone();
+        Python::with_gil(|py| block_on(py, self.1.record_current(&mut client, addresses)))
+            .map_err(to_py_err)
+    }
+
+    fn get_account_blocking(&self, address: PubkeyOriginal) -> PyResult<Option<AccountOriginal>> {
+        let mut client = self.0.banks_client.clone();
+        Python::with_gil(|py| {
+            block_on(
+                py,
+                client.get_account_with_commitment(address, CommitmentLevelOriginal::Processed),
+            )
+        })
+        .map_err(to_py_err)
+    }
+
+    fn write_accounts(&mut self, accounts: &[(PubkeyOriginal, AccountSharedData)]) -> PyResult<()> {
+        let addresses: Vec<PubkeyOriginal> = accounts.iter().map(|(a, _)| *a).collect();
+        self.record_before_write(&addresses)?;
+        for (address, account) in accounts {
+            self.0.set_account(address, account);
+        }
+        Ok(())
+    }
+
+    /// Upgrade a deployed program by sending the loader's ``Upgrade`` instruction,
+    /// since the runtime keeps serving a cached program whose accounts are overwritten directly.
+    fn upgrade_program(
+        &mut self,
+        program_id: PubkeyOriginal,
+        elf: &[u8],
+        upgrade_authority: PubkeyOriginal,
+    ) -> PyResult<()> {
+        let payer = self.0.payer.pubkey();
+        let programdata_address = programs::programdata_address(&program_id);
+        let buffer_address = KeypairOriginal::new().pubkey();
+        // Hand the upgrade authority to the payer so it can sign the upgrade.
+        self.write_accounts(&[
+            (
+                programdata_address,
+                programs::programdata_account(&[], elf.len(), 0, Some(payer)),
+            ),
+            (buffer_address, programs::buffer_account(elf, payer)),
+        ])?;
+        self.record_before_write(&[payer, program_id])?;
+        let mut client = self.0.banks_client.clone();
+        let blockhash = Python::with_gil(|py| block_on(py, client.get_latest_blockhash()))
+            .map_err(to_py_err)?;
+        let ix = bpf_loader_upgradeable::upgrade(&program_id, &buffer_address, &payer, &payer);
+        let tx = TransactionOriginal::new_signed_with_payer(
+            &[ix],
+            Some(&payer),
+            &[&self.0.payer],
+            blockhash,
+        );
+        Python::with_gil(|py| block_on(py, client.process_transaction(tx))).map_err(to_py_err)?;
+        if upgrade_authority != payer {
+            let mut programdata = self
+                .get_account_blocking(programdata_address)?
+                .map(AccountSharedData::from)
+                .ok_or_else(|| {
+                    PyValueError::new_err("Programdata account missing after upgrade.")
+                })?;
+            programs::set_upgrade_authority(&mut programdata, Some(upgrade_authority));
+            self.0.set_account(&programdata_address, &programdata);
+        }
+        // An upgraded program only becomes visible in the next slot.
+        let slot = Python::with_gil(|py| {
+            block_on(
+                py,
+                client.get_slot_with_context(current(), CommitmentLevelOriginal::Processed),
+            )
+        })
+        .map_err(to_py_err)?;
+        self.0
+            .warp_to_slot(slot + 1)
+            .map_err(|e| to_py_value_err(&e))
+    }
+}
 
 #[pymethods]
 impl ProgramTestContext {
     /// BanksClient: The client for this test.
     #[getter]
     pub fn banks_client(&self) -> BanksClient {
-        self.0.banks_client.clone().into()
+        BanksClient(self.0.banks_client.clone(), self.1.clone(), self.2.clone())
+    }
+
+    /// SyncBanksClient: A blocking client for this test, for use outside of ``asyncio``.
+    #[getter]
+    pub fn sync_banks_client(&self) -> SyncBanksClient {
+        SyncBanksClient::new(self.0.banks_client.clone(), self.1.clone(), self.2.clone())
     }
 
     /// Hash: The last blockhash registered when the client was initialized.
@@ -591,11 +1140,177 @@ impl ProgramTestContext {
     ///     address (Pubkey): The address to write to.
     ///     account (Account): The account object to write.
     ///
-    pub fn set_account(&mut self, address: &Pubkey, account: Account) {
+    pub fn set_account(&mut self, address: &Pubkey, account: Account) -> PyResult<()> {
+        self.record_before_write(&[address.0])?;
         self.0.set_account(
             address.as_ref(),
             &AccountSharedData::from(AccountOriginal::from(account)),
         );
+        Ok(())
+    }
+
+    /// Get the runtime features that are active.
+    ///
+    /// Returns:
+    ///     Dict[Pubkey, int]: The active feature ids, mapped to the slot each was activated at.
+    ///
+    pub fn get_active_features(&self) -> PyResult<HashMap<Pubkey, Slot>> {
+        let mut client = self.0.banks_client.clone();
+        let active = Python::with_gil(|py| block_on(py, features::active_features(&mut client)))
+            .map_err(to_py_err)?;
+        Ok(active
+            .into_iter()
+            .map(|(feature_id, slot)| (feature_id.into(), slot))
+            .collect())
+    }
+
+    /// Write the accounts in an account JSON file, or a directory of them, into the bank.
+    ///
+    /// Files use the format written by ``solana account --output json`` and read by
+    /// ``solana-test-validator --account``. In a directory, every ``.json`` file is loaded.
+    ///
+    /// Args:
+    ///     path (Union[str, os.PathLike]): The file or directory to load.
+    ///
+    /// Returns:
+    ///     List[Tuple[Pubkey, Account]]: The loaded accounts.
+    ///
+    /// Raises:
+    ///     FileNotFoundError: If a file can't be read.
+    ///     ValueError: If a file isn't a valid account JSON file.
+    ///
+    pub fn load_accounts(&mut self, path: PathBuf) -> PyResult<Vec<(Pubkey, Account)>> {
+        let accounts = fixtures::read_accounts(&path)?;
+        let shared: Vec<(PubkeyOriginal, AccountSharedData)> = accounts
+            .iter()
+            .map(|(address, account)| (*address, AccountSharedData::from(account.clone())))
+            .collect();
+        self.write_accounts(&shared)?;
+        Ok(accounts
+            .into_iter()
+            .map(|(address, account)| (address.into(), account.into()))
+            .collect())
+    }
+
+    /// Save an account to a JSON file that ``start`` and ``load_accounts`` can load.
+    ///
+    /// Args:
+    ///     address (Pubkey): The account address.
+    ///     path (Union[str, os.PathLike]): The file to write. If this is a directory,
+    ///         the file is written to ``<address>.json`` inside it.
+    ///
+    /// Returns:
+    ///     str: The path of the written file.
+    ///
+    /// Raises:
+    ///     ValueError: If the account doesn't exist.
+    ///
+    pub fn dump_account(&self, address: &Pubkey, path: PathBuf) -> PyResult<String> {
+        let account = self
+            .get_account_blocking(address.0)?
+            .ok_or_else(|| PyValueError::new_err(format!("Account {} not found.", address.0)))?;
+        let file = fixtures::write_account(&path, &address.0, &account)?;
+        Ok(file.to_string_lossy().into_owned())
+    }
+
+    /// Deploy a program into the running bank, or upgrade an already deployed upgradeable program.
+    ///
+    /// New programs are written straight into the bank. An upgradeable program that is
+    /// already deployed is upgraded with a real ``Upgrade`` instruction paid for by the payer,
+    /// after which the bank is warped forward one slot so the new code takes effect.
+    /// Programs owned by the non-upgradeable loader can't be replaced once deployed.
+    ///
+    /// Args:
+    ///     program_id (Pubkey): The program address.
+    ///     program (Union[bytes, str, os.PathLike]): The program ELF, or the path to a ``.so`` file.
+    ///     upgradeable (bool): Deploy with the upgradeable BPF loader, writing the programdata account too.
+    ///         Otherwise the non-upgradeable BPF loader is used.
+    ///     upgrade_authority (Optional[Pubkey]): The upgrade authority of an upgradeable program.
+    ///         Defaults to the payer.
+    ///
+    /// Raises:
+    ///     FileNotFoundError: If the program file can't be read.
+    ///     ValueError: If a program that can't be replaced is already deployed at ``program_id``.
+    ///     BanksClientError: If the upgrade transaction fails, e.g. because the ELF is invalid.
+    ///
+    #[pyo3(signature = (program_id, program, upgradeable=false, upgrade_authority=None))]
+    pub fn add_program(
+        &mut self,
+        program_id: &Pubkey,
+        program: ProgramSource,
+        upgradeable: bool,
+        upgrade_authority: Option<Pubkey>,
+    ) -> PyResult<()> {
+        let elf = program.read()?;
+        let program_id = program_id.0;
+        let upgrade_authority = upgrade_authority.map_or_else(|| self.0.payer.pubkey(), |a| a.0);
+        let deployed = self
+            .get_account_blocking(program_id)?
+            .filter(|a| a.executable);
+        match deployed {
+            Some(existing) if upgradeable && existing.owner == bpf_loader_upgradeable::id() => {
+                self.upgrade_program(program_id, &elf, upgrade_authority)
+            }
+            Some(existing) => Err(PyValueError::new_err(format!(
+                "Program {program_id} is already deployed with loader {} and can't be replaced. \
+                Only upgradeable programs can be upgraded, by passing upgradeable=True.",
+                existing.owner
+            ))),
+            None if upgradeable => self.write_accounts(&[
+                (
+                    program_id,
+                    programs::upgradeable_program_account(&program_id),
+                ),
+                (
+                    programs::programdata_address(&program_id),
+                    programs::programdata_account(&elf, elf.len(), 0, Some(upgrade_authority)),
+                ),
+            ]),
+            None => self.write_accounts(&[(program_id, programs::program_account(&elf))]),
+        }
+    }
+
+    /// Take a snapshot of the bank's accounts, to roll back to later with ``restore``.
+    ///
+    /// Taking a snapshot is cheap: an account's state is only recorded just before it is
+    /// first written to, by a transaction sent through this context's ``banks_client``
+    /// or by ``set_account``. The slot, sysvars and transaction status cache are not rolled back,
+    /// so re-sending an identical transaction after ``restore`` needs a fresh blockhash.
+    ///
+    /// Returns:
+    ///     BankSnapshot: The snapshot. Recording stops once it is garbage collected.
+    ///
+    /// Example:
+    ///     >>> from solders.bankrun import start
+    ///     >>> async def example():
+    ///     ...     context = await start()
+    ///     ...     snapshot = context.snapshot()
+    ///     ...     # send transactions...
+    ///     ...     context.restore(snapshot)
+    ///
+    pub fn snapshot(&self) -> BankSnapshot {
+        BankSnapshot::new(&self.1)
+    }
+
+    /// Roll the bank's accounts back to the state they had when a snapshot was taken.
+    ///
+    /// Args:
+    ///     snapshot (BankSnapshot): A snapshot taken from this context.
+    ///
+    /// Raises:
+    ///     ValueError: If the snapshot was taken from a different context.
+    ///
+    pub fn restore(&mut self, snapshot: &BankSnapshot) -> PyResult<()> {
+        let states = snapshot.states_for(&self.1).ok_or_else(|| {
+            PyValueError::new_err("The snapshot was taken from a different context.")
+        })?;
+        let addresses: Vec<PubkeyOriginal> = states.keys().copied().collect();
+        self.record_before_write(&addresses)?;
+        for (address, account) in states {
+            let data = account.map_or_else(AccountSharedData::default, AccountSharedData::from);
+            self.0.set_account(&address, &data);
+        }
+        Ok(())
     }
 
     /// Overwrite the clock sysvar.
@@ -616,7 +1331,49 @@ impl ProgramTestContext {
         self.0.set_sysvar(&rent.0)
     }
 
-    /// Force the working bank ahead to a new slot
+    /// Overwrite the epoch schedule sysvar.
+    ///
+    /// This only changes what programs see. The bank keeps using its own epoch schedule,
+    /// e.g. in ``warp_to_epoch``.
+    ///
+    /// Args:
+    ///     epoch_schedule (EpochSchedule): The new epoch schedule object.
+    ///
+    pub fn set_epoch_schedule(&mut self, epoch_schedule: EpochSchedule) {
+        self.0
+            .set_sysvar(&EpochScheduleOriginal::from(epoch_schedule))
+    }
+
+    /// Overwrite the epoch rewards sysvar.
+    ///
+    /// Args:
+    ///     epoch_rewards (EpochRewards): The new epoch rewards object.
+    ///
+    pub fn set_epoch_rewards(&mut self, epoch_rewards: &EpochRewards) {
+        self.0.set_sysvar(&epoch_rewards.0)
+    }
+
+    /// Overwrite the stake history sysvar.
+    ///
+    /// Args:
+    ///     stake_history (StakeHistory): The new stake history object.
+    ///
+    pub fn set_stake_history(&mut self, stake_history: &StakeHistory) {
+        self.0.set_sysvar(&stake_history.0)
+    }
+
+    /// Overwrite the slot hashes sysvar.
+    ///
+    /// Args:
+    ///     slot_hashes (SlotHashes): The new slot hashes object.
+    ///
+    pub fn set_slot_hashes(&mut self, slot_hashes: SlotHashes) {
+        self.0.set_sysvar(&SlotHashesOriginal::from(slot_hashes))
+    }
+
+    /// Force the working bank ahead to a new slot.
+    ///
+    /// ``last_blockhash`` is updated to the new bank's blockhash.
     ///
     /// Args:
     ///     warp_slot (int): The slot to warp to.
@@ -626,16 +1383,43 @@ impl ProgramTestContext {
             .warp_to_slot(warp_slot)
             .map_err(|e| to_py_value_err(&e))
     }
+
+    /// Force the working bank ahead to the first slot of a new epoch.
+    ///
+    /// ``last_blockhash`` is updated to the new bank's blockhash.
+    ///
+    /// Args:
+    ///     warp_epoch (int): The epoch to warp to.
+    ///
+    pub fn warp_to_epoch(&mut self, warp_epoch: Epoch) -> PyResult<()> {
+        self.0
+            .warp_to_epoch(warp_epoch)
+            .map_err(|e| to_py_value_err(&e))
+    }
+
+    /// Warp forward one slot and force the end of the epoch rewards distribution interval.
+    ///
+    /// Useful after ``warp_to_epoch``, since stake accounts can't be modified while
+    /// rewards are being distributed. ``last_blockhash`` is updated to the new bank's blockhash.
+    ///
+    pub fn warp_forward_force_reward_interval_end(&mut self) -> PyResult<()> {
+        self.0
+            .warp_forward_force_reward_interval_end()
+            .map_err(|e| to_py_value_err(&e))
+    }
 }
 
 pub fn create_bankrun_mod(py: Python<'_>) -> PyResult<&PyModule> {
     let m = PyModule::new(py, "bankrun")?;
     m.add("BanksClientError", py.get_type::<BanksClientError>())?;
     m.add_class::<BanksClient>()?;
+    m.add_class::<SyncBanksClient>()?;
     m.add_class::<ProgramTestContext>()?;
+    m.add_class::<BankSnapshot>()?;
     m.add_class::<BanksTransactionResultWithMeta>()?;
     m.add_class::<BanksTransactionMeta>()?;
     m.add_function(wrap_pyfunction!(start, m)?)?;
     m.add_function(wrap_pyfunction!(start_anchor, m)?)?;
+    m.add_function(wrap_pyfunction!(start_sync, m)?)?;
     Ok(m)
 }
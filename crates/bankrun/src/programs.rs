@@ -0,0 +1,106 @@
+//! Accounts for deploying programs into a running bank.
+use solana_sdk::{
+    account::{AccountSharedData, WritableAccount},
+    account_utils::StateMut,
+    bpf_loader, bpf_loader_upgradeable,
+    bpf_loader_upgradeable::UpgradeableLoaderState,
+    clock::Slot,
+    pubkey::Pubkey as PubkeyOriginal,
+    rent::Rent,
+};
+
+fn rent_exempt_account(
+    len: usize,
+    owner: &PubkeyOriginal,
+    state: Option<&UpgradeableLoaderState>,
+) -> AccountSharedData {
+    let lamports = Rent::default().minimum_balance(len).max(1);
+    let mut account = AccountSharedData::new(lamports, len, owner);
+    if let Some(state) = state {
+        account
+            .set_state(state)
+            .expect("account data is sized for its loader state");
+    }
+    account
+}
+
+/// The executable account of a program owned by the non-upgradeable BPF loader.
+pub fn program_account(elf: &[u8]) -> AccountSharedData {
+    let mut account = rent_exempt_account(elf.len(), &bpf_loader::id(), None);
+    account.data_as_mut_slice().copy_from_slice(elf);
+    account.set_executable(true);
+    account
+}
+
+pub fn programdata_address(program_id: &PubkeyOriginal) -> PubkeyOriginal {
+    PubkeyOriginal::find_program_address(&[program_id.as_ref()], &bpf_loader_upgradeable::id()).0
+}
+
+/// The program account of an upgradeable program, pointing at its programdata account.
+pub fn upgradeable_program_account(program_id: &PubkeyOriginal) -> AccountSharedData {
+    let state = UpgradeableLoaderState::Program {
+        programdata_address: programdata_address(program_id),
+    };
+    let mut account = rent_exempt_account(
+        UpgradeableLoaderState::size_of_program(),
+        &bpf_loader_upgradeable::id(),
+        Some(&state),
+    );
+    account.set_executable(true);
+    account
+}
+
+/// A programdata account holding ``elf``, with room for a program of ``capacity`` bytes.
+pub fn programdata_account(
+    elf: &[u8],
+    capacity: usize,
+    slot: Slot,
+    upgrade_authority_address: Option<PubkeyOriginal>,
+) -> AccountSharedData {
+    let metadata_len = UpgradeableLoaderState::size_of_programdata_metadata();
+    let state = UpgradeableLoaderState::ProgramData {
+        slot,
+        upgrade_authority_address,
+    };
+    let mut account = rent_exempt_account(
+        metadata_len + capacity.max(elf.len()),
+        &bpf_loader_upgradeable::id(),
+        Some(&state),
+    );
+    account.data_as_mut_slice()[metadata_len..metadata_len + elf.len()].copy_from_slice(elf);
+    account
+}
+
+/// A buffer account holding ``elf``, ready to be passed to the loader's ``Upgrade`` instruction.
+pub fn buffer_account(elf: &[u8], authority_address: PubkeyOriginal) -> AccountSharedData {
+    let metadata_len = UpgradeableLoaderState::size_of_buffer_metadata();
+    let state = UpgradeableLoaderState::Buffer {
+        authority_address: Some(authority_address),
+    };
+    let mut account = rent_exempt_account(
+        metadata_len + elf.len(),
+        &bpf_loader_upgradeable::id(),
+        Some(&state),
+    );
+    account.data_as_mut_slice()[metadata_len..].copy_from_slice(elf);
+    account
+}
+
+/// Overwrite the upgrade authority stored in a programdata account.
+pub fn set_upgrade_authority(
+    programdata: &mut AccountSharedData,
+    upgrade_authority_address: Option<PubkeyOriginal>,
+) {
+    let metadata_len = UpgradeableLoaderState::size_of_programdata_metadata();
+    let slot = match programdata.state() {
+        Ok(UpgradeableLoaderState::ProgramData { slot, .. }) => slot,
+        _ => 0,
+    };
+    programdata.data_as_mut_slice()[..metadata_len].fill(0);
+    programdata
+        .set_state(&UpgradeableLoaderState::ProgramData {
+            slot,
+            upgrade_authority_address,
+        })
+        .expect("programdata has room for its metadata");
+}
@@ -0,0 +1,147 @@
+//! Copy-on-write snapshots of bank accounts.
+//!
+//! The test bank itself can't be cloned, so a snapshot instead records the state
+//! each account had when the snapshot was taken, just before that account is
+//! first written to. Restoring writes those states back.
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use pyo3::prelude::*;
+use solana_banks_client::BanksClientError as BanksClientErrorOriginal;
+use solana_program_test::BanksClient as BanksClientOriginal;
+use solana_sdk::{
+    account::Account as AccountOriginal,
+    commitment_config::CommitmentLevel as CommitmentLevelOriginal,
+    pubkey::Pubkey as PubkeyOriginal,
+};
+
+type AccountStates = HashMap<PubkeyOriginal, Option<AccountOriginal>>;
+
+#[derive(Default, Debug)]
+struct JournalInner {
+    snapshots: HashMap<u64, AccountStates>,
+    next_id: u64,
+}
+
+/// Shared between a ``ProgramTestContext`` and the ``BanksClient`` objects it hands out.
+#[derive(Clone, Default, Debug)]
+pub struct SnapshotJournal(Arc<Mutex<JournalInner>>);
+
+impl SnapshotJournal {
+    fn lock(&self) -> std::sync::MutexGuard<'_, JournalInner> {
+        self.0.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    pub fn is_active(&self) -> bool {
+        !self.lock().snapshots.is_empty()
+    }
+
+    /// The addresses whose current state some live snapshot hasn't recorded yet.
+    pub fn missing(&self, addresses: &[PubkeyOriginal]) -> Vec<PubkeyOriginal> {
+        let inner = self.lock();
+        let mut missing: Vec<PubkeyOriginal> = addresses
+            .iter()
+            .filter(|a| inner.snapshots.values().any(|s| !s.contains_key(a)))
+            .copied()
+            .collect();
+        missing.sort();
+        missing.dedup();
+        missing
+    }
+
+    pub fn record(&self, address: PubkeyOriginal, account: Option<AccountOriginal>) {
+        let mut inner = self.lock();
+        for states in inner.snapshots.values_mut() {
+            states.entry(address).or_insert_with(|| account.clone());
+        }
+    }
+
+    /// Record the current state of the given accounts in every live snapshot that lacks it.
+    pub async fn record_current(
+        &self,
+        client: &mut BanksClientOriginal,
+        addresses: &[PubkeyOriginal],
+    ) -> Result<(), BanksClientErrorOriginal> {
+        for address in self.missing(addresses) {
+            let account = client
+                .get_account_with_commitment(address, CommitmentLevelOriginal::Processed)
+                .await?;
+            self.record(address, account);
+        }
+        Ok(())
+    }
+
+    fn start(&self) -> u64 {
+        let mut inner = self.lock();
+        let id = inner.next_id;
+        inner.next_id += 1;
+        inner.snapshots.insert(id, HashMap::new());
+        id
+    }
+
+    fn states(&self, id: u64) -> Option<AccountStates> {
+        self.lock().snapshots.get(&id).cloned()
+    }
+
+    fn release(&self, id: u64) {
+        self.lock().snapshots.remove(&id);
+    }
+
+    fn same_as(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+/// A snapshot of the accounts in a test bank, taken with ``ProgramTestContext.snapshot()``.
+///
+/// Pass it to ``ProgramTestContext.restore()`` to roll accounts back to the state they had
+/// when the snapshot was taken. A snapshot can be restored any number of times.
+#[pyclass(module = "solders.bankrun", subclass)]
+#[derive(Debug)]
+pub struct BankSnapshot {
+    id: u64,
+    journal: SnapshotJournal,
+}
+
+impl BankSnapshot {
+    pub fn new(journal: &SnapshotJournal) -> Self {
+        Self {
+            id: journal.start(),
+            journal: journal.clone(),
+        }
+    }
+
+    /// The recorded account states, if this snapshot belongs to the given journal.
+    pub fn states_for(&self, journal: &SnapshotJournal) -> Option<AccountStates> {
+        if self.journal.same_as(journal) {
+            self.journal.states(self.id)
+        } else {
+            None
+        }
+    }
+}
+
+impl Drop for BankSnapshot {
+    fn drop(&mut self) {
+        self.journal.release(self.id);
+    }
+}
+
+#[pymethods]
+impl BankSnapshot {
+    /// int: The number of accounts whose state the snapshot has recorded,
+    /// i.e. that may have changed since it was taken.
+    #[getter]
+    pub fn num_recorded_accounts(&self) -> usize {
+        self.journal.states(self.id).map_or(0, |s| s.len())
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "BankSnapshot(num_recorded_accounts={})",
+            self.num_recorded_accounts()
+        )
+    }
+}
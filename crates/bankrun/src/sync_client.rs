@@ -0,0 +1,405 @@
+//! A blocking counterpart to ``BanksClient``.
+use std::future::Future;
+
+use pyo3::prelude::*;
+use solana_program_test::BanksClient as BanksClientOriginal;
+use solana_sdk::{
+    clock::Clock as ClockOriginal, commitment_config::CommitmentLevel as CommitmentLevelOriginal,
+    slot_history::Slot,
+};
+use solders_account::Account;
+use solders_banks_interface::{
+    transaction_status_from_banks, BanksTransactionMeta, BanksTransactionResultWithMeta,
+};
+use solders_commitment_config::CommitmentLevel;
+use solders_hash::Hash as SolderHash;
+use solders_message::Message;
+use solders_primitives::{clock::Clock, rent::Rent};
+use solders_pubkey::Pubkey;
+use solders_rpc_filter::RpcFilterType;
+use solders_signature::Signature;
+use solders_traits::to_py_err;
+use solders_transaction_status_struct::TransactionStatus;
+use tarpc::context::current;
+
+use crate::{
+    accounts::{convert_filters, AccountIndex},
+    get_latest_blockhash_inner, get_multiple_accounts_inner, get_program_accounts_inner,
+    process_transaction_inner, process_transaction_with_metadata_inner, send_transaction_inner,
+    simulate_transaction_inner,
+    snapshot::SnapshotJournal,
+    TransactionType,
+};
+
+/// Run a future on the bankrun tokio runtime, releasing the GIL while it runs.
+pub fn block_on<F>(py: Python<'_>, fut: F) -> F::Output
+where
+    F: Future + Send,
+    F::Output: Send,
+{
+    py.allow_threads(|| pyo3_asyncio::tokio::get_runtime().block_on(fut))
+}
+
+/// A blocking version of ``BanksClient``, for use outside of ``asyncio``.
+///
+/// It has the same methods as ``BanksClient``, but they return their results directly.
+/// The GIL is released while waiting for the bank.
+/// Use ``ProgramTestContext.sync_banks_client`` to get a SyncBanksClient.
+#[pyclass(module = "solders.bankrun", subclass)]
+pub struct SyncBanksClient(BanksClientOriginal, SnapshotJournal, AccountIndex);
+
+impl SyncBanksClient {
+    pub fn new(client: BanksClientOriginal, journal: SnapshotJournal, index: AccountIndex) -> Self {
+        Self(client, journal, index)
+    }
+}
+
+#[pymethods]
+impl SyncBanksClient {
+    /// Send a transaction and return immediately.
+    ///
+    /// Args:
+    ///     transaction (Transaction | VersionedTransaction): The transaction to send.
+    ///
+    pub fn send_transaction(&self, py: Python<'_>, transaction: TransactionType) -> PyResult<()> {
+        block_on(
+            py,
+            send_transaction_inner(self.0.clone(), self.1.clone(), self.2.clone(), transaction),
+        )
+    }
+
+    /// Process a transaction and return the transaction metadata, raising any errors.
+    ///
+    /// As with ``BanksClient.process_transaction``, a raised ``TransactionError`` has
+    /// the metadata of the failed transaction in its ``meta`` attribute.
+    ///
+    /// Args:
+    ///     transaction (Transaction | VersionedTransaction): The transaction to send.
+    ///
+    /// Returns:
+    ///     BanksTransactionMeta: The transaction metadata.
+    ///
+    pub fn process_transaction(
+        &self,
+        py: Python<'_>,
+        transaction: TransactionType,
+    ) -> PyResult<BanksTransactionMeta> {
+        block_on(
+            py,
+            process_transaction_inner(self.0.clone(), self.1.clone(), self.2.clone(), transaction),
+        )
+    }
+
+    /// Process a transaction and return the result and metadata, without raising
+    /// if the transaction fails.
+    ///
+    /// Args:
+    ///     transaction (Transaction | VersionedTransaction): The transaction to send.
+    ///
+    /// Returns:
+    ///     BanksTransactionResultWithMeta: The transaction result and metadata.
+    ///
+    pub fn process_transaction_with_metadata(
+        &self,
+        py: Python<'_>,
+        transaction: TransactionType,
+    ) -> PyResult<BanksTransactionResultWithMeta> {
+        block_on(
+            py,
+            process_transaction_with_metadata_inner(
+                self.0.clone(),
+                self.1.clone(),
+                self.2.clone(),
+                transaction,
+            ),
+        )
+    }
+
+    /// Simulate a transaction at the given commitment level.
+    ///
+    /// Args:
+    ///     transaction (Transaction | VersionedTransaction): The transaction to simulate.
+    ///     commitment (Optional[CommitmentLevel]): The commitment level to use.
+    ///
+    /// Returns:
+    ///     BanksTransactionResultWithMeta: The transaction simulation result.
+    ///
+    pub fn simulate_transaction(
+        &self,
+        py: Python<'_>,
+        transaction: TransactionType,
+        commitment: Option<CommitmentLevel>,
+    ) -> PyResult<BanksTransactionResultWithMeta> {
+        let commitment_inner = CommitmentLevelOriginal::from(commitment.unwrap_or_default());
+        block_on(
+            py,
+            simulate_transaction_inner(self.0.clone(), transaction, commitment_inner),
+        )
+    }
+
+    /// Return the account at the given address at the slot corresponding to the given
+    /// commitment level. If the account is not found, None is returned.
+    ///
+    /// Args:
+    ///     address (Pubkey): The account address to look up.
+    ///     commitment (Optional[CommitmentLevel]): The commitment level to use.
+    ///
+    /// Returns:
+    ///     Optional[Account]: The account object, if the account exists
+    ///
+    pub fn get_account(
+        &self,
+        py: Python<'_>,
+        address: Pubkey,
+        commitment: Option<CommitmentLevel>,
+    ) -> PyResult<Option<Account>> {
+        let commitment_inner = CommitmentLevelOriginal::from(commitment.unwrap_or_default());
+        let mut client = self.0.clone();
+        block_on(
+            py,
+            client.get_account_with_commitment(address.0, commitment_inner),
+        )
+        .map(|acc| acc.map(Account::from))
+        .map_err(to_py_err)
+    }
+
+    /// Return the accounts at the given addresses, like ``get_account`` for each of them.
+    ///
+    /// Args:
+    ///     addresses (Sequence[Pubkey]): The account addresses to look up.
+    ///     commitment (Optional[CommitmentLevel]): The commitment level to use.
+    ///
+    /// Returns:
+    ///     List[Optional[Account]]: The account objects, with None for accounts that don't exist.
+    ///
+    pub fn get_multiple_accounts(
+        &self,
+        py: Python<'_>,
+        addresses: Vec<Pubkey>,
+        commitment: Option<CommitmentLevel>,
+    ) -> PyResult<Vec<Option<Account>>> {
+        let commitment_inner = CommitmentLevelOriginal::from(commitment.unwrap_or_default());
+        block_on(
+            py,
+            get_multiple_accounts_inner(self.0.clone(), addresses, commitment_inner),
+        )
+    }
+
+    /// Return the accounts owned by a program, like the ``getProgramAccounts`` RPC method.
+    ///
+    /// See ``BanksClient.get_program_accounts`` for which accounts are scanned.
+    ///
+    /// Args:
+    ///     program_id (Pubkey): The owner of the accounts.
+    ///     filters (Optional[Sequence[int | Memcmp | RpcFilterTypeFieldless]]): Filters the accounts
+    ///         must all pass, as in ``RpcProgramAccountsConfig``.
+    ///     commitment (Optional[CommitmentLevel]): The commitment level to use.
+    ///
+    /// Returns:
+    ///     List[Tuple[Pubkey, Account]]: The matching accounts, ordered by address.
+    ///
+    /// Raises:
+    ///     ValueError: If a filter is invalid.
+    ///
+    pub fn get_program_accounts(
+        &self,
+        py: Python<'_>,
+        program_id: Pubkey,
+        filters: Option<Vec<RpcFilterType>>,
+        commitment: Option<CommitmentLevel>,
+    ) -> PyResult<Vec<(Pubkey, Account)>> {
+        let filters_inner = convert_filters(filters)?;
+        let commitment_inner = CommitmentLevelOriginal::from(commitment.unwrap_or_default());
+        block_on(
+            py,
+            get_program_accounts_inner(
+                self.0.clone(),
+                self.2.clone(),
+                program_id,
+                filters_inner,
+                commitment_inner,
+            ),
+        )
+    }
+
+    /// Return the status of a transaction with a signature matching the transaction's first
+    /// signature.
+    ///
+    /// Args:
+    ///     signature (Signature): The transaction signature (the first signature of the transaction).
+    ///
+    /// Returns:
+    ///     Optional[TransactionStatus]: The transaction status, if found.
+    ///
+    pub fn get_transaction_status(
+        &self,
+        py: Python<'_>,
+        signature: Signature,
+    ) -> PyResult<Option<TransactionStatus>> {
+        let mut client = self.0.clone();
+        block_on(py, client.get_transaction_status(signature.0))
+            .map(|s| s.map(transaction_status_from_banks))
+            .map_err(to_py_err)
+    }
+
+    /// Same as ``get_transaction_status``, but for multiple transactions.
+    ///
+    /// Args:
+    ///     signatures (Sequence[Signature]): The transaction signatures.
+    ///
+    /// Returns:
+    ///     List[Optional[TransactionStatus]]: The transaction statuses, if found.
+    ///
+    pub fn get_transaction_statuses(
+        &self,
+        py: Python<'_>,
+        signatures: Vec<Signature>,
+    ) -> PyResult<Vec<Option<TransactionStatus>>> {
+        let mut client = self.0.clone();
+        let signatures_inner = signatures.iter().map(|x| x.0).collect();
+        block_on(py, client.get_transaction_statuses(signatures_inner))
+            .map(|v| {
+                v.into_iter()
+                    .map(|s| s.map(transaction_status_from_banks))
+                    .collect()
+            })
+            .map_err(to_py_err)
+    }
+
+    /// Get the slot that has reached the given commitment level (or the default commitment).
+    ///
+    /// Args:
+    ///     commitment (Optional[CommitmentLevel]): The commitment level to use.
+    ///
+    /// Returns:
+    ///     int: The current slot.
+    ///
+    pub fn get_slot(&self, py: Python<'_>, commitment: Option<CommitmentLevel>) -> PyResult<Slot> {
+        let mut client = self.0.clone();
+        let commitment_inner = CommitmentLevelOriginal::from(commitment.unwrap_or_default());
+        block_on(
+            py,
+            client.get_slot_with_context(current(), commitment_inner),
+        )
+        .map_err(to_py_err)
+    }
+
+    /// Get the current block height.
+    ///
+    /// Args:
+    ///     commitment (Optional[CommitmentLevel]): The commitment level to use.
+    ///
+    /// Returns:
+    ///     int: The current block height.
+    ///
+    pub fn get_block_height(
+        &self,
+        py: Python<'_>,
+        commitment: Option<CommitmentLevel>,
+    ) -> PyResult<u64> {
+        let mut client = self.0.clone();
+        let commitment_inner = CommitmentLevelOriginal::from(commitment.unwrap_or_default());
+        block_on(
+            py,
+            client.get_block_height_with_context(current(), commitment_inner),
+        )
+        .map_err(to_py_err)
+    }
+
+    /// Get the cluster rent.
+    ///
+    /// Returns:
+    ///     Rent: The rent object.
+    ///
+    pub fn get_rent(&self, py: Python<'_>) -> PyResult<Rent> {
+        let mut client = self.0.clone();
+        block_on(py, client.get_rent())
+            .map(Rent::from)
+            .map_err(to_py_err)
+    }
+
+    /// Get the cluster clock.
+    ///
+    /// Returns:
+    ///     Clock: the clock object.
+    ///
+    pub fn get_clock(&self, py: Python<'_>) -> PyResult<Clock> {
+        let mut client = self.0.clone();
+        block_on(py, client.get_sysvar::<ClockOriginal>())
+            .map(Clock::from)
+            .map_err(to_py_err)
+    }
+
+    /// Return the balance in lamports of an account at the given address at the slot
+    /// corresponding to the given commitment level.
+    ///
+    /// Args:
+    ///     address (Pubkey): The account to look up.
+    ///     commitment (Optional[CommitmentLevel]): The commitment level to use.
+    ///
+    /// Returns:
+    ///     int: The account balance in lamports.
+    ///
+    pub fn get_balance(
+        &self,
+        py: Python<'_>,
+        address: Pubkey,
+        commitment: Option<CommitmentLevel>,
+    ) -> PyResult<u64> {
+        let mut client = self.0.clone();
+        let commitment_inner = CommitmentLevelOriginal::from(commitment.unwrap_or_default());
+        block_on(
+            py,
+            client.get_balance_with_commitment(address.0, commitment_inner),
+        )
+        .map_err(to_py_err)
+    }
+
+    /// Returns latest blockhash and last valid block height for given commitment level.
+    ///
+    /// Args:
+    ///     commitment (Optional[CommitmentLevel]): The commitment level to use.
+    ///
+    /// Returns:
+    ///     tuple[Hash, int]: The blockhash and last valid block height.
+    ///
+    pub fn get_latest_blockhash(
+        &self,
+        py: Python<'_>,
+        commitment: Option<CommitmentLevel>,
+    ) -> PyResult<(SolderHash, u64)> {
+        let commitment_inner = CommitmentLevelOriginal::from(commitment.unwrap_or_default());
+        block_on(
+            py,
+            get_latest_blockhash_inner(self.0.clone(), commitment_inner),
+        )
+    }
+
+    /// Get the fee in lamports for a given message.
+    ///
+    /// Args:
+    ///     message (Message): The message to check.
+    ///     commitment (Optional[CommitmentLevel]): The commitment level to use.
+    ///
+    /// Returns:
+    ///     Optional[int]: The fee for the given message.
+    ///
+    pub fn get_fee_for_message(
+        &self,
+        py: Python<'_>,
+        message: Message,
+        commitment: Option<CommitmentLevel>,
+    ) -> PyResult<Option<u64>> {
+        let mut client = self.0.clone();
+        let commitment_inner = CommitmentLevelOriginal::from(commitment.unwrap_or_default());
+        block_on(
+            py,
+            client.get_fee_for_message_with_commitment_and_context(
+                current(),
+                message.0,
+                commitment_inner,
+            ),
+        )
+        .map_err(to_py_err)
+    }
+}
@@ -6,6 +6,7 @@ use solana_banks_interface::{
     BanksTransactionResultWithMetadata, BanksTransactionResultWithSimulation,
     TransactionConfirmationStatus as TransactionConfirmationStatusBanks, TransactionMetadata,
 };
+use solders_log_parser::{parse_log_messages, ParsedLogs};
 use solders_macros::{common_methods, richcmp_eq_only};
 use solders_traits_core::transaction_status_boilerplate;
 use solders_transaction_confirmation_status::TransactionConfirmationStatus;
@@ -75,6 +76,15 @@ impl BanksTransactionMeta {
     pub fn compute_units_consumed(&self) -> u64 {
         self.0.compute_units_consumed
     }
+
+    /// Parse the log messages into a call tree.
+    ///
+    /// Returns:
+    ///     ParsedLogs: The parsed logs.
+    ///
+    pub fn parse_logs(&self) -> ParsedLogs {
+        parse_log_messages(&self.0.log_messages)
+    }
 }
 
 /// A transaction result.
@@ -0,0 +1,168 @@
+use std::collections::HashMap;
+
+use pyo3::{exceptions::PyValueError, prelude::*, types::PyBytes};
+use pythonize::depythonize;
+use serde_json::Value;
+use solders_anchor_idl::{
+    codec::{decode, decode_defined, encode, encode_defined, Reader},
+    idl::{parse_type, parse_type_def_ty, IdlModel, IdlType, IdlTypeDef},
+};
+use solders_traits_core::handle_py_value_err;
+
+fn to_value(obj: &PyAny) -> PyResult<Value> {
+    match obj.extract::<&str>() {
+        Ok(s) => Ok(Value::String(s.to_owned())),
+        Err(_) => handle_py_value_err(depythonize::<Value>(obj)),
+    }
+}
+
+fn is_type_def(value: &Value) -> bool {
+    value.get("kind").is_some()
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Root {
+    Type(IdlType),
+    Def(IdlTypeDef),
+}
+
+/// A Borsh schema, used to encode and decode arbitrary program data.
+///
+/// Schemas use the same type syntax as Anchor IDLs. Primitive types are given by name,
+/// e.g. ``"u64"``, ``"i128"``, ``"bool"``, ``"string"``, ``"bytes"`` or ``"pubkey"``,
+/// and compound types as dicts, e.g. ``{"vec": "u8"}``, ``{"option": "pubkey"}``,
+/// ``{"array": ["u8", 32]}`` or ``{"defined": "MyStruct"}``.
+/// Structs and enums are given as type definitions, e.g.
+/// ``{"kind": "struct", "fields": [{"name": "amount", "type": "u64"}]}``.
+///
+/// Structs are given as dicts (or objects with matching attributes) and decoded as dicts.
+/// Enums are given as ``{"Variant": fields}``, or just ``"Variant"`` for variants without fields,
+/// and decoded as ``{"Variant": fields}`` where ``fields`` is ``None`` for variants without fields.
+///
+/// Args:
+///     schema (Union[str, dict]): The type or type definition to encode and decode.
+///     types (Optional[Dict[str, dict]]): Type definitions referred to by ``{"defined": name}``.
+///
+/// Example:
+///     >>> from solders.borsh import BorshSchema
+///     >>> schema = BorshSchema(
+///     ...     {"kind": "struct", "fields": [{"name": "amount", "type": "u64"}, {"name": "side", "type": {"defined": "Side"}}]},
+///     ...     {"Side": {"kind": "enum", "variants": [{"name": "Bid"}, {"name": "Ask"}]}},
+///     ... )
+///     >>> data = schema.encode({"amount": 5, "side": "Ask"})
+///     >>> data.hex()
+///     '050000000000000001'
+///     >>> schema.decode(data)
+///     {'amount': 5, 'side': {'Ask': None}}
+///
+#[pyclass(module = "solders.borsh", subclass)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BorshSchema {
+    root: Root,
+    types: IdlModel,
+}
+
+#[pymethods]
+impl BorshSchema {
+    #[new]
+    #[pyo3(signature = (schema, types=None))]
+    pub fn new(schema: &PyAny, types: Option<HashMap<String, &PyAny>>) -> PyResult<Self> {
+        let defs = types
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(name, def)| {
+                let ty = parse_type_def_ty(&to_value(def)?)?;
+                Ok((
+                    name.clone(),
+                    IdlTypeDef {
+                        name,
+                        ty,
+                        serialization: None,
+                    },
+                ))
+            })
+            .collect::<PyResult<HashMap<_, _>>>()?;
+        let value = to_value(schema)?;
+        let root = if is_type_def(&value) {
+            Root::Def(IdlTypeDef {
+                name: "schema".to_string(),
+                ty: parse_type_def_ty(&value)?,
+                serialization: None,
+            })
+        } else {
+            Root::Type(parse_type(&value)?)
+        };
+        Ok(Self {
+            root,
+            types: IdlModel::from_types(defs),
+        })
+    }
+
+    /// Borsh-encode a value.
+    ///
+    /// Args:
+    ///     value (Any): The value to encode.
+    ///
+    /// Returns:
+    ///     bytes: The encoded value.
+    ///
+    /// Raises:
+    ///     ValueError: If the value doesn't match the schema.
+    ///
+    pub fn encode<'a>(&self, py: Python<'a>, value: &PyAny) -> PyResult<&'a PyBytes> {
+        let mut out = vec![];
+        match &self.root {
+            Root::Type(ty) => encode(&self.types, ty, value, &mut out, 0)?,
+            Root::Def(def) => encode_defined(&self.types, def, value, &mut out, 0)?,
+        }
+        Ok(PyBytes::new(py, &out))
+    }
+
+    /// Decode Borsh-encoded data.
+    ///
+    /// Args:
+    ///     data (bytes): The encoded value.
+    ///     strict (bool): If ``True``, raise if there are bytes left over after decoding.
+    ///         Pass ``False`` to decode account data that has trailing padding.
+    ///
+    /// Returns:
+    ///     Any: The decoded value.
+    ///
+    /// Raises:
+    ///     ValueError: If the data doesn't match the schema.
+    ///
+    #[pyo3(signature = (data, strict=true))]
+    pub fn decode(&self, py: Python<'_>, data: &[u8], strict: bool) -> PyResult<PyObject> {
+        let mut reader = Reader::new(data);
+        let value = match &self.root {
+            Root::Type(ty) => decode(py, &self.types, ty, &mut reader)?,
+            Root::Def(def) => decode_defined(py, &self.types, def, &mut reader)?,
+        };
+        if strict && reader.remaining() > 0 {
+            return Err(PyValueError::new_err(format!(
+                "{} bytes left over after decoding",
+                reader.remaining()
+            )));
+        }
+        Ok(value)
+    }
+
+    fn __repr__(&self) -> String {
+        let mut types: Vec<&String> = self.types.types.keys().collect();
+        types.sort();
+        match &self.root {
+            Root::Type(ty) => format!("BorshSchema({ty:?}, types={types:?})"),
+            Root::Def(def) => format!("BorshSchema({:?}, types={types:?})", def.ty),
+        }
+    }
+
+    fn __eq__(&self, other: &Self) -> bool {
+        self == other
+    }
+}
+
+pub fn create_borsh_mod(py: Python<'_>) -> PyResult<&PyModule> {
+    let m = PyModule::new(py, "borsh")?;
+    m.add_class::<BorshSchema>()?;
+    Ok(m)
+}
@@ -1,7 +1,9 @@
-use pyo3::prelude::*;
+use borsh::BorshDeserialize;
+use pyo3::{prelude::*, types::PyDict};
 use solana_sdk::compute_budget::{ComputeBudgetInstruction, ID};
 use solders_instruction::Instruction;
 use solders_pubkey::Pubkey;
+use solders_traits::InstructionDecodeError;
 
 /// Request a specific transaction-wide program heap region size in bytes.
 /// The value requested must be a multiple of 1024. This new heap region
@@ -25,6 +27,88 @@ pub fn set_compute_unit_price(micro_lamports: u64) -> Instruction {
     ComputeBudgetInstruction::set_compute_unit_price(micro_lamports).into()
 }
 
+/// The params of a compute budget instruction, tagged with the instruction name.
+pub enum ComputeBudgetInstructionParams {
+    RequestHeapFrame { bytes: u32 },
+    SetComputeUnitLimit { units: u32 },
+    SetComputeUnitPrice { micro_lamports: u64 },
+    SetLoadedAccountsDataSizeLimit { bytes: u32 },
+}
+
+impl ComputeBudgetInstructionParams {
+    /// Convert to a ``(name, params)`` tuple.
+    pub fn into_py_tuple(self, py: Python<'_>) -> PyResult<PyObject> {
+        let params = PyDict::new(py);
+        let name = match self {
+            Self::RequestHeapFrame { bytes } => {
+                params.set_item("bytes", bytes)?;
+                "RequestHeapFrame"
+            }
+            Self::SetComputeUnitLimit { units } => {
+                params.set_item("units", units)?;
+                "SetComputeUnitLimit"
+            }
+            Self::SetComputeUnitPrice { micro_lamports } => {
+                params.set_item("micro_lamports", micro_lamports)?;
+                "SetComputeUnitPrice"
+            }
+            Self::SetLoadedAccountsDataSizeLimit { bytes } => {
+                params.set_item("bytes", bytes)?;
+                "SetLoadedAccountsDataSizeLimit"
+            }
+        };
+        Ok((name, params).into_py(py))
+    }
+}
+
+/// Decode compute budget instruction data.
+pub fn decode_compute_budget_instruction_data(
+    data: &[u8],
+) -> PyResult<ComputeBudgetInstructionParams> {
+    let parsed = ComputeBudgetInstruction::try_from_slice(data).map_err(|e| {
+        InstructionDecodeError::new_err(format!("Invalid compute budget instruction data: {e}"))
+    })?;
+    match parsed {
+        ComputeBudgetInstruction::RequestHeapFrame(bytes) => {
+            Ok(ComputeBudgetInstructionParams::RequestHeapFrame { bytes })
+        }
+        ComputeBudgetInstruction::SetComputeUnitLimit(units) => {
+            Ok(ComputeBudgetInstructionParams::SetComputeUnitLimit { units })
+        }
+        ComputeBudgetInstruction::SetComputeUnitPrice(micro_lamports) => {
+            Ok(ComputeBudgetInstructionParams::SetComputeUnitPrice { micro_lamports })
+        }
+        ComputeBudgetInstruction::SetLoadedAccountsDataSizeLimit(bytes) => {
+            Ok(ComputeBudgetInstructionParams::SetLoadedAccountsDataSizeLimit { bytes })
+        }
+        ComputeBudgetInstruction::Unused => Err(InstructionDecodeError::new_err(
+            "Unused compute budget instruction",
+        )),
+    }
+}
+
+/// Decode a compute budget instruction.
+///
+/// Args:
+///     instruction (Instruction): The instruction to decode.
+///
+/// Returns:
+///     Tuple[str, dict]: The instruction name, e.g. ``"SetComputeUnitLimit"``, and its params.
+///
+/// Raises:
+///     InstructionDecodeError: if the instruction is not a valid compute budget instruction.
+///
+#[pyfunction]
+pub fn decode_instruction(py: Python<'_>, instruction: Instruction) -> PyResult<PyObject> {
+    if instruction.0.program_id != ID {
+        return Err(InstructionDecodeError::new_err(format!(
+            "Program {} is not the compute budget program",
+            instruction.0.program_id
+        )));
+    }
+    decode_compute_budget_instruction_data(&instruction.0.data)?.into_py_tuple(py)
+}
+
 pub fn create_compute_budget_mod(py: Python<'_>) -> PyResult<&PyModule> {
     let m = PyModule::new(py, "compute_budget")?;
     m.add("ID", Pubkey(ID))?;
@@ -32,6 +116,7 @@ pub fn create_compute_budget_mod(py: Python<'_>) -> PyResult<&PyModule> {
         wrap_pyfunction!(request_heap_frame, m)?,
         wrap_pyfunction!(set_compute_unit_limit, m)?,
         wrap_pyfunction!(set_compute_unit_price, m)?,
+        wrap_pyfunction!(decode_instruction, m)?,
     ];
     for func in funcs {
         m.add_function(func)?;
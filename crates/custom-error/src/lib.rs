@@ -0,0 +1,461 @@
+use std::{
+    collections::HashMap,
+    sync::{OnceLock, RwLock},
+};
+
+use pyo3::{exceptions::PyValueError, prelude::*};
+use serde::{Deserialize, Serialize};
+use solana_sdk::{
+    instruction::InstructionError as InstructionErrorOriginal, pubkey,
+    pubkey::Pubkey as PubkeyOriginal, stake, system_program,
+    transaction::TransactionError as TransactionErrorOriginal, vote,
+};
+use solders_anchor_idl::idl::IdlModel;
+use solders_macros::{common_methods, richcmp_eq_only};
+use solders_pubkey::Pubkey;
+use solders_traits_core::transaction_status_boilerplate;
+
+mod tables;
+use tables::{
+    ANCHOR_ERRORS, ASSOCIATED_TOKEN_ERRORS, STAKE_ERRORS, SYSTEM_ERRORS, TOKEN_2022_ERRORS,
+    TOKEN_ERRORS, VOTE_ERRORS,
+};
+
+const TOKEN_PROGRAM_ID: PubkeyOriginal = pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+const TOKEN_2022_PROGRAM_ID: PubkeyOriginal =
+    pubkey!("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb");
+const ASSOCIATED_TOKEN_PROGRAM_ID: PubkeyOriginal =
+    pubkey!("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL");
+
+/// A custom program error code resolved to a named error.
+///
+/// Args:
+///     program_id (Pubkey): The program that returned the error.
+///     code (int): The custom error code.
+///     name (str): The qualified error name, e.g. ``"TokenError::InsufficientFunds"``.
+///     message (str): The error message.
+///
+#[pyclass(module = "solders.custom_error", subclass)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResolvedError {
+    program_id: Pubkey,
+    code: u32,
+    name: String,
+    message: String,
+}
+
+transaction_status_boilerplate!(ResolvedError);
+
+#[richcmp_eq_only]
+#[common_methods]
+#[pymethods]
+impl ResolvedError {
+    #[new]
+    pub fn new(program_id: Pubkey, code: u32, name: String, message: String) -> Self {
+        Self {
+            program_id,
+            code,
+            name,
+            message,
+        }
+    }
+
+    /// Pubkey: The program that returned the error.
+    #[getter]
+    pub fn program_id(&self) -> Pubkey {
+        self.program_id
+    }
+
+    /// int: The custom error code.
+    #[getter]
+    pub fn code(&self) -> u32 {
+        self.code
+    }
+
+    /// str: The qualified error name.
+    #[getter]
+    pub fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    /// str: The error message.
+    #[getter]
+    pub fn message(&self) -> String {
+        self.message.clone()
+    }
+
+    /// Format the error as ``"<name>: <message>"``.
+    ///
+    /// Returns:
+    ///     str: The description.
+    ///
+    pub fn describe(&self) -> String {
+        format!("{}: {}", self.name, self.message)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct ProgramErrors {
+    /// Qualified name and message for each code.
+    errors: HashMap<u32, (String, String)>,
+    /// Whether the program can also return Anchor framework errors.
+    anchor: bool,
+}
+
+impl ProgramErrors {
+    fn from_table(prefix: &str, table: &[(&str, &str)]) -> Self {
+        let errors = table
+            .iter()
+            .enumerate()
+            .map(|(code, (name, message))| {
+                (
+                    code as u32,
+                    (format!("{prefix}::{name}"), (*message).to_string()),
+                )
+            })
+            .collect();
+        Self {
+            errors,
+            anchor: false,
+        }
+    }
+}
+
+fn anchor_error(code: u32) -> Option<(String, String)> {
+    ANCHOR_ERRORS
+        .iter()
+        .find(|(c, _, _)| *c == code)
+        .map(|(_, name, message)| (format!("AnchorError::{name}"), (*message).to_string()))
+}
+
+fn to_upper_camel(s: &str) -> String {
+    s.split(|c: char| c == '_' || c == '-')
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            chars
+                .next()
+                .map(|first| first.to_uppercase().chain(chars).collect::<String>())
+                .unwrap_or_default()
+        })
+        .collect()
+}
+
+/// Maps custom program error codes to named errors.
+///
+/// The default resolver knows the errors of the system, SPL Token, Token-2022,
+/// associated token account, stake and vote programs.
+/// Anchor framework error codes are only resolved for programs registered as
+/// Anchor programs, with :meth:`register_anchor_idl` or :meth:`register_anchor_program`.
+///
+/// Example:
+///     >>> from solders.custom_error import CustomErrorResolver
+///     >>> from solders.pubkey import Pubkey
+///     >>> resolver = CustomErrorResolver()
+///     >>> token_program = Pubkey.from_string("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA")
+///     >>> resolver.resolve(token_program, 1).name
+///     'TokenError::InsufficientFunds'
+///
+#[pyclass(module = "solders.custom_error", subclass)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CustomErrorResolver {
+    programs: HashMap<PubkeyOriginal, ProgramErrors>,
+}
+
+impl Default for CustomErrorResolver {
+    fn default() -> Self {
+        let programs = HashMap::from([
+            (
+                system_program::ID,
+                ProgramErrors::from_table("SystemError", SYSTEM_ERRORS),
+            ),
+            (
+                TOKEN_PROGRAM_ID,
+                ProgramErrors::from_table("TokenError", TOKEN_ERRORS),
+            ),
+            (
+                TOKEN_2022_PROGRAM_ID,
+                ProgramErrors::from_table("TokenError", TOKEN_2022_ERRORS),
+            ),
+            (
+                ASSOCIATED_TOKEN_PROGRAM_ID,
+                ProgramErrors::from_table("AssociatedTokenAccountError", ASSOCIATED_TOKEN_ERRORS),
+            ),
+            (
+                stake::program::ID,
+                ProgramErrors::from_table("StakeError", STAKE_ERRORS),
+            ),
+            (
+                vote::program::ID,
+                ProgramErrors::from_table("VoteError", VOTE_ERRORS),
+            ),
+        ]);
+        Self { programs }
+    }
+}
+
+impl CustomErrorResolver {
+    pub fn resolve_original(
+        &self,
+        program_id: &PubkeyOriginal,
+        code: u32,
+    ) -> Option<ResolvedError> {
+        let program = self.programs.get(program_id)?;
+        let (name, message) = program.errors.get(&code).cloned().or_else(|| {
+            if program.anchor {
+                anchor_error(code)
+            } else {
+                None
+            }
+        })?;
+        Some(ResolvedError {
+            program_id: Pubkey(*program_id),
+            code,
+            name,
+            message,
+        })
+    }
+
+    /// Describe a transaction error, naming the custom error if it can be resolved.
+    ///
+    /// The error is resolved against the program that returned it, found in the
+    /// transaction's ``log_messages``. Without such a log the raw error is described.
+    pub fn describe_transaction_error(
+        &self,
+        err: &TransactionErrorOriginal,
+        log_messages: &[String],
+    ) -> String {
+        if let TransactionErrorOriginal::InstructionError(
+            index,
+            InstructionErrorOriginal::Custom(code),
+        ) = err
+        {
+            if let Some(resolved) = failed_program_id(log_messages, *code)
+                .and_then(|program_id| self.resolve_original(&program_id, *code))
+            {
+                return format!(
+                    "Error processing Instruction {index}: {}",
+                    resolved.describe()
+                );
+            }
+        }
+        err.to_string()
+    }
+}
+
+#[pymethods]
+impl CustomErrorResolver {
+    #[new]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a resolver with no error tables, not even the built-in ones.
+    ///
+    /// Returns:
+    ///     CustomErrorResolver: The empty resolver.
+    ///
+    #[staticmethod]
+    pub fn empty() -> Self {
+        Self {
+            programs: HashMap::new(),
+        }
+    }
+
+    /// Register a single error for a program, replacing any existing error with the same code.
+    ///
+    /// Args:
+    ///     program_id (Pubkey): The program that returns the error.
+    ///     code (int): The custom error code.
+    ///     name (str): The qualified error name, e.g. ``"MyError::Unauthorized"``.
+    ///     message (str): The error message.
+    ///
+    pub fn register(&mut self, program_id: Pubkey, code: u32, name: String, message: String) {
+        self.programs
+            .entry(program_id.0)
+            .or_insert_with(|| ProgramErrors {
+                errors: HashMap::new(),
+                anchor: false,
+            })
+            .errors
+            .insert(code, (name, message));
+    }
+
+    /// Mark a program as an Anchor program, so Anchor framework errors are resolved for it.
+    ///
+    /// Use this for Anchor programs without an IDL. Errors already registered for the
+    /// program are kept.
+    ///
+    /// Args:
+    ///     program_id (Pubkey): The program.
+    ///
+    pub fn register_anchor_program(&mut self, program_id: Pubkey) {
+        self.programs
+            .entry(program_id.0)
+            .or_insert_with(|| ProgramErrors {
+                errors: HashMap::new(),
+                anchor: true,
+            })
+            .anchor = true;
+    }
+
+    /// Register the errors declared in an Anchor IDL.
+    ///
+    /// Both the legacy IDL format and the format introduced in Anchor 0.30 are supported.
+    /// Error names are qualified with the program name, e.g. ``"MyProgramError::Unauthorized"``.
+    /// Anchor framework errors are also resolved for the program.
+    ///
+    /// Args:
+    ///     idl (str): The IDL JSON.
+    ///     program_id (Optional[Pubkey]): The program ID. Defaults to the address in the IDL.
+    ///
+    /// Returns:
+    ///     Pubkey: The program the errors were registered for.
+    ///
+    /// Raises:
+    ///     ValueError: If the IDL is invalid or has no address and ``program_id`` is not given.
+    ///
+    #[pyo3(signature = (idl, program_id=None))]
+    pub fn register_anchor_idl(
+        &mut self,
+        idl: &str,
+        program_id: Option<Pubkey>,
+    ) -> PyResult<Pubkey> {
+        let model = IdlModel::parse(idl)?;
+        let program_id = match (program_id, model.address) {
+            (Some(p), _) => p,
+            (None, Some(a)) => Pubkey(a),
+            (None, None) => {
+                return Err(PyValueError::new_err(
+                    "The IDL has no address, so program_id must be given",
+                ))
+            }
+        };
+        let program_name = if model.name.is_empty() {
+            "Program"
+        } else {
+            &model.name
+        };
+        let prefix = format!("{}Error", to_upper_camel(program_name));
+        let errors = model
+            .errors
+            .into_iter()
+            .map(|e| (e.code, (format!("{prefix}::{}", e.name), e.msg)))
+            .collect();
+        self.programs.insert(
+            program_id.0,
+            ProgramErrors {
+                errors,
+                anchor: true,
+            },
+        );
+        Ok(program_id)
+    }
+
+    /// Remove the error table of a program.
+    ///
+    /// Args:
+    ///     program_id (Pubkey): The program to remove.
+    ///
+    /// Returns:
+    ///     bool: Whether the program had a table.
+    ///
+    pub fn unregister(&mut self, program_id: Pubkey) -> bool {
+        self.programs.remove(&program_id.0).is_some()
+    }
+
+    /// The programs that have an error table.
+    ///
+    /// Returns:
+    ///     List[Pubkey]: The program IDs.
+    ///
+    pub fn program_ids(&self) -> Vec<Pubkey> {
+        let mut ids: Vec<Pubkey> = self.programs.keys().map(|k| Pubkey(*k)).collect();
+        ids.sort();
+        ids
+    }
+
+    /// Resolve a custom error code.
+    ///
+    /// Args:
+    ///     program_id (Pubkey): The program that returned the error.
+    ///     code (int): The custom error code.
+    ///
+    /// Returns:
+    ///     Optional[ResolvedError]: The named error, or ``None`` if the code is unknown.
+    ///
+    pub fn resolve(&self, program_id: Pubkey, code: u32) -> Option<ResolvedError> {
+        self.resolve_original(&program_id.0, code)
+    }
+
+    fn __contains__(&self, program_id: Pubkey) -> bool {
+        self.programs.contains_key(&program_id.0)
+    }
+
+    fn __len__(&self) -> usize {
+        self.programs.len()
+    }
+}
+
+fn default_resolver_lock() -> &'static RwLock<CustomErrorResolver> {
+    static DEFAULT_RESOLVER: OnceLock<RwLock<CustomErrorResolver>> = OnceLock::new();
+    DEFAULT_RESOLVER.get_or_init(|| RwLock::new(CustomErrorResolver::default()))
+}
+
+/// The resolver used when none is passed explicitly, e.g. for bankrun errors.
+pub fn default_resolver() -> CustomErrorResolver {
+    default_resolver_lock()
+        .read()
+        .map(|r| r.clone())
+        .unwrap_or_default()
+}
+
+/// Get a copy of the default resolver.
+///
+/// The default resolver is used to describe errors raised by bankrun and by
+/// methods that take an optional resolver. Changes to the returned copy have no
+/// effect until it is passed to :func:`set_default_resolver`.
+///
+/// Returns:
+///     CustomErrorResolver: The default resolver.
+///
+#[pyfunction]
+pub fn get_default_resolver() -> CustomErrorResolver {
+    default_resolver()
+}
+
+/// Replace the default resolver.
+///
+/// Args:
+///     resolver (CustomErrorResolver): The new default resolver.
+///
+#[pyfunction]
+pub fn set_default_resolver(resolver: CustomErrorResolver) {
+    if let Ok(mut r) = default_resolver_lock().write() {
+        *r = resolver;
+    }
+}
+
+/// Find the program that returned a custom error in a transaction's logs.
+///
+/// The failing program logs ``Program <id> failed: custom program error: 0x<code>``
+/// before each of its callers logs the same error, so the first match is the
+/// innermost program.
+pub fn failed_program_id(log_messages: &[String], code: u32) -> Option<PubkeyOriginal> {
+    let suffix = format!(" failed: custom program error: {code:#x}");
+    log_messages.iter().find_map(|log| {
+        log.strip_prefix("Program ")?
+            .strip_suffix(suffix.as_str())?
+            .parse()
+            .ok()
+    })
+}
+
+pub fn create_custom_error_mod(py: Python<'_>) -> PyResult<&PyModule> {
+    let m = PyModule::new(py, "custom_error")?;
+    m.add_class::<ResolvedError>()?;
+    m.add_class::<CustomErrorResolver>()?;
+    m.add_function(wrap_pyfunction!(get_default_resolver, m)?)?;
+    m.add_function(wrap_pyfunction!(set_default_resolver, m)?)?;
+    Ok(m)
+}
@@ -0,0 +1,672 @@
+//! Built-in error tables, generated from the error enums of each program.
+//! The position of each entry is its custom error code.
+
+pub(crate) const SYSTEM_ERRORS: &[(&str, &str)] = &[
+    (
+        "AccountAlreadyInUse",
+        "an account with the same address already exists",
+    ),
+    (
+        "ResultWithNegativeLamports",
+        "account does not have enough SOL to perform the operation",
+    ),
+    (
+        "InvalidProgramId",
+        "cannot assign account to this program id",
+    ),
+    (
+        "InvalidAccountDataLength",
+        "cannot allocate account data of this length",
+    ),
+    (
+        "MaxSeedLengthExceeded",
+        "length of requested seed is too long",
+    ),
+    (
+        "AddressWithSeedMismatch",
+        "provided address does not match addressed derived from seed",
+    ),
+    (
+        "NonceNoRecentBlockhashes",
+        "advancing stored nonce requires a populated RecentBlockhashes sysvar",
+    ),
+    (
+        "NonceBlockhashNotExpired",
+        "stored nonce is still in recent_blockhashes",
+    ),
+    (
+        "NonceUnexpectedBlockhashValue",
+        "specified nonce does not match stored nonce",
+    ),
+];
+
+pub(crate) const TOKEN_ERRORS: &[(&str, &str)] = &[
+    (
+        "NotRentExempt",
+        "Lamport balance below rent-exempt threshold",
+    ),
+    ("InsufficientFunds", "Insufficient funds"),
+    ("InvalidMint", "Invalid Mint"),
+    ("MintMismatch", "Account not associated with this Mint"),
+    ("OwnerMismatch", "Owner does not match"),
+    ("FixedSupply", "Fixed supply"),
+    ("AlreadyInUse", "Already in use"),
+    (
+        "InvalidNumberOfProvidedSigners",
+        "Invalid number of provided signers",
+    ),
+    (
+        "InvalidNumberOfRequiredSigners",
+        "Invalid number of required signers",
+    ),
+    ("UninitializedState", "State is uninitialized"),
+    (
+        "NativeNotSupported",
+        "Instruction does not support native tokens",
+    ),
+    (
+        "NonNativeHasBalance",
+        "Non-native account can only be closed if its balance is zero",
+    ),
+    ("InvalidInstruction", "Invalid instruction"),
+    ("InvalidState", "State is invalid for requested operation"),
+    ("Overflow", "Operation overflowed"),
+    (
+        "AuthorityTypeNotSupported",
+        "Account does not support specified authority type",
+    ),
+    ("MintCannotFreeze", "This token mint cannot freeze accounts"),
+    ("AccountFrozen", "Account is frozen"),
+    (
+        "MintDecimalsMismatch",
+        "The provided decimals value different from the Mint decimals",
+    ),
+    (
+        "NonNativeNotSupported",
+        "Instruction does not support non-native tokens",
+    ),
+];
+
+pub(crate) const TOKEN_2022_ERRORS: &[(&str, &str)] = &[
+    (
+        "NotRentExempt",
+        "Lamport balance below rent-exempt threshold",
+    ),
+    ("InsufficientFunds", "Insufficient funds"),
+    ("InvalidMint", "Invalid Mint"),
+    ("MintMismatch", "Account not associated with this Mint"),
+    ("OwnerMismatch", "Owner does not match"),
+    ("FixedSupply", "Fixed supply"),
+    ("AlreadyInUse", "Already in use"),
+    (
+        "InvalidNumberOfProvidedSigners",
+        "Invalid number of provided signers",
+    ),
+    (
+        "InvalidNumberOfRequiredSigners",
+        "Invalid number of required signers",
+    ),
+    ("UninitializedState", "State is uninitialized"),
+    (
+        "NativeNotSupported",
+        "Instruction does not support native tokens",
+    ),
+    (
+        "NonNativeHasBalance",
+        "Non-native account can only be closed if its balance is zero",
+    ),
+    ("InvalidInstruction", "Invalid instruction"),
+    ("InvalidState", "State is invalid for requested operation"),
+    ("Overflow", "Operation overflowed"),
+    (
+        "AuthorityTypeNotSupported",
+        "Account does not support specified authority type",
+    ),
+    (
+        "MintCannotFreeze",
+        "This token mint cannot freeze accounts",
+    ),
+    ("AccountFrozen", "Account is frozen"),
+    (
+        "MintDecimalsMismatch",
+        "The provided decimals value different from the Mint decimals",
+    ),
+    (
+        "NonNativeNotSupported",
+        "Instruction does not support non-native tokens",
+    ),
+    (
+        "ExtensionTypeMismatch",
+        "Extension type does not match already existing extensions",
+    ),
+    (
+        "ExtensionBaseMismatch",
+        "Extension does not match the base type provided",
+    ),
+    (
+        "ExtensionAlreadyInitialized",
+        "Extension already initialized on this account",
+    ),
+    (
+        "ConfidentialTransferAccountHasBalance",
+        "An account can only be closed if its confidential balance is zero",
+    ),
+    (
+        "ConfidentialTransferAccountNotApproved",
+        "Account not approved for confidential transfers",
+    ),
+    (
+        "ConfidentialTransferDepositsAndTransfersDisabled",
+        "Account not accepting deposits or transfers",
+    ),
+    (
+        "ConfidentialTransferElGamalPubkeyMismatch",
+        "ElGamal public key mismatch",
+    ),
+    ("ConfidentialTransferBalanceMismatch", "Balance mismatch"),
+    (
+        "MintHasSupply",
+        "Mint has non-zero supply. Burn all tokens before closing the mint",
+    ),
+    (
+        "NoAuthorityExists",
+        "No authority exists to perform the desired operation",
+    ),
+    (
+        "TransferFeeExceedsMaximum",
+        "Transfer fee exceeds maximum of 10,000 basis points",
+    ),
+    (
+        "MintRequiredForTransfer",
+        "Mint required for this account to transfer tokens, use `transfer_checked` or `transfer_checked_with_fee`",
+    ),
+    (
+        "FeeMismatch",
+        "Calculated fee does not match expected fee",
+    ),
+    (
+        "FeeParametersMismatch",
+        "Fee parameters associated with zero-knowledge proofs do not match fee parameters in mint",
+    ),
+    ("ImmutableOwner", "The owner authority cannot be changed"),
+    (
+        "AccountHasWithheldTransferFees",
+        "An account can only be closed if its withheld fee balance is zero, harvest fees to the mint and try again",
+    ),
+    (
+        "NoMemo",
+        "No memo in previous instruction; required for recipient to receive a transfer",
+    ),
+    ("NonTransferable", "Transfer is disabled for this mint"),
+    (
+        "NonTransferableNeedsImmutableOwnership",
+        "Non-transferable tokens can't be minted to an account without immutable ownership",
+    ),
+    (
+        "MaximumPendingBalanceCreditCounterExceeded",
+        "The total number of `Deposit` and `Transfer` instructions to an account cannot exceed the associated `maximum_pending_balance_credit_counter`",
+    ),
+    (
+        "MaximumDepositAmountExceeded",
+        "Deposit amount exceeds maximum limit",
+    ),
+    (
+        "CpiGuardSettingsLocked",
+        "CPI Guard cannot be enabled or disabled in CPI",
+    ),
+    (
+        "CpiGuardTransferBlocked",
+        "CPI Guard is enabled, and a program attempted to transfer user funds via CPI without using a delegate",
+    ),
+    (
+        "CpiGuardBurnBlocked",
+        "CPI Guard is enabled, and a program attempted to burn user funds via CPI without using a delegate",
+    ),
+    (
+        "CpiGuardCloseAccountBlocked",
+        "CPI Guard is enabled, and a program attempted to close an account via CPI without returning lamports to owner",
+    ),
+    (
+        "CpiGuardApproveBlocked",
+        "CPI Guard is enabled, and a program attempted to approve a delegate via CPI",
+    ),
+    (
+        "CpiGuardSetAuthorityBlocked",
+        "CPI Guard is enabled, and a program attempted to add or replace an authority via CPI",
+    ),
+    (
+        "CpiGuardOwnerChangeBlocked",
+        "Account ownership cannot be changed while CPI Guard is enabled",
+    ),
+    ("ExtensionNotFound", "Extension not found in account data"),
+    (
+        "NonConfidentialTransfersDisabled",
+        "Non-confidential transfers disabled",
+    ),
+    (
+        "ConfidentialTransferFeeAccountHasWithheldFee",
+        "An account can only be closed if the confidential withheld fee is zero",
+    ),
+    (
+        "InvalidExtensionCombination",
+        "A mint or an account is initialized to an invalid combination of extensions",
+    ),
+    (
+        "InvalidLengthForAlloc",
+        "Extension allocation with overwrite must use the same length",
+    ),
+    (
+        "AccountDecryption",
+        "Failed to decrypt a confidential transfer account",
+    ),
+    ("ProofGeneration", "Failed to generate proof"),
+    (
+        "InvalidProofInstructionOffset",
+        "An invalid proof instruction offset was provided",
+    ),
+    (
+        "HarvestToMintDisabled",
+        "Harvest of withheld tokens to mint is disabled",
+    ),
+    (
+        "SplitProofContextStateAccountsNotSupported",
+        "Split proof context state accounts not supported for instruction",
+    ),
+    (
+        "NotEnoughProofContextStateAccounts",
+        "Not enough proof context state accounts provided",
+    ),
+    ("MalformedCiphertext", "Ciphertext is malformed"),
+    (
+        "CiphertextArithmeticFailed",
+        "Ciphertext arithmetic failed",
+    ),
+];
+
+pub(crate) const ASSOCIATED_TOKEN_ERRORS: &[(&str, &str)] = &[(
+    "InvalidOwner",
+    "Associated token account owner does not match address derivation",
+)];
+
+pub(crate) const STAKE_ERRORS: &[(&str, &str)] = &[
+    ("NoCreditsToRedeem", "not enough credits to redeem"),
+    ("LockupInForce", "lockup has not yet expired"),
+    ("AlreadyDeactivated", "stake already deactivated"),
+    (
+        "TooSoonToRedelegate",
+        "one re-delegation permitted per epoch",
+    ),
+    ("InsufficientStake", "split amount is more than is staked"),
+    (
+        "MergeTransientStake",
+        "stake account with transient stake cannot be merged",
+    ),
+    (
+        "MergeMismatch",
+        "stake account merge failed due to different authority, lockups or state",
+    ),
+    ("CustodianMissing", "custodian address not present"),
+    (
+        "CustodianSignatureMissing",
+        "custodian signature not present",
+    ),
+    (
+        "InsufficientReferenceVotes",
+        "insufficient voting activity in the reference vote account",
+    ),
+    (
+        "VoteAddressMismatch",
+        "stake account is not delegated to the provided vote account",
+    ),
+    (
+        "MinimumDelinquentEpochsForDeactivationNotMet",
+        "stake account has not been delinquent for the minimum epochs required for deactivation",
+    ),
+    (
+        "InsufficientDelegation",
+        "delegation amount is less than the minimum",
+    ),
+    (
+        "RedelegateTransientOrInactiveStake",
+        "stake account with transient or inactive stake cannot be redelegated",
+    ),
+    (
+        "RedelegateToSameVoteAccount",
+        "stake redelegation to the same vote account is not permitted",
+    ),
+    (
+        "RedelegatedStakeMustFullyActivateBeforeDeactivationIsPermitted",
+        "redelegated stake must be fully activated before deactivation",
+    ),
+];
+
+pub(crate) const VOTE_ERRORS: &[(&str, &str)] = &[
+    (
+        "VoteTooOld",
+        "vote already recorded or not in slot hashes history",
+    ),
+    ("SlotsMismatch", "vote slots do not match bank history"),
+    ("SlotHashMismatch", "vote hash does not match bank hash"),
+    ("EmptySlots", "vote has no slots, invalid"),
+    ("TimestampTooOld", "vote timestamp not recent"),
+    (
+        "TooSoonToReauthorize",
+        "authorized voter has already been changed this epoch",
+    ),
+    (
+        "LockoutConflict",
+        "Old state had vote which should not have been popped off by vote in new state",
+    ),
+    (
+        "NewVoteStateLockoutMismatch",
+        "Proposed state had earlier slot which should have been popped off by later vote",
+    ),
+    ("SlotsNotOrdered", "Vote slots are not ordered"),
+    ("ConfirmationsNotOrdered", "Confirmations are not ordered"),
+    ("ZeroConfirmations", "Zero confirmations"),
+    ("ConfirmationTooLarge", "Confirmation exceeds limit"),
+    ("RootRollBack", "Root rolled back"),
+    (
+        "ConfirmationRollBack",
+        "Confirmations for same vote were smaller in new proposed state",
+    ),
+    (
+        "SlotSmallerThanRoot",
+        "New state contained a vote slot smaller than the root",
+    ),
+    ("TooManyVotes", "New state contained too many votes"),
+    (
+        "VotesTooOldAllFiltered",
+        "every slot in the vote was older than the SlotHashes history",
+    ),
+    ("RootOnDifferentFork", "Proposed root is not in slot hashes"),
+    (
+        "ActiveVoteAccountClose",
+        "Cannot close vote account unless it stopped voting at least one full epoch ago",
+    ),
+    (
+        "CommissionUpdateTooLate",
+        "Cannot update commission at this point in the epoch",
+    ),
+];
+
+/// Anchor framework errors, which any Anchor program can return.
+pub(crate) const ANCHOR_ERRORS: &[(u32, &str, &str)] = &[
+    (
+        100,
+        "InstructionMissing",
+        "8 byte instruction identifier not provided",
+    ),
+    (
+        101,
+        "InstructionFallbackNotFound",
+        "Fallback functions are not supported",
+    ),
+    (
+        102,
+        "InstructionDidNotDeserialize",
+        "The program could not deserialize the given instruction",
+    ),
+    (
+        103,
+        "InstructionDidNotSerialize",
+        "The program could not serialize the given instruction",
+    ),
+    (
+        1000,
+        "IdlInstructionStub",
+        "The program was compiled without idl instructions",
+    ),
+    (
+        1001,
+        "IdlInstructionInvalidProgram",
+        "Invalid program given to the IDL instruction",
+    ),
+    (
+        1002,
+        "IdlAccountNotEmpty",
+        "IDL account must be empty in order to resize, try closing first",
+    ),
+    (
+        1500,
+        "EventInstructionStub",
+        "The program was compiled without `event-cpi` feature",
+    ),
+    (2000, "ConstraintMut", "A mut constraint was violated"),
+    (
+        2001,
+        "ConstraintHasOne",
+        "A has one constraint was violated",
+    ),
+    (
+        2002,
+        "ConstraintSigner",
+        "A signer constraint was violated",
+    ),
+    (2003, "ConstraintRaw", "A raw constraint was violated"),
+    (
+        2004,
+        "ConstraintOwner",
+        "An owner constraint was violated",
+    ),
+    (
+        2005,
+        "ConstraintRentExempt",
+        "A rent exemption constraint was violated",
+    ),
+    (2006, "ConstraintSeeds", "A seeds constraint was violated"),
+    (
+        2007,
+        "ConstraintExecutable",
+        "An executable constraint was violated",
+    ),
+    (
+        2008,
+        "ConstraintState",
+        "Deprecated Error, feel free to replace with something else",
+    ),
+    (
+        2009,
+        "ConstraintAssociated",
+        "An associated constraint was violated",
+    ),
+    (
+        2010,
+        "ConstraintAssociatedInit",
+        "An associated init constraint was violated",
+    ),
+    (2011, "ConstraintClose", "A close constraint was violated"),
+    (
+        2012,
+        "ConstraintAddress",
+        "An address constraint was violated",
+    ),
+    (
+        2013,
+        "ConstraintZero",
+        "Expected zero account discriminant",
+    ),
+    (
+        2014,
+        "ConstraintTokenMint",
+        "A token mint constraint was violated",
+    ),
+    (
+        2015,
+        "ConstraintTokenOwner",
+        "A token owner constraint was violated",
+    ),
+    (
+        2016,
+        "ConstraintMintMintAuthority",
+        "A mint mint authority constraint was violated",
+    ),
+    (
+        2017,
+        "ConstraintMintFreezeAuthority",
+        "A mint freeze authority constraint was violated",
+    ),
+    (
+        2018,
+        "ConstraintMintDecimals",
+        "A mint decimals constraint was violated",
+    ),
+    (2019, "ConstraintSpace", "A space constraint was violated"),
+    (
+        2020,
+        "ConstraintAccountIsNone",
+        "A required account for the constraint is None",
+    ),
+    (
+        2021,
+        "ConstraintTokenTokenProgram",
+        "A token account token program constraint was violated",
+    ),
+    (
+        2022,
+        "ConstraintMintTokenProgram",
+        "A mint token program constraint was violated",
+    ),
+    (
+        2023,
+        "ConstraintAssociatedTokenTokenProgram",
+        "An associated token account token program constraint was violated",
+    ),
+    (
+        2500,
+        "RequireViolated",
+        "A require expression was violated",
+    ),
+    (
+        2501,
+        "RequireEqViolated",
+        "A require_eq expression was violated",
+    ),
+    (
+        2502,
+        "RequireKeysEqViolated",
+        "A require_keys_eq expression was violated",
+    ),
+    (
+        2503,
+        "RequireNeqViolated",
+        "A require_neq expression was violated",
+    ),
+    (
+        2504,
+        "RequireKeysNeqViolated",
+        "A require_keys_neq expression was violated",
+    ),
+    (
+        2505,
+        "RequireGtViolated",
+        "A require_gt expression was violated",
+    ),
+    (
+        2506,
+        "RequireGteViolated",
+        "A require_gte expression was violated",
+    ),
+    (
+        3000,
+        "AccountDiscriminatorAlreadySet",
+        "The account discriminator was already set on this account",
+    ),
+    (
+        3001,
+        "AccountDiscriminatorNotFound",
+        "No 8 byte discriminator was found on the account",
+    ),
+    (
+        3002,
+        "AccountDiscriminatorMismatch",
+        "8 byte discriminator did not match what was expected",
+    ),
+    (
+        3003,
+        "AccountDidNotDeserialize",
+        "Failed to deserialize the account",
+    ),
+    (
+        3004,
+        "AccountDidNotSerialize",
+        "Failed to serialize the account",
+    ),
+    (
+        3005,
+        "AccountNotEnoughKeys",
+        "Not enough account keys given to the instruction",
+    ),
+    (
+        3006,
+        "AccountNotMutable",
+        "The given account is not mutable",
+    ),
+    (
+        3007,
+        "AccountOwnedByWrongProgram",
+        "The given account is owned by a different program than expected",
+    ),
+    (3008, "InvalidProgramId", "Program ID was not as expected"),
+    (
+        3009,
+        "InvalidProgramExecutable",
+        "Program account is not executable",
+    ),
+    (3010, "AccountNotSigner", "The given account did not sign"),
+    (
+        3011,
+        "AccountNotSystemOwned",
+        "The given account is not owned by the system program",
+    ),
+    (
+        3012,
+        "AccountNotInitialized",
+        "The program expected this account to be already initialized",
+    ),
+    (
+        3013,
+        "AccountNotProgramData",
+        "The given account is not a program data account",
+    ),
+    (
+        3014,
+        "AccountNotAssociatedTokenAccount",
+        "The given account is not the associated token account",
+    ),
+    (
+        3015,
+        "AccountSysvarMismatch",
+        "The given public key does not match the required sysvar",
+    ),
+    (
+        3016,
+        "AccountReallocExceedsLimit",
+        "The account reallocation exceeds the MAX_PERMITTED_DATA_INCREASE limit",
+    ),
+    (
+        3017,
+        "AccountDuplicateReallocs",
+        "The account was duplicated for more than one reallocation",
+    ),
+    (
+        4100,
+        "DeclaredProgramIdMismatch",
+        "The declared program id does not match the actual program id",
+    ),
+    (
+        4101,
+        "TryingToInitPayerAsProgramAccount",
+        "You cannot/should not initialize the payer account as a program account",
+    ),
+    (
+        4102,
+        "InvalidNumericConversion",
+        "The program could not perform the numeric conversion, out of range integral type conversion attempted",
+    ),
+    (
+        5000,
+        "Deprecated",
+        "The API being used is deprecated and should no longer be used",
+    ),
+];
@@ -1,12 +1,13 @@
 use std::str::FromStr;
 
+use borsh::{BorshDeserialize, BorshSerialize};
 use derive_more::{From, Into};
 use pyo3::prelude::*;
 use serde::{Deserialize, Serialize};
 use solana_program::hash::{
     hash, Hash as HashOriginal, ParseHashError as ParseHashErrorOriginal, HASH_BYTES,
 };
-use solders_macros::{common_methods, pyhash, richcmp_full};
+use solders_macros::{borsh_methods, common_methods, pyhash, richcmp_full};
 
 use solders_traits::handle_py_err;
 use solders_traits_core::{
@@ -40,6 +41,7 @@ pub struct Hash(HashOriginal);
 #[pyhash]
 #[richcmp_full]
 #[common_methods]
+#[borsh_methods]
 #[pymethods]
 impl Hash {
     #[classattr]
@@ -139,6 +141,19 @@ impl PyFromBytesGeneral for Hash {
 
 pybytes_general_via_slice!(Hash);
 solders_traits_core::common_methods_default!(Hash);
+solders_traits_core::borsh_methods_default!(Hash);
+
+impl BorshSerialize for Hash {
+    fn serialize<W: borsh::io::Write>(&self, writer: &mut W) -> borsh::io::Result<()> {
+        BorshSerialize::serialize(&self.0.to_bytes(), writer)
+    }
+}
+
+impl BorshDeserialize for Hash {
+    fn deserialize_reader<R: borsh::io::Read>(reader: &mut R) -> borsh::io::Result<Self> {
+        <[u8; HASH_BYTES]>::deserialize_reader(reader).map(Self::new)
+    }
+}
 
 impl RichcmpFull for Hash {}
 
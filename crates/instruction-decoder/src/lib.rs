@@ -0,0 +1,564 @@
+use std::collections::HashMap;
+
+use pyo3::{prelude::*, types::PyBytes};
+use solana_program::address_lookup_table;
+use solana_sdk::{
+    compute_budget, message::VersionedMessage as VersionedMessageOriginal,
+    pubkey::Pubkey as PubkeyOriginal, system_program,
+};
+use solders_compute_budget::decode_compute_budget_instruction_data;
+use solders_instruction::Instruction;
+use solders_pubkey::Pubkey;
+use solders_system_program::{
+    decode_lookup_table_instruction_data, decode_system_instruction_data,
+};
+use solders_traits::InstructionDecodeError;
+use solders_transaction::{Transaction, VersionedTransaction};
+#[cfg(feature = "ring")]
+use solders_transaction_status::{UiInstruction, UiMessage, UiParsedInstruction, UiTransaction};
+
+pub mod tree;
+#[cfg(feature = "ring")]
+pub use tree::build_instruction_tree;
+pub use tree::{build_instruction_tree_from_logs, InstructionNode};
+
+/// An instruction that was decoded by a registered decoder.
+///
+/// Args:
+///     program_id (Pubkey): The program that the instruction invokes.
+///     name (str): The instruction name, e.g. ``"Transfer"``.
+///     params (Any): The decoded params, as returned by the decoder.
+///     accounts (Sequence[Pubkey]): The accounts passed to the instruction.
+///         Empty for instructions the RPC node parsed (``jsonParsed`` encoding),
+///         since the parsed form doesn't list them.
+///
+#[pyclass(module = "solders.instruction_decoder", subclass)]
+#[derive(Clone, Debug)]
+pub struct DecodedInstruction {
+    #[pyo3(get)]
+    program_id: Pubkey,
+    #[pyo3(get)]
+    name: String,
+    #[pyo3(get)]
+    params: PyObject,
+    #[pyo3(get)]
+    accounts: Vec<Pubkey>,
+}
+
+#[pymethods]
+impl DecodedInstruction {
+    #[new]
+    pub fn new(program_id: Pubkey, name: String, params: PyObject, accounts: Vec<Pubkey>) -> Self {
+        Self {
+            program_id,
+            name,
+            params,
+            accounts,
+        }
+    }
+
+    fn __repr__(&self, py: Python<'_>) -> PyResult<String> {
+        Ok(format!(
+            "DecodedInstruction(program_id={}, name={:?}, params={})",
+            self.program_id.0,
+            self.name,
+            self.params.as_ref(py).repr()?
+        ))
+    }
+}
+
+/// An instruction that could not be decoded.
+///
+/// Args:
+///     program_id (Pubkey): The program that the instruction invokes.
+///     data (bytes): The raw instruction data.
+///     accounts (Sequence[Pubkey]): The accounts passed to the instruction.
+///     error (Optional[str]): Why decoding failed. ``None`` if no decoder is
+///         registered for the program.
+///
+#[pyclass(module = "solders.instruction_decoder", subclass)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnknownInstruction {
+    #[pyo3(get)]
+    program_id: Pubkey,
+    data: Vec<u8>,
+    #[pyo3(get)]
+    accounts: Vec<Pubkey>,
+    #[pyo3(get)]
+    error: Option<String>,
+}
+
+#[pymethods]
+impl UnknownInstruction {
+    #[new]
+    #[pyo3(signature = (program_id, data, accounts, error=None))]
+    pub fn new(
+        program_id: Pubkey,
+        data: &[u8],
+        accounts: Vec<Pubkey>,
+        error: Option<String>,
+    ) -> Self {
+        Self {
+            program_id,
+            data: data.to_vec(),
+            accounts,
+            error,
+        }
+    }
+
+    #[getter]
+    pub fn data<'a>(&self, py: Python<'a>) -> &'a PyBytes {
+        PyBytes::new(py, &self.data)
+    }
+
+    fn __eq__(&self, other: &Self) -> bool {
+        self == other
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "UnknownInstruction(program_id={}, data={:?}, error={:?})",
+            self.program_id.0, self.data, self.error
+        )
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum InstructionDecodeResult {
+    Decoded(DecodedInstruction),
+    Unknown(UnknownInstruction),
+}
+
+impl IntoPy<PyObject> for InstructionDecodeResult {
+    fn into_py(self, py: Python<'_>) -> PyObject {
+        match self {
+            Self::Decoded(x) => x.into_py(py),
+            Self::Unknown(x) => x.into_py(py),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+enum Decoder {
+    System,
+    ComputeBudget,
+    AddressLookupTable,
+    Python(PyObject),
+}
+
+impl Decoder {
+    fn decode(
+        &self,
+        py: Python<'_>,
+        data: &[u8],
+        accounts: &[PubkeyOriginal],
+    ) -> PyResult<(String, PyObject)> {
+        let decoded = match self {
+            Self::System => decode_system_instruction_data(data, accounts)?.into_py(py),
+            Self::ComputeBudget => {
+                decode_compute_budget_instruction_data(data)?.into_py_tuple(py)?
+            }
+            Self::AddressLookupTable => {
+                decode_lookup_table_instruction_data(data, accounts)?.into_py(py)
+            }
+            Self::Python(func) => {
+                let accounts_py: Vec<Pubkey> = accounts.iter().map(|a| Pubkey(*a)).collect();
+                func.call1(py, (PyBytes::new(py, data), accounts_py))?
+            }
+        };
+        decoded.extract(py)
+    }
+}
+
+/// An instruction that has been resolved against its message's account keys,
+/// or that the RPC node has already parsed.
+pub(crate) enum ResolvedInstruction {
+    Raw {
+        program_id: PubkeyOriginal,
+        data: Vec<u8>,
+        accounts: Vec<PubkeyOriginal>,
+    },
+    #[cfg(feature = "ring")]
+    Parsed(DecodedInstruction),
+}
+
+fn lookup_key(keys: &[PubkeyOriginal], index: u8) -> PyResult<PubkeyOriginal> {
+    keys.get(usize::from(index)).copied().ok_or_else(|| {
+        InstructionDecodeError::new_err(format!(
+            "Account index {index} is out of range for {} account keys",
+            keys.len()
+        ))
+    })
+}
+
+pub(crate) fn resolve_compiled(
+    keys: &[PubkeyOriginal],
+    program_id_index: u8,
+    account_indices: &[u8],
+    data: Vec<u8>,
+) -> PyResult<ResolvedInstruction> {
+    let program_id = lookup_key(keys, program_id_index)?;
+    let accounts = account_indices
+        .iter()
+        .map(|idx| lookup_key(keys, *idx))
+        .collect::<PyResult<Vec<_>>>()?;
+    Ok(ResolvedInstruction::Raw {
+        program_id,
+        data,
+        accounts,
+    })
+}
+
+pub(crate) fn resolve_message(
+    message: &VersionedMessageOriginal,
+    loaded_addresses: Option<(Vec<Pubkey>, Vec<Pubkey>)>,
+) -> PyResult<Vec<ResolvedInstruction>> {
+    let mut keys = message.static_account_keys().to_vec();
+    if let Some((writable, readonly)) = loaded_addresses {
+        keys.extend(writable.into_iter().chain(readonly).map(|k| k.0));
+    }
+    message
+        .instructions()
+        .iter()
+        .map(|ix| resolve_compiled(&keys, ix.program_id_index, &ix.accounts, ix.data.clone()))
+        .collect()
+}
+
+#[cfg(feature = "ring")]
+fn decode_base58(data: &str) -> PyResult<Vec<u8>> {
+    bs58::decode(data).into_vec().map_err(|e| {
+        InstructionDecodeError::new_err(format!("Invalid base58 instruction data: {e}"))
+    })
+}
+
+#[cfg(feature = "ring")]
+pub(crate) fn resolve_ui_instruction(
+    py: Python<'_>,
+    keys: &[PubkeyOriginal],
+    ix: UiInstruction,
+) -> PyResult<ResolvedInstruction> {
+    match ix {
+        UiInstruction::Compiled(c) => resolve_compiled(
+            keys,
+            c.program_id_index(),
+            c.accounts(py).as_bytes(),
+            decode_base58(&c.data())?,
+        ),
+        UiInstruction::Parsed(UiParsedInstruction::PartiallyDecoded(p)) => {
+            Ok(ResolvedInstruction::Raw {
+                program_id: p.program_id().0,
+                data: decode_base58(&p.data())?,
+                accounts: p.accounts().into_iter().map(|k| k.0).collect(),
+            })
+        }
+        UiInstruction::Parsed(UiParsedInstruction::Parsed(p)) => {
+            let parsed = p.parsed(py)?;
+            let parsed_ref = parsed.as_ref(py);
+            let (name, params) = match (
+                parsed_ref.get_item("type").ok(),
+                parsed_ref.get_item("info").ok(),
+            ) {
+                (Some(name), Some(info)) => (name.extract::<String>()?, info.into_py(py)),
+                _ => (p.program(), parsed),
+            };
+            Ok(ResolvedInstruction::Parsed(DecodedInstruction {
+                program_id: p.program_id(),
+                name,
+                params,
+                accounts: vec![],
+            }))
+        }
+    }
+}
+
+/// The full account keys of a UI transaction and its top-level instructions.
+#[cfg(feature = "ring")]
+pub(crate) fn ui_transaction_keys_and_instructions(
+    tx: &UiTransaction,
+    loaded_addresses: Option<(Vec<Pubkey>, Vec<Pubkey>)>,
+) -> (Vec<PubkeyOriginal>, Vec<UiInstruction>) {
+    match tx.message() {
+        UiMessage::Raw(msg) => {
+            let mut keys: Vec<PubkeyOriginal> =
+                msg.account_keys().into_iter().map(|k| k.0).collect();
+            if let Some((writable, readonly)) = loaded_addresses {
+                keys.extend(writable.into_iter().chain(readonly).map(|k| k.0));
+            }
+            let instructions = msg
+                .instructions()
+                .into_iter()
+                .map(UiInstruction::Compiled)
+                .collect();
+            (keys, instructions)
+        }
+        // jsonParsed account keys already include loaded addresses.
+        UiMessage::Parsed(msg) => (
+            msg.account_keys()
+                .into_iter()
+                .map(|k| k.pubkey().0)
+                .collect(),
+            msg.instructions(),
+        ),
+    }
+}
+
+#[cfg(feature = "ring")]
+fn resolve_ui_transaction(
+    py: Python<'_>,
+    tx: &UiTransaction,
+    loaded_addresses: Option<(Vec<Pubkey>, Vec<Pubkey>)>,
+) -> PyResult<Vec<ResolvedInstruction>> {
+    let (keys, instructions) = ui_transaction_keys_and_instructions(tx, loaded_addresses);
+    instructions
+        .into_iter()
+        .map(|ix| resolve_ui_instruction(py, &keys, ix))
+        .collect()
+}
+
+#[derive(FromPyObject)]
+pub enum TransactionToDecode {
+    Legacy(Transaction),
+    Versioned(VersionedTransaction),
+    #[cfg(feature = "ring")]
+    Ui(UiTransaction),
+}
+
+/// A registry mapping program IDs to instruction decoders.
+///
+/// By default the registry knows how to decode system program,
+/// compute budget and address lookup table instructions.
+/// Use :meth:`InstructionDecoderRegistry.empty` for a registry with no decoders.
+///
+/// Custom decoders are callables taking the raw instruction data and the
+/// instruction's accounts, and returning a tuple of the instruction name and
+/// its decoded params. A decoder should raise
+/// :class:`~solders.errors.InstructionDecodeError` if it cannot decode the data.
+///
+/// Example:
+///     >>> from solders.instruction_decoder import InstructionDecoderRegistry
+///     >>> from solders.pubkey import Pubkey
+///     >>> from solders.system_program import transfer, TransferParams
+///     >>> registry = InstructionDecoderRegistry()
+///     >>> from_pubkey, to_pubkey = Pubkey.new_unique(), Pubkey.new_unique()
+///     >>> ix = transfer(TransferParams(from_pubkey=from_pubkey, to_pubkey=to_pubkey, lamports=1))
+///     >>> decoded = registry.decode_instruction(ix)
+///     >>> decoded.name
+///     'Transfer'
+///     >>> decoded.params["lamports"]
+///     1
+///
+#[pyclass(module = "solders.instruction_decoder", subclass)]
+#[derive(Clone, Debug)]
+pub struct InstructionDecoderRegistry {
+    decoders: HashMap<PubkeyOriginal, Decoder>,
+}
+
+impl Default for InstructionDecoderRegistry {
+    fn default() -> Self {
+        let decoders = HashMap::from([
+            (system_program::ID, Decoder::System),
+            (compute_budget::ID, Decoder::ComputeBudget),
+            (
+                address_lookup_table::program::ID,
+                Decoder::AddressLookupTable,
+            ),
+        ]);
+        Self { decoders }
+    }
+}
+
+impl InstructionDecoderRegistry {
+    fn decode_raw(
+        &self,
+        py: Python<'_>,
+        program_id: PubkeyOriginal,
+        data: Vec<u8>,
+        accounts: Vec<PubkeyOriginal>,
+    ) -> PyResult<InstructionDecodeResult> {
+        let unknown = |data: Vec<u8>, error: Option<String>| {
+            InstructionDecodeResult::Unknown(UnknownInstruction {
+                program_id: Pubkey(program_id),
+                data,
+                accounts: accounts.iter().map(|a| Pubkey(*a)).collect(),
+                error,
+            })
+        };
+        let decoder = match self.decoders.get(&program_id) {
+            Some(d) => d,
+            None => return Ok(unknown(data, None)),
+        };
+        match decoder.decode(py, &data, &accounts) {
+            Ok((name, params)) => Ok(InstructionDecodeResult::Decoded(DecodedInstruction {
+                program_id: Pubkey(program_id),
+                name,
+                params,
+                accounts: accounts.iter().map(|a| Pubkey(*a)).collect(),
+            })),
+            Err(e) if e.is_instance_of::<InstructionDecodeError>(py) => {
+                Ok(unknown(data, Some(e.value(py).to_string())))
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    fn decode_resolved(
+        &self,
+        py: Python<'_>,
+        resolved: Vec<ResolvedInstruction>,
+    ) -> PyResult<Vec<InstructionDecodeResult>> {
+        resolved
+            .into_iter()
+            .map(|ix| match ix {
+                ResolvedInstruction::Raw {
+                    program_id,
+                    data,
+                    accounts,
+                } => self.decode_raw(py, program_id, data, accounts),
+                #[cfg(feature = "ring")]
+                ResolvedInstruction::Parsed(decoded) => {
+                    Ok(InstructionDecodeResult::Decoded(decoded))
+                }
+            })
+            .collect()
+    }
+}
+
+#[pymethods]
+impl InstructionDecoderRegistry {
+    #[new]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a registry with no decoders, not even the built-in ones.
+    ///
+    /// Returns:
+    ///     InstructionDecoderRegistry: The empty registry.
+    ///
+    #[staticmethod]
+    pub fn empty() -> Self {
+        Self {
+            decoders: HashMap::new(),
+        }
+    }
+
+    /// Register a decoder for a program, replacing any existing decoder.
+    ///
+    /// Args:
+    ///     program_id (Pubkey): The program whose instructions the decoder handles.
+    ///     decoder (Callable[[bytes, List[Pubkey]], Tuple[str, Any]]): The decoder.
+    ///
+    pub fn register(
+        &mut self,
+        py: Python<'_>,
+        program_id: Pubkey,
+        decoder: PyObject,
+    ) -> PyResult<()> {
+        if !decoder.as_ref(py).is_callable() {
+            return Err(pyo3::exceptions::PyTypeError::new_err(
+                "decoder must be callable",
+            ));
+        }
+        self.decoders.insert(program_id.0, Decoder::Python(decoder));
+        Ok(())
+    }
+
+    /// Remove the decoder for a program.
+    ///
+    /// Args:
+    ///     program_id (Pubkey): The program to remove.
+    ///
+    /// Returns:
+    ///     bool: Whether a decoder was registered for the program.
+    ///
+    pub fn unregister(&mut self, program_id: Pubkey) -> bool {
+        self.decoders.remove(&program_id.0).is_some()
+    }
+
+    /// The programs that have a registered decoder.
+    ///
+    /// Returns:
+    ///     List[Pubkey]: The program IDs.
+    ///
+    pub fn program_ids(&self) -> Vec<Pubkey> {
+        let mut ids: Vec<Pubkey> = self.decoders.keys().map(|k| Pubkey(*k)).collect();
+        ids.sort();
+        ids
+    }
+
+    fn __contains__(&self, program_id: Pubkey) -> bool {
+        self.decoders.contains_key(&program_id.0)
+    }
+
+    fn __len__(&self) -> usize {
+        self.decoders.len()
+    }
+
+    /// Decode a single instruction.
+    ///
+    /// Args:
+    ///     instruction (Instruction): The instruction to decode.
+    ///
+    /// Returns:
+    ///     Union[DecodedInstruction, UnknownInstruction]: The decoded instruction,
+    ///     or a placeholder if the program is unknown or the data is invalid.
+    ///
+    pub fn decode_instruction(
+        &self,
+        py: Python<'_>,
+        instruction: Instruction,
+    ) -> PyResult<InstructionDecodeResult> {
+        let ix = instruction.0;
+        self.decode_raw(
+            py,
+            ix.program_id,
+            ix.data,
+            ix.accounts.into_iter().map(|a| a.pubkey).collect(),
+        )
+    }
+
+    /// Decode every top-level instruction in a transaction.
+    ///
+    /// Args:
+    ///     tx (Union[Transaction, VersionedTransaction, UiTransaction]): The transaction to decode.
+    ///     loaded_addresses (Optional[Tuple[Sequence[Pubkey], Sequence[Pubkey]]]): The writable and
+    ///         readonly addresses loaded from lookup tables, needed to resolve account indices in
+    ///         v0 transactions that use address lookup tables.
+    ///
+    /// Returns:
+    ///     List[Union[DecodedInstruction, UnknownInstruction]]: The decoded instructions, in order.
+    ///
+    /// Raises:
+    ///     InstructionDecodeError: if an account index cannot be resolved.
+    ///
+    #[pyo3(signature = (tx, loaded_addresses=None))]
+    pub fn decode_transaction(
+        &self,
+        py: Python<'_>,
+        tx: TransactionToDecode,
+        loaded_addresses: Option<(Vec<Pubkey>, Vec<Pubkey>)>,
+    ) -> PyResult<Vec<InstructionDecodeResult>> {
+        let resolved = match tx {
+            TransactionToDecode::Legacy(t) => resolve_message(
+                &VersionedMessageOriginal::Legacy(t.0.message),
+                loaded_addresses,
+            )?,
+            TransactionToDecode::Versioned(t) => resolve_message(&t.0.message, loaded_addresses)?,
+            #[cfg(feature = "ring")]
+            TransactionToDecode::Ui(t) => resolve_ui_transaction(py, &t, loaded_addresses)?,
+        };
+        self.decode_resolved(py, resolved)
+    }
+}
+
+pub fn create_instruction_decoder_mod(py: Python<'_>) -> PyResult<&PyModule> {
+    let m = PyModule::new(py, "instruction_decoder")?;
+    m.add_class::<InstructionDecoderRegistry>()?;
+    m.add_class::<DecodedInstruction>()?;
+    m.add_class::<UnknownInstruction>()?;
+    m.add_class::<InstructionNode>()?;
+    m.add_function(wrap_pyfunction!(build_instruction_tree_from_logs, m)?)?;
+    #[cfg(feature = "ring")]
+    m.add_function(wrap_pyfunction!(build_instruction_tree, m)?)?;
+    Ok(m)
+}
@@ -0,0 +1,327 @@
+use pyo3::{prelude::*, types::PyBytes};
+use serde::{Deserialize, Serialize};
+use solana_sdk::message::VersionedMessage as VersionedMessageOriginal;
+use solders_log_parser::{ParsedLogs, ProgramInvocation};
+use solders_macros::{common_methods, richcmp_eq_only};
+use solders_pubkey::Pubkey;
+use solders_traits_core::transaction_status_boilerplate;
+use solders_transaction::{Transaction, VersionedTransaction};
+#[cfg(feature = "ring")]
+use {
+    crate::{resolve_ui_instruction, ui_transaction_keys_and_instructions},
+    pyo3::exceptions::PyValueError,
+    solana_sdk::pubkey::Pubkey as PubkeyOriginal,
+    solders_transaction_status::{
+        EncodedTransactionWithStatusMeta, EncodedVersionedTransaction, UiInstruction,
+        UiParsedInstruction,
+    },
+};
+
+use crate::{resolve_message, ResolvedInstruction};
+
+/// An instruction and the instructions it invoked via CPI.
+///
+/// Args:
+///     program_id (Pubkey): The invoked program.
+///     accounts (Sequence[Pubkey]): The accounts passed to the instruction.
+///         Empty if they are not known, e.g. for jsonParsed instructions or
+///         CPIs reconstructed from logs.
+///     data (Optional[bytes]): The instruction data, if known.
+///     stack_height (int): The invocation depth, starting at 1 for top-level instructions.
+///     children (Sequence[InstructionNode]): The instructions invoked by this one.
+///
+#[pyclass(module = "solders.instruction_decoder", subclass)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InstructionNode {
+    program_id: Pubkey,
+    accounts: Vec<Pubkey>,
+    data: Option<Vec<u8>>,
+    stack_height: u32,
+    children: Vec<InstructionNode>,
+}
+
+transaction_status_boilerplate!(InstructionNode);
+
+impl InstructionNode {
+    fn from_resolved(resolved: ResolvedInstruction, stack_height: u32) -> Self {
+        match resolved {
+            ResolvedInstruction::Raw {
+                program_id,
+                data,
+                accounts,
+            } => Self::new(
+                Pubkey(program_id),
+                accounts.into_iter().map(Pubkey).collect(),
+                Some(data),
+                stack_height,
+                vec![],
+            ),
+            #[cfg(feature = "ring")]
+            ResolvedInstruction::Parsed(decoded) => {
+                Self::new(decoded.program_id, vec![], None, stack_height, vec![])
+            }
+        }
+    }
+
+    fn from_invocation(invocation: &ProgramInvocation) -> Self {
+        Self::new(
+            invocation.program_id(),
+            vec![],
+            None,
+            u32::try_from(invocation.depth()).unwrap_or(u32::MAX),
+            invocation
+                .inner()
+                .iter()
+                .map(Self::from_invocation)
+                .collect(),
+        )
+    }
+
+    fn walk<'a>(&'a self, out: &mut Vec<&'a Self>) {
+        out.push(self);
+        for child in &self.children {
+            child.walk(out);
+        }
+    }
+}
+
+#[richcmp_eq_only]
+#[common_methods]
+#[pymethods]
+impl InstructionNode {
+    #[new]
+    #[pyo3(signature = (program_id, accounts, data, stack_height, children))]
+    pub fn new(
+        program_id: Pubkey,
+        accounts: Vec<Pubkey>,
+        data: Option<Vec<u8>>,
+        stack_height: u32,
+        children: Vec<InstructionNode>,
+    ) -> Self {
+        Self {
+            program_id,
+            accounts,
+            data,
+            stack_height,
+            children,
+        }
+    }
+
+    /// Pubkey: The invoked program.
+    #[getter]
+    pub fn program_id(&self) -> Pubkey {
+        self.program_id
+    }
+
+    /// List[Pubkey]: The accounts passed to the instruction.
+    #[getter]
+    pub fn accounts(&self) -> Vec<Pubkey> {
+        self.accounts.clone()
+    }
+
+    /// Optional[bytes]: The instruction data, if known.
+    #[getter]
+    pub fn data<'a>(&self, py: Python<'a>) -> Option<&'a PyBytes> {
+        self.data.as_ref().map(|d| PyBytes::new(py, d))
+    }
+
+    /// int: The invocation depth, starting at 1 for top-level instructions.
+    #[getter]
+    pub fn stack_height(&self) -> u32 {
+        self.stack_height
+    }
+
+    /// List[InstructionNode]: The instructions invoked by this one.
+    #[getter]
+    pub fn children(&self) -> Vec<InstructionNode> {
+        self.children.clone()
+    }
+
+    /// This node and all its descendants, depth first.
+    ///
+    /// Returns:
+    ///     List[InstructionNode]: The flattened subtree.
+    ///
+    pub fn walk_all(&self) -> Vec<InstructionNode> {
+        let mut out = vec![];
+        self.walk(&mut out);
+        out.into_iter().cloned().collect()
+    }
+
+    /// Find every instruction in this subtree that invokes ``program_id``.
+    ///
+    /// Args:
+    ///     program_id (Pubkey): The program to look for.
+    ///
+    /// Returns:
+    ///     List[InstructionNode]: The matching instructions, depth first.
+    ///
+    pub fn find(&self, program_id: Pubkey) -> Vec<InstructionNode> {
+        let mut out = vec![];
+        self.walk(&mut out);
+        out.into_iter()
+            .filter(|n| n.program_id == program_id)
+            .cloned()
+            .collect()
+    }
+}
+
+/// Place inner instructions under their parent using their stack heights.
+#[cfg(feature = "ring")]
+fn attach_inner(root: &mut InstructionNode, inner: Vec<(Option<u32>, InstructionNode)>) {
+    let mut stack: Vec<InstructionNode> = vec![root.clone()];
+    for (height, mut node) in inner {
+        // Nodes that don't report a stack height are treated as direct CPIs.
+        let height = height.unwrap_or(2).max(2);
+        node.stack_height = height;
+        while stack.len() > 1 && stack.last().map_or(false, |n| n.stack_height >= height) {
+            let done = stack.pop().unwrap();
+            stack.last_mut().unwrap().children.push(done);
+        }
+        stack.push(node);
+    }
+    while stack.len() > 1 {
+        let done = stack.pop().unwrap();
+        stack.last_mut().unwrap().children.push(done);
+    }
+    *root = stack.pop().unwrap();
+}
+
+#[cfg(feature = "ring")]
+fn ui_stack_height(ix: &UiInstruction) -> Option<u32> {
+    match ix {
+        UiInstruction::Compiled(c) => c.stack_height(),
+        UiInstruction::Parsed(UiParsedInstruction::Parsed(p)) => p.stack_height(),
+        UiInstruction::Parsed(UiParsedInstruction::PartiallyDecoded(p)) => p.stack_height(),
+    }
+}
+
+/// Build the CPI tree of a transaction fetched over RPC.
+///
+/// Top-level instructions are merged with the ``inner_instructions`` of the
+/// transaction meta, using each inner instruction's ``stack_height``.
+///
+/// Args:
+///     tx (EncodedTransactionWithStatusMeta): The transaction and its meta.
+///
+/// Returns:
+///     List[InstructionNode]: One node per top-level instruction.
+///
+/// Raises:
+///     ValueError: If the transaction was fetched with the ``accounts`` details level.
+///     InstructionDecodeError: If an account index cannot be resolved.
+///
+#[cfg(feature = "ring")]
+#[pyfunction]
+pub fn build_instruction_tree(
+    py: Python<'_>,
+    tx: EncodedTransactionWithStatusMeta,
+) -> PyResult<Vec<InstructionNode>> {
+    let meta = tx.meta();
+    let loaded_addresses = meta
+        .as_ref()
+        .and_then(|m| m.loaded_addresses())
+        .map(|l| (l.writable(), l.readonly()));
+    let (keys, top_level): (Vec<PubkeyOriginal>, Vec<ResolvedInstruction>) = match tx.transaction()
+    {
+        EncodedVersionedTransaction::Binary(t) => {
+            let mut keys = t.0.message.static_account_keys().to_vec();
+            if let Some((writable, readonly)) = loaded_addresses.clone() {
+                keys.extend(writable.into_iter().chain(readonly).map(|k| k.0));
+            }
+            (keys, resolve_message(&t.0.message, loaded_addresses)?)
+        }
+        EncodedVersionedTransaction::Json(t) => {
+            let (keys, instructions) = ui_transaction_keys_and_instructions(&t, loaded_addresses);
+            let resolved = instructions
+                .into_iter()
+                .map(|ix| resolve_ui_instruction(py, &keys, ix))
+                .collect::<PyResult<Vec<_>>>()?;
+            (keys, resolved)
+        }
+        EncodedVersionedTransaction::Accounts(_) => {
+            return Err(PyValueError::new_err(
+                "Transactions encoded with the accounts details level have no instructions",
+            ))
+        }
+    };
+    let mut roots: Vec<InstructionNode> = top_level
+        .into_iter()
+        .map(|ix| InstructionNode::from_resolved(ix, 1))
+        .collect();
+    for inner in meta
+        .and_then(|m| m.inner_instructions())
+        .unwrap_or_default()
+    {
+        let root = match roots.get_mut(usize::from(inner.index())) {
+            Some(r) => r,
+            None => continue,
+        };
+        let nodes = inner
+            .instructions()
+            .into_iter()
+            .map(|ix| {
+                let height = ui_stack_height(&ix);
+                Ok((
+                    height,
+                    InstructionNode::from_resolved(resolve_ui_instruction(py, &keys, ix)?, 2),
+                ))
+            })
+            .collect::<PyResult<Vec<_>>>()?;
+        attach_inner(root, nodes);
+    }
+    Ok(roots)
+}
+
+#[derive(FromPyObject)]
+pub enum TransactionForTree {
+    Legacy(Transaction),
+    Versioned(VersionedTransaction),
+}
+
+/// Build the CPI tree of a transaction from its program logs.
+///
+/// This is useful for bankrun results, which carry logs but no inner instructions.
+/// Top-level nodes carry their accounts and data; CPI nodes only carry the program ID
+/// and stack height, since the logs don't record anything else.
+///
+/// Args:
+///     tx (Union[Transaction, VersionedTransaction]): The transaction.
+///     logs (ParsedLogs): The parsed logs, e.g. from ``BanksTransactionMeta.parse_logs()``.
+///     loaded_addresses (Optional[Tuple[Sequence[Pubkey], Sequence[Pubkey]]]): The writable and
+///         readonly addresses loaded from lookup tables.
+///
+/// Returns:
+///     List[InstructionNode]: One node per top-level instruction.
+///
+/// Raises:
+///     InstructionDecodeError: If an account index cannot be resolved.
+///
+#[pyfunction]
+#[pyo3(signature = (tx, logs, loaded_addresses=None))]
+pub fn build_instruction_tree_from_logs(
+    tx: TransactionForTree,
+    logs: ParsedLogs,
+    loaded_addresses: Option<(Vec<Pubkey>, Vec<Pubkey>)>,
+) -> PyResult<Vec<InstructionNode>> {
+    let message = match tx {
+        TransactionForTree::Legacy(t) => VersionedMessageOriginal::Legacy(t.0.message),
+        TransactionForTree::Versioned(t) => t.0.message,
+    };
+    let invocations = logs.invocations();
+    let mut cursor = invocations.iter().peekable();
+    Ok(resolve_message(&message, loaded_addresses)?
+        .into_iter()
+        .map(|ix| {
+            let mut node = InstructionNode::from_resolved(ix, 1);
+            if let Some(invocation) = cursor.next_if(|inv| inv.program_id() == node.program_id) {
+                node.children = invocation
+                    .inner()
+                    .iter()
+                    .map(InstructionNode::from_invocation)
+                    .collect();
+            }
+            node
+        })
+        .collect())
+}
@@ -1,5 +1,6 @@
 use std::hash::Hasher;
 
+use borsh::{BorshDeserialize, BorshSerialize};
 use derive_more::{From, Into};
 use pyo3::{prelude::*, types::PyBytes};
 use serde::{Deserialize, Serialize};
@@ -10,7 +11,7 @@ use solana_program::{
     },
     pubkey::Pubkey as PubkeyOriginal,
 };
-use solders_macros::{common_methods, pyhash, richcmp_eq_only};
+use solders_macros::{borsh_methods, common_methods, pyhash, richcmp_eq_only};
 use solders_pubkey::Pubkey;
 
 use solders_traits_core::{
@@ -52,6 +53,7 @@ pub struct AccountMeta(AccountMetaOriginal);
 #[pyhash]
 #[richcmp_eq_only]
 #[common_methods]
+#[borsh_methods]
 #[pymethods]
 impl AccountMeta {
     #[new]
@@ -98,6 +100,24 @@ impl RichcmpEqualityOnly for AccountMeta {}
 py_from_bytes_general_via_bincode!(AccountMeta);
 
 solders_traits_core::common_methods_default!(AccountMeta);
+solders_traits_core::borsh_methods_default!(AccountMeta);
+
+impl BorshSerialize for AccountMeta {
+    fn serialize<W: borsh::io::Write>(&self, writer: &mut W) -> borsh::io::Result<()> {
+        BorshSerialize::serialize(&Pubkey(self.0.pubkey), writer)?;
+        BorshSerialize::serialize(&self.0.is_signer, writer)?;
+        BorshSerialize::serialize(&self.0.is_writable, writer)
+    }
+}
+
+impl BorshDeserialize for AccountMeta {
+    fn deserialize_reader<R: borsh::io::Read>(reader: &mut R) -> borsh::io::Result<Self> {
+        let pubkey = Pubkey::deserialize_reader(reader)?;
+        let is_signer = bool::deserialize_reader(reader)?;
+        let is_writable = bool::deserialize_reader(reader)?;
+        Ok(Self::new(&pubkey, is_signer, is_writable))
+    }
+}
 
 impl PyHash for AccountMeta {}
 impl_display!(AccountMeta);
@@ -178,6 +198,7 @@ pub struct Instruction(pub InstructionOriginal);
 
 #[richcmp_eq_only]
 #[common_methods]
+#[borsh_methods]
 #[pymethods]
 impl Instruction {
     #[new]
@@ -247,6 +268,24 @@ impl RichcmpEqualityOnly for Instruction {}
 py_from_bytes_general_via_bincode!(Instruction);
 
 solders_traits_core::common_methods_default!(Instruction);
+solders_traits_core::borsh_methods_default!(Instruction);
+
+impl BorshSerialize for Instruction {
+    fn serialize<W: borsh::io::Write>(&self, writer: &mut W) -> borsh::io::Result<()> {
+        BorshSerialize::serialize(&self.program_id(), writer)?;
+        BorshSerialize::serialize(&self.accounts(), writer)?;
+        BorshSerialize::serialize(&self.0.data, writer)
+    }
+}
+
+impl BorshDeserialize for Instruction {
+    fn deserialize_reader<R: borsh::io::Read>(reader: &mut R) -> borsh::io::Result<Self> {
+        let program_id = Pubkey::deserialize_reader(reader)?;
+        let accounts = Vec::<AccountMeta>::deserialize_reader(reader)?;
+        let data = Vec::<u8>::deserialize_reader(reader)?;
+        Ok(Self::new(&program_id, &data, accounts))
+    }
+}
 
 impl_display!(Instruction);
 
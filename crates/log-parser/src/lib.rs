@@ -0,0 +1,468 @@
+use std::str::FromStr;
+
+use pyo3::{prelude::*, types::PyBytes};
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey as PubkeyOriginal;
+use solders_macros::{common_methods, richcmp_eq_only};
+use solders_pubkey::Pubkey;
+use solders_traits_core::transaction_status_boilerplate;
+
+const LOG_TRUNCATED: &str = "Log truncated";
+const PROGRAM_LOG: &str = "Program log: ";
+const PROGRAM_DATA: &str = "Program data: ";
+const PROGRAM_RETURN: &str = "Program return: ";
+
+/// A single program invocation reconstructed from transaction logs.
+///
+/// Args:
+///     program_id (Pubkey): The invoked program.
+///     depth (int): The invocation depth, starting at 1 for top-level instructions.
+///     logs (Sequence[str]): The ``Program log:`` messages emitted by this invocation.
+///     data (Sequence[Sequence[bytes]]): The decoded ``Program data:`` entries
+///         emitted by this invocation. Each entry holds one or more fields.
+///     other_logs (Sequence[str]): Any other log lines emitted while this invocation was
+///         the innermost one, such as ``Program consumption:`` lines.
+///     inner (Sequence[ProgramInvocation]): The invocations made via CPI.
+///     compute_units_consumed (Optional[int]): The compute units consumed, if logged.
+///     compute_units_budget (Optional[int]): The compute units available, if logged.
+///     return_data (Optional[bytes]): The return data set by this invocation, if any.
+///     success (Optional[bool]): Whether the invocation succeeded.
+///         ``None`` if the logs end before the invocation completes.
+///     error (Optional[str]): The failure message, if the invocation failed.
+///
+#[pyclass(module = "solders.log_parser", subclass)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProgramInvocation {
+    program_id: Pubkey,
+    depth: u64,
+    logs: Vec<String>,
+    data: Vec<Vec<Vec<u8>>>,
+    other_logs: Vec<String>,
+    inner: Vec<ProgramInvocation>,
+    compute_units_consumed: Option<u64>,
+    compute_units_budget: Option<u64>,
+    return_data: Option<Vec<u8>>,
+    success: Option<bool>,
+    error: Option<String>,
+}
+
+transaction_status_boilerplate!(ProgramInvocation);
+
+impl ProgramInvocation {
+    fn start(program_id: Pubkey, depth: u64) -> Self {
+        Self {
+            program_id,
+            depth,
+            logs: vec![],
+            data: vec![],
+            other_logs: vec![],
+            inner: vec![],
+            compute_units_consumed: None,
+            compute_units_budget: None,
+            return_data: None,
+            success: None,
+            error: None,
+        }
+    }
+
+    fn walk<'a>(&'a self, out: &mut Vec<&'a Self>) {
+        out.push(self);
+        for child in &self.inner {
+            child.walk(out);
+        }
+    }
+}
+
+#[richcmp_eq_only]
+#[common_methods]
+#[pymethods]
+impl ProgramInvocation {
+    #[allow(clippy::too_many_arguments)]
+    #[new]
+    #[pyo3(signature = (program_id, depth, logs, data, other_logs, inner, compute_units_consumed=None, compute_units_budget=None, return_data=None, success=None, error=None))]
+    pub fn new(
+        program_id: Pubkey,
+        depth: u64,
+        logs: Vec<String>,
+        data: Vec<Vec<Vec<u8>>>,
+        other_logs: Vec<String>,
+        inner: Vec<ProgramInvocation>,
+        compute_units_consumed: Option<u64>,
+        compute_units_budget: Option<u64>,
+        return_data: Option<Vec<u8>>,
+        success: Option<bool>,
+        error: Option<String>,
+    ) -> Self {
+        Self {
+            program_id,
+            depth,
+            logs,
+            data,
+            other_logs,
+            inner,
+            compute_units_consumed,
+            compute_units_budget,
+            return_data,
+            success,
+            error,
+        }
+    }
+
+    /// Pubkey: The invoked program.
+    #[getter]
+    pub fn program_id(&self) -> Pubkey {
+        self.program_id
+    }
+
+    /// int: The invocation depth, starting at 1 for top-level instructions.
+    #[getter]
+    pub fn depth(&self) -> u64 {
+        self.depth
+    }
+
+    /// List[str]: The ``Program log:`` messages, without the prefix.
+    #[getter]
+    pub fn logs(&self) -> Vec<String> {
+        self.logs.clone()
+    }
+
+    /// List[List[bytes]]: The decoded ``Program data:`` entries.
+    #[getter]
+    pub fn data<'a>(&self, py: Python<'a>) -> Vec<Vec<&'a PyBytes>> {
+        self.data
+            .iter()
+            .map(|fields| fields.iter().map(|f| PyBytes::new(py, f)).collect())
+            .collect()
+    }
+
+    /// List[str]: Other log lines emitted while this invocation was the innermost one.
+    #[getter]
+    pub fn other_logs(&self) -> Vec<String> {
+        self.other_logs.clone()
+    }
+
+    /// List[ProgramInvocation]: The invocations made via CPI.
+    #[getter]
+    pub fn inner(&self) -> Vec<ProgramInvocation> {
+        self.inner.clone()
+    }
+
+    /// Optional[int]: The compute units consumed, if logged.
+    #[getter]
+    pub fn compute_units_consumed(&self) -> Option<u64> {
+        self.compute_units_consumed
+    }
+
+    /// Optional[int]: The compute units available to the invocation, if logged.
+    #[getter]
+    pub fn compute_units_budget(&self) -> Option<u64> {
+        self.compute_units_budget
+    }
+
+    /// Optional[bytes]: The return data set by this invocation.
+    #[getter]
+    pub fn return_data<'a>(&self, py: Python<'a>) -> Option<&'a PyBytes> {
+        self.return_data.as_ref().map(|d| PyBytes::new(py, d))
+    }
+
+    /// Optional[bool]: Whether the invocation succeeded. ``None`` if it never completed in the logs.
+    #[getter]
+    pub fn success(&self) -> Option<bool> {
+        self.success
+    }
+
+    /// Optional[str]: The failure message, if the invocation failed.
+    #[getter]
+    pub fn error(&self) -> Option<String> {
+        self.error.clone()
+    }
+
+    /// Flatten this invocation and all its CPIs in execution order.
+    ///
+    /// Returns:
+    ///     List[ProgramInvocation]: This invocation followed by its descendants, depth first.
+    ///
+    pub fn flatten(&self) -> Vec<ProgramInvocation> {
+        let mut out = vec![];
+        self.walk(&mut out);
+        out.into_iter().cloned().collect()
+    }
+}
+
+/// Transaction logs parsed into a call tree.
+///
+/// Args:
+///     invocations (Sequence[ProgramInvocation]): The top-level program invocations.
+///     other_logs (Sequence[str]): Log lines that appear outside any invocation.
+///     truncated (bool): Whether the logs were truncated by the runtime.
+///
+#[pyclass(module = "solders.log_parser", subclass)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ParsedLogs {
+    invocations: Vec<ProgramInvocation>,
+    other_logs: Vec<String>,
+    truncated: bool,
+}
+
+transaction_status_boilerplate!(ParsedLogs);
+
+#[richcmp_eq_only]
+#[common_methods]
+#[pymethods]
+impl ParsedLogs {
+    #[new]
+    pub fn new(
+        invocations: Vec<ProgramInvocation>,
+        other_logs: Vec<String>,
+        truncated: bool,
+    ) -> Self {
+        Self {
+            invocations,
+            other_logs,
+            truncated,
+        }
+    }
+
+    /// List[ProgramInvocation]: The top-level program invocations.
+    #[getter]
+    pub fn invocations(&self) -> Vec<ProgramInvocation> {
+        self.invocations.clone()
+    }
+
+    /// List[str]: Log lines that appear outside any invocation.
+    #[getter]
+    pub fn other_logs(&self) -> Vec<String> {
+        self.other_logs.clone()
+    }
+
+    /// bool: Whether the logs were truncated by the runtime.
+    #[getter]
+    pub fn truncated(&self) -> bool {
+        self.truncated
+    }
+
+    /// Optional[bool]: Whether every top-level invocation succeeded.
+    /// ``None`` if any invocation never completed in the logs.
+    #[getter]
+    pub fn success(&self) -> Option<bool> {
+        let mut result = Some(true);
+        for ix in &self.invocations {
+            match ix.success {
+                Some(false) => return Some(false),
+                None => result = None,
+                Some(true) => {}
+            }
+        }
+        result
+    }
+
+    /// All invocations, including CPIs, in execution order.
+    ///
+    /// Returns:
+    ///     List[ProgramInvocation]: The flattened call tree.
+    ///
+    pub fn flatten(&self) -> Vec<ProgramInvocation> {
+        let mut out = vec![];
+        for ix in &self.invocations {
+            ix.walk(&mut out);
+        }
+        out.into_iter().cloned().collect()
+    }
+
+    /// Get every ``Program log:`` message in execution order.
+    ///
+    /// Args:
+    ///     program_id (Optional[Pubkey]): Only include messages from this program.
+    ///
+    /// Returns:
+    ///     List[str]: The log messages.
+    ///
+    #[pyo3(signature = (program_id=None))]
+    pub fn program_logs(&self, program_id: Option<Pubkey>) -> Vec<String> {
+        let mut out = vec![];
+        for ix in &self.invocations {
+            ix.walk(&mut out);
+        }
+        out.into_iter()
+            .filter(|ix| program_id.map_or(true, |p| p == ix.program_id))
+            .flat_map(|ix| ix.logs.clone())
+            .collect()
+    }
+}
+
+struct LogParser {
+    stack: Vec<ProgramInvocation>,
+    parsed: ParsedLogs,
+}
+
+impl LogParser {
+    fn new() -> Self {
+        Self {
+            stack: vec![],
+            parsed: ParsedLogs::new(vec![], vec![], false),
+        }
+    }
+
+    fn finish_top(&mut self) {
+        if let Some(done) = self.stack.pop() {
+            match self.stack.last_mut() {
+                Some(parent) => parent.inner.push(done),
+                None => self.parsed.invocations.push(done),
+            }
+        }
+    }
+
+    fn other(&mut self, line: &str) {
+        match self.stack.last_mut() {
+            Some(current) => current.other_logs.push(line.to_owned()),
+            None => self.parsed.other_logs.push(line.to_owned()),
+        }
+    }
+
+    /// Handle a ``Program <id> ...`` line. Returns false if the line isn't recognised.
+    fn program_line(&mut self, rest: &str) -> bool {
+        let (id, tail) = match rest.split_once(' ') {
+            Some(x) => x,
+            None => return false,
+        };
+        let program_id = match PubkeyOriginal::from_str(id) {
+            Ok(p) => Pubkey(p),
+            Err(_) => return false,
+        };
+        let is_current = self
+            .stack
+            .last()
+            .map_or(false, |c| c.program_id == program_id);
+        if let Some(depth) = tail
+            .strip_prefix("invoke [")
+            .and_then(|d| d.strip_suffix(']'))
+        {
+            return match depth.parse() {
+                Ok(depth) => {
+                    self.stack.push(ProgramInvocation::start(program_id, depth));
+                    true
+                }
+                Err(_) => false,
+            };
+        }
+        if !is_current {
+            return false;
+        }
+        if tail == "success" {
+            if let Some(current) = self.stack.last_mut() {
+                current.success = Some(true);
+            }
+            self.finish_top();
+            return true;
+        }
+        if let Some(msg) = tail.strip_prefix("failed: ") {
+            if let Some(current) = self.stack.last_mut() {
+                current.success = Some(false);
+                current.error = Some(msg.to_owned());
+            }
+            self.finish_top();
+            return true;
+        }
+        if let Some(units) = tail
+            .strip_prefix("consumed ")
+            .and_then(|u| u.strip_suffix(" compute units"))
+        {
+            if let Some((consumed, budget)) = units.split_once(" of ") {
+                if let (Ok(consumed), Ok(budget), Some(current)) =
+                    (consumed.parse(), budget.parse(), self.stack.last_mut())
+                {
+                    current.compute_units_consumed = Some(consumed);
+                    current.compute_units_budget = Some(budget);
+                    return true;
+                }
+            }
+        }
+        false
+    }
+
+    fn line(&mut self, line: &str) {
+        if line == LOG_TRUNCATED {
+            self.parsed.truncated = true;
+            return;
+        }
+        if let Some(msg) = line.strip_prefix(PROGRAM_LOG) {
+            if let Some(current) = self.stack.last_mut() {
+                current.logs.push(msg.to_owned());
+                return;
+            }
+        } else if let Some(encoded) = line.strip_prefix(PROGRAM_DATA) {
+            let fields: Result<Vec<Vec<u8>>, _> =
+                encoded.split_whitespace().map(base64::decode).collect();
+            if let (Ok(fields), Some(current)) = (fields, self.stack.last_mut()) {
+                current.data.push(fields);
+                return;
+            }
+        } else if let Some(ret) = line.strip_prefix(PROGRAM_RETURN) {
+            if let Some((id, encoded)) = ret.split_once(' ') {
+                let decoded = base64::decode(encoded.trim());
+                if let (Ok(decoded), Some(current)) = (decoded, self.stack.last_mut()) {
+                    if current.program_id.0.to_string() == id {
+                        current.return_data = Some(decoded);
+                        return;
+                    }
+                }
+            }
+        } else if let Some(rest) = line.strip_prefix("Program ") {
+            if self.program_line(rest) {
+                return;
+            }
+        }
+        self.other(line);
+    }
+
+    fn finish(mut self) -> ParsedLogs {
+        while !self.stack.is_empty() {
+            self.finish_top();
+        }
+        self.parsed
+    }
+}
+
+/// Parse transaction log messages into a call tree.
+pub fn parse_log_messages(log_messages: &[String]) -> ParsedLogs {
+    let mut parser = LogParser::new();
+    for line in log_messages {
+        parser.line(line);
+    }
+    parser.finish()
+}
+
+/// Parse transaction log messages into a call tree.
+///
+/// Args:
+///     log_messages (Sequence[str]): The log messages, e.g. from ``BanksTransactionMeta.log_messages``
+///         or ``UiTransactionStatusMeta.log_messages``.
+///
+/// Returns:
+///     ParsedLogs: The parsed logs.
+///
+/// Example:
+///     >>> from solders.log_parser import parse_logs
+///     >>> logs = [
+///     ...     "Program 11111111111111111111111111111111 invoke [1]",
+///     ...     "Program log: hello",
+///     ...     "Program 11111111111111111111111111111111 consumed 150 of 200000 compute units",
+///     ...     "Program 11111111111111111111111111111111 success",
+///     ... ]
+///     >>> parsed = parse_logs(logs)
+///     >>> ix = parsed.invocations[0]
+///     >>> ix.logs, ix.compute_units_consumed, ix.success
+///     (['hello'], 150, True)
+///
+#[pyfunction]
+pub fn parse_logs(log_messages: Vec<String>) -> ParsedLogs {
+    parse_log_messages(&log_messages)
+}
+
+pub fn create_log_parser_mod(py: Python<'_>) -> PyResult<&PyModule> {
+    let m = PyModule::new(py, "log_parser")?;
+    m.add_class::<ProgramInvocation>()?;
+    m.add_class::<ParsedLogs>()?;
+    m.add_function(wrap_pyfunction!(parse_logs, m)?)?;
+    Ok(m)
+}
@@ -167,6 +167,36 @@ pub fn common_methods(_: TokenStream, item: TokenStream) -> TokenStream {
     TokenStream::from(ast.to_token_stream())
 }
 
+/// Add `to_borsh` and `from_borsh` using the `PyBytesBorsh` and `PyFromBytesBorsh` traits.
+#[proc_macro_attribute]
+pub fn borsh_methods(_: TokenStream, item: TokenStream) -> TokenStream {
+    let mut ast = parse_macro_input!(item as ItemImpl);
+    let methods = vec![
+        ImplItem::Verbatim(quote! {
+        /// Serialize with Borsh.
+        ///
+        /// Returns:
+        ///     bytes: the Borsh-serialized object.
+        ///
+        pub fn to_borsh<'a>(&self, py: pyo3::prelude::Python<'a>) -> &'a pyo3::types::PyBytes {
+            solders_traits_core::PyBytesBorsh::pybytes_borsh(self, py)
+        } }),
+        ImplItem::Verbatim(quote! {
+        /// Deserialize from Borsh bytes.
+        ///
+        /// Args:
+        ///     data (bytes): the Borsh-serialized object.
+        ///
+        /// Returns: the deserialized object.
+        ///
+        #[staticmethod] pub fn from_borsh(data: &[u8]) -> PyResult<Self> {
+            <Self as solders_traits_core::PyFromBytesBorsh>::py_from_bytes_borsh(data)
+        } }),
+    ];
+    ast.items.extend_from_slice(&methods);
+    TokenStream::from(ast.to_token_stream())
+}
+
 /// Add `__bytes__`, `__str__`, `__repr__`, `__reduce__`, `to_json`, `from_json`, `from_bytes` and `__richcmp__` using the `CommonMethodsRpcResp` trait.
 #[proc_macro_attribute]
 pub fn common_methods_rpc_resp(_: TokenStream, item: TokenStream) -> TokenStream {
@@ -224,6 +254,18 @@ pub fn common_methods_rpc_resp(_: TokenStream, item: TokenStream) -> TokenStream
                 solders_traits_core::RichcmpEqualityOnly::richcmp(self, other, op)
             }},
         ),
+        ImplItem::Verbatim(quote! {
+            /// Raise an ``RpcError`` if this is an error response.
+            ///
+            /// This is a successful response, so it is returned unchanged.
+            /// Error responses raise the matching ``RpcError`` subclass instead.
+            ///
+            /// Returns: this response.
+            ///
+            pub fn raise_for_error(slf: pyo3::prelude::PyRef<'_, Self>) -> pyo3::prelude::PyRef<'_, Self> {
+                slf
+            }
+        }),
     ];
     ast.items.extend_from_slice(&methods);
     TokenStream::from(ast.to_token_stream())
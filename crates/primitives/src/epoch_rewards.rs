@@ -0,0 +1,71 @@
+use derive_more::{From, Into};
+use pyo3::prelude::*;
+use serde::{Deserialize, Serialize};
+use solana_program::epoch_rewards::EpochRewards as EpochRewardsOriginal;
+use solders_macros::{common_methods, richcmp_eq_only};
+
+use solders_traits_core::{
+    impl_display, py_from_bytes_general_via_bincode, pybytes_general_via_bincode,
+    RichcmpEqualityOnly,
+};
+
+/// The progress of the staking rewards distribution for the current epoch.
+///
+/// Args:
+///     total_rewards (int): Total rewards for the current epoch, in lamports.
+///     distributed_rewards (int): Rewards distributed so far in the current epoch, in lamports.
+///     distribution_complete_block_height (int): The block height at which the distribution
+///         of all staking rewards for the current epoch will be complete.
+///
+#[pyclass(module = "solders.epoch_rewards", subclass)]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Default, From, Into)]
+pub struct EpochRewards(pub EpochRewardsOriginal);
+
+#[richcmp_eq_only]
+#[common_methods]
+#[pymethods]
+impl EpochRewards {
+    #[new]
+    pub fn new(
+        total_rewards: u64,
+        distributed_rewards: u64,
+        distribution_complete_block_height: u64,
+    ) -> Self {
+        EpochRewardsOriginal {
+            total_rewards,
+            distributed_rewards,
+            distribution_complete_block_height,
+        }
+        .into()
+    }
+
+    /// int: Total rewards for the current epoch, in lamports.
+    #[getter]
+    pub fn total_rewards(&self) -> u64 {
+        self.0.total_rewards
+    }
+
+    /// int: Rewards distributed so far in the current epoch, in lamports.
+    #[getter]
+    pub fn distributed_rewards(&self) -> u64 {
+        self.0.distributed_rewards
+    }
+
+    /// int: The block height at which the rewards distribution will be complete.
+    #[getter]
+    pub fn distribution_complete_block_height(&self) -> u64 {
+        self.0.distribution_complete_block_height
+    }
+}
+
+impl_display!(EpochRewards);
+pybytes_general_via_bincode!(EpochRewards);
+py_from_bytes_general_via_bincode!(EpochRewards);
+solders_traits_core::common_methods_default!(EpochRewards);
+impl RichcmpEqualityOnly for EpochRewards {}
+
+pub fn create_epoch_rewards_mod(py: Python<'_>) -> PyResult<&PyModule> {
+    let m = PyModule::new(py, "epoch_rewards")?;
+    m.add_class::<EpochRewards>()?;
+    Ok(m)
+}
@@ -1,3 +1,5 @@
 pub mod clock;
+pub mod epoch_rewards;
 pub mod epoch_schedule;
 pub mod rent;
+pub mod stake_history;
@@ -0,0 +1,141 @@
+use derive_more::{From, Into};
+use pyo3::prelude::*;
+use serde::{Deserialize, Serialize};
+use solana_program::{
+    clock::Epoch,
+    stake_history::{
+        StakeHistory as StakeHistoryOriginal, StakeHistoryEntry as StakeHistoryEntryOriginal,
+        MAX_ENTRIES,
+    },
+};
+use solders_macros::{common_methods, richcmp_eq_only};
+
+use solders_traits_core::{
+    impl_display, py_from_bytes_general_via_bincode, pybytes_general_via_bincode,
+    RichcmpEqualityOnly,
+};
+
+/// The stake activation state of the cluster in one epoch.
+///
+/// Args:
+///     effective (int): Effective stake at this epoch.
+///     activating (int): Sum of portion of stakes not fully warmed up.
+///     deactivating (int): Stake requested to be cooled down, not fully deactivated yet.
+///
+#[pyclass(module = "solders.stake_history", subclass)]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Default, From, Into)]
+pub struct StakeHistoryEntry(pub StakeHistoryEntryOriginal);
+
+#[richcmp_eq_only]
+#[common_methods]
+#[pymethods]
+impl StakeHistoryEntry {
+    #[new]
+    #[pyo3(signature = (effective, activating=0, deactivating=0))]
+    pub fn new(effective: u64, activating: u64, deactivating: u64) -> Self {
+        StakeHistoryEntryOriginal {
+            effective,
+            activating,
+            deactivating,
+        }
+        .into()
+    }
+
+    /// int: Effective stake at this epoch.
+    #[getter]
+    pub fn effective(&self) -> u64 {
+        self.0.effective
+    }
+
+    /// int: Sum of portion of stakes not fully warmed up.
+    #[getter]
+    pub fn activating(&self) -> u64 {
+        self.0.activating
+    }
+
+    /// int: Stake requested to be cooled down, not fully deactivated yet.
+    #[getter]
+    pub fn deactivating(&self) -> u64 {
+        self.0.deactivating
+    }
+}
+
+impl_display!(StakeHistoryEntry);
+pybytes_general_via_bincode!(StakeHistoryEntry);
+py_from_bytes_general_via_bincode!(StakeHistoryEntry);
+solders_traits_core::common_methods_default!(StakeHistoryEntry);
+impl RichcmpEqualityOnly for StakeHistoryEntry {}
+
+/// The history of the cluster's stake activation, most recent epoch first.
+///
+/// Args:
+///     entries (Sequence[Tuple[int, StakeHistoryEntry]]): (epoch, entry) pairs, in any order.
+///         At most ``MAX_ENTRIES`` are kept.
+///
+#[pyclass(module = "solders.stake_history", subclass)]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Default, From, Into)]
+pub struct StakeHistory(pub StakeHistoryOriginal);
+
+#[richcmp_eq_only]
+#[common_methods]
+#[pymethods]
+impl StakeHistory {
+    #[new]
+    #[pyo3(signature = (entries=None))]
+    pub fn new(entries: Option<Vec<(Epoch, StakeHistoryEntry)>>) -> Self {
+        let mut history = StakeHistoryOriginal::default();
+        for (epoch, entry) in entries.unwrap_or_default() {
+            history.add(epoch, entry.0);
+        }
+        history.into()
+    }
+
+    /// List[Tuple[int, StakeHistoryEntry]]: The (epoch, entry) pairs, most recent epoch first.
+    #[getter]
+    pub fn entries(&self) -> Vec<(Epoch, StakeHistoryEntry)> {
+        self.0
+            .iter()
+            .map(|(epoch, entry)| (*epoch, entry.clone().into()))
+            .collect()
+    }
+
+    /// Get the entry for an epoch.
+    ///
+    /// Args:
+    ///     epoch (int): The epoch.
+    ///
+    /// Returns:
+    ///     Optional[StakeHistoryEntry]: The entry, if there is one.
+    ///
+    pub fn get(&self, epoch: Epoch) -> Option<StakeHistoryEntry> {
+        self.0.get(epoch).cloned().map(Into::into)
+    }
+
+    /// Add or replace the entry for an epoch.
+    ///
+    /// Args:
+    ///     epoch (int): The epoch.
+    ///     entry (StakeHistoryEntry): The entry.
+    ///
+    pub fn add(&mut self, epoch: Epoch, entry: StakeHistoryEntry) {
+        self.0.add(epoch, entry.0);
+    }
+
+    fn __len__(&self) -> usize {
+        self.0.len()
+    }
+}
+
+impl_display!(StakeHistory);
+pybytes_general_via_bincode!(StakeHistory);
+py_from_bytes_general_via_bincode!(StakeHistory);
+solders_traits_core::common_methods_default!(StakeHistory);
+impl RichcmpEqualityOnly for StakeHistory {}
+
+pub fn create_stake_history_mod(py: Python<'_>) -> PyResult<&PyModule> {
+    let m = PyModule::new(py, "stake_history")?;
+    m.add_class::<StakeHistoryEntry>()?;
+    m.add_class::<StakeHistory>()?;
+    m.add("MAX_ENTRIES", MAX_ENTRIES)?;
+    Ok(m)
+}
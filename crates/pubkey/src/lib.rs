@@ -1,10 +1,11 @@
 use std::{hash::Hash, str::FromStr};
 
+use borsh::{BorshDeserialize, BorshSerialize};
 use derive_more::{From, Into};
 use pyo3::prelude::*;
 use serde::{Deserialize, Serialize};
 use solana_program::pubkey::{ParsePubkeyError, Pubkey as PubkeyOriginal, PUBKEY_BYTES};
-use solders_macros::{common_methods, pyhash, richcmp_full};
+use solders_macros::{borsh_methods, common_methods, pyhash, richcmp_full};
 use solders_traits::handle_py_err;
 use solders_traits_core::{
     handle_py_value_err, pybytes_general_via_slice, CommonMethodsCore, PyFromBytesGeneral, PyHash,
@@ -45,6 +46,7 @@ pub struct Pubkey(pub PubkeyOriginal);
 #[pyhash]
 #[richcmp_full]
 #[common_methods]
+#[borsh_methods]
 #[pymethods]
 impl Pubkey {
     #[classattr]
@@ -261,6 +263,19 @@ impl PyFromBytesGeneral for Pubkey {
     }
 }
 solders_traits_core::common_methods_default!(Pubkey);
+solders_traits_core::borsh_methods_default!(Pubkey);
+
+impl BorshSerialize for Pubkey {
+    fn serialize<W: borsh::io::Write>(&self, writer: &mut W) -> borsh::io::Result<()> {
+        BorshSerialize::serialize(&self.0.to_bytes(), writer)
+    }
+}
+
+impl BorshDeserialize for Pubkey {
+    fn deserialize_reader<R: borsh::io::Read>(reader: &mut R) -> borsh::io::Result<Self> {
+        <[u8; PUBKEY_BYTES]>::deserialize_reader(reader).map(Self::new)
+    }
+}
 
 impl AsRef<[u8]> for Pubkey {
     fn as_ref(&self) -> &[u8] {
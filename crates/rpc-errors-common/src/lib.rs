@@ -1,6 +1,177 @@
+use pyo3::{create_exception, exceptions::PyException, prelude::*, PyTypeInfo};
+
+create_exception!(
+    solders,
+    RpcError,
+    PyException,
+    "Base class for errors returned by the RPC node. ``error`` holds the error object and ``data`` its data, if any."
+);
+
+macro_rules! rpc_exception {
+    ($name:ident, $doc:expr) => {
+        create_exception!(solders, $name, RpcError, $doc);
+    };
+}
+
+rpc_exception!(
+    RpcParseError,
+    "Raised when the RPC node receives invalid JSON."
+);
+rpc_exception!(
+    RpcInvalidRequest,
+    "Raised when the request is not a valid JSON-RPC request."
+);
+rpc_exception!(
+    RpcMethodNotFound,
+    "Raised when the RPC method does not exist."
+);
+rpc_exception!(
+    RpcInvalidParams,
+    "Raised when the RPC method params are invalid."
+);
+rpc_exception!(RpcInternalError, "Raised on an internal JSON-RPC error.");
+rpc_exception!(
+    RpcTransactionSignatureVerificationFailure,
+    "Raised when transaction signature verification fails."
+);
+rpc_exception!(RpcNoSnapshot, "Raised when the node has no snapshot.");
+rpc_exception!(
+    RpcTransactionHistoryNotAvailable,
+    "Raised when transaction history is not available from the node."
+);
+rpc_exception!(
+    RpcTransactionSignatureLenMismatch,
+    "Raised when the number of signatures does not match the number of signers."
+);
+rpc_exception!(
+    RpcBlockCleanedUp,
+    "Raised when the requested block has been cleaned up."
+);
+rpc_exception!(
+    RpcPreflightFailure,
+    "Raised when transaction simulation fails during preflight. ``data`` holds the ``RpcSimulateTransactionResult``."
+);
+create_exception!(
+    solders,
+    RpcBlockhashNotFound,
+    RpcPreflightFailure,
+    "Raised when preflight fails because the transaction's blockhash was not found."
+);
+rpc_exception!(
+    RpcBlockNotAvailable,
+    "Raised when the requested block is not available."
+);
+rpc_exception!(
+    RpcNodeUnhealthy,
+    "Raised when the node is unhealthy. ``data`` holds the ``NodeUnhealthy`` details."
+);
+rpc_exception!(
+    RpcTransactionPrecompileVerificationFailure,
+    "Raised when transaction precompile verification fails."
+);
+rpc_exception!(
+    RpcSlotSkipped,
+    "Raised when the requested slot was skipped."
+);
+rpc_exception!(
+    RpcLongTermStorageSlotSkipped,
+    "Raised when the requested slot was skipped or is missing in long-term storage."
+);
+rpc_exception!(
+    RpcKeyExcludedFromSecondaryIndex,
+    "Raised when the key is excluded from the node's secondary index."
+);
+rpc_exception!(RpcScanError, "Raised when the node fails to scan accounts.");
+rpc_exception!(
+    RpcBlockStatusNotAvailableYet,
+    "Raised when the status of the requested block is not available yet."
+);
+rpc_exception!(
+    RpcMinContextSlotNotReached,
+    "Raised when the node has not reached the requested minimum context slot. ``data`` holds the ``MinContextSlotNotReached`` details."
+);
+rpc_exception!(
+    RpcUnsupportedTransactionVersion,
+    "Raised when the transaction version is not supported by the request."
+);
+
+/// Build the exception ``E`` for an RPC error object and return it as an ``Err``.
+pub fn raise_rpc_error<E: PyTypeInfo>(
+    py: Python<'_>,
+    message: String,
+    error: PyObject,
+    data: Option<PyObject>,
+) -> PyResult<()> {
+    let err = PyErr::new::<E, _>(message);
+    let value = err.value(py);
+    value.setattr("error", error)?;
+    value.setattr("data", data)?;
+    Err(err)
+}
+
+pub fn add_rpc_exceptions(py: Python<'_>, m: &PyModule) -> PyResult<()> {
+    m.add("RpcError", py.get_type::<RpcError>())?;
+    m.add("RpcParseError", py.get_type::<RpcParseError>())?;
+    m.add("RpcInvalidRequest", py.get_type::<RpcInvalidRequest>())?;
+    m.add("RpcMethodNotFound", py.get_type::<RpcMethodNotFound>())?;
+    m.add("RpcInvalidParams", py.get_type::<RpcInvalidParams>())?;
+    m.add("RpcInternalError", py.get_type::<RpcInternalError>())?;
+    m.add(
+        "RpcTransactionSignatureVerificationFailure",
+        py.get_type::<RpcTransactionSignatureVerificationFailure>(),
+    )?;
+    m.add("RpcNoSnapshot", py.get_type::<RpcNoSnapshot>())?;
+    m.add(
+        "RpcTransactionHistoryNotAvailable",
+        py.get_type::<RpcTransactionHistoryNotAvailable>(),
+    )?;
+    m.add(
+        "RpcTransactionSignatureLenMismatch",
+        py.get_type::<RpcTransactionSignatureLenMismatch>(),
+    )?;
+    m.add("RpcBlockCleanedUp", py.get_type::<RpcBlockCleanedUp>())?;
+    m.add("RpcPreflightFailure", py.get_type::<RpcPreflightFailure>())?;
+    m.add(
+        "RpcBlockhashNotFound",
+        py.get_type::<RpcBlockhashNotFound>(),
+    )?;
+    m.add(
+        "RpcBlockNotAvailable",
+        py.get_type::<RpcBlockNotAvailable>(),
+    )?;
+    m.add("RpcNodeUnhealthy", py.get_type::<RpcNodeUnhealthy>())?;
+    m.add(
+        "RpcTransactionPrecompileVerificationFailure",
+        py.get_type::<RpcTransactionPrecompileVerificationFailure>(),
+    )?;
+    m.add("RpcSlotSkipped", py.get_type::<RpcSlotSkipped>())?;
+    m.add(
+        "RpcLongTermStorageSlotSkipped",
+        py.get_type::<RpcLongTermStorageSlotSkipped>(),
+    )?;
+    m.add(
+        "RpcKeyExcludedFromSecondaryIndex",
+        py.get_type::<RpcKeyExcludedFromSecondaryIndex>(),
+    )?;
+    m.add("RpcScanError", py.get_type::<RpcScanError>())?;
+    m.add(
+        "RpcBlockStatusNotAvailableYet",
+        py.get_type::<RpcBlockStatusNotAvailableYet>(),
+    )?;
+    m.add(
+        "RpcMinContextSlotNotReached",
+        py.get_type::<RpcMinContextSlotNotReached>(),
+    )?;
+    m.add(
+        "RpcUnsupportedTransactionVersion",
+        py.get_type::<RpcUnsupportedTransactionVersion>(),
+    )?;
+    Ok(())
+}
+
 #[macro_export]
 macro_rules! error_message {
-    ($name:ident) => {
+    ($name:ident, $exception:ident) => {
         #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, From, Into)]
         #[pyclass(module = "solders.rpc.errors", subclass)]
         #[serde(rename_all = "camelCase")]
@@ -19,9 +190,23 @@ macro_rules! error_message {
             pub fn new(message: String) -> Self {
                 message.into()
             }
+
+            /// Raise the matching ``RpcError`` subclass.
+            ///
+            /// Raises:
+            ///     RpcError: Always.
+            ///
+            pub fn raise_for_error(&self, py: Python<'_>) -> PyResult<()> {
+                $crate::raise_rpc_error::<$exception>(
+                    py,
+                    self.message.clone(),
+                    self.clone().into_py(py),
+                    None,
+                )
+            }
         }
     };
-    ($name:ident, $data_type:ty) => {
+    ($name:ident, $data_type:ty, $exception:ident) => {
         #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, From, Into)]
         #[pyclass(module = "solders.rpc.errors", subclass)]
         #[serde(rename_all = "camelCase")]
@@ -42,6 +227,20 @@ macro_rules! error_message {
             pub fn new(message: String, data: $data_type) -> Self {
                 (message, data).into()
             }
+
+            /// Raise the matching ``RpcError`` subclass.
+            ///
+            /// Raises:
+            ///     RpcError: Always.
+            ///
+            pub fn raise_for_error(&self, py: Python<'_>) -> PyResult<()> {
+                $crate::raise_rpc_error::<$exception>(
+                    py,
+                    self.message.clone(),
+                    self.clone().into_py(py),
+                    Some(self.data.clone().into_py(py)),
+                )
+            }
         }
     };
 }
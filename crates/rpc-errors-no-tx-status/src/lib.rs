@@ -2,7 +2,15 @@ use derive_more::{From, Into};
 use pyo3::prelude::*;
 use serde::{Deserialize, Serialize};
 use solders_macros::{common_methods, richcmp_eq_only};
-use solders_rpc_errors_common::error_message;
+use solders_rpc_errors_common::{
+    error_message, raise_rpc_error, RpcBlockCleanedUp, RpcBlockNotAvailable,
+    RpcBlockStatusNotAvailableYet, RpcInternalError, RpcInvalidParams, RpcInvalidRequest,
+    RpcKeyExcludedFromSecondaryIndex, RpcLongTermStorageSlotSkipped, RpcMethodNotFound,
+    RpcMinContextSlotNotReached, RpcNoSnapshot, RpcNodeUnhealthy, RpcParseError, RpcScanError,
+    RpcSlotSkipped, RpcTransactionHistoryNotAvailable, RpcTransactionPrecompileVerificationFailure,
+    RpcTransactionSignatureLenMismatch, RpcTransactionSignatureVerificationFailure,
+    RpcUnsupportedTransactionVersion,
+};
 use solders_traits_core::transaction_status_boilerplate;
 use solders_transaction_error::TransactionErrorType;
 type Slot = u64;
@@ -29,7 +37,7 @@ impl BlockCleanedUp {
     }
 }
 
-error_message!(BlockCleanedUpMessage);
+error_message!(BlockCleanedUpMessage, RpcBlockCleanedUp);
 
 #[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone)]
 #[pyclass(module = "solders.transaction_status")]
@@ -40,6 +48,47 @@ pub enum RpcCustomErrorFieldless {
     TransactionSignatureLenMismatch,
 }
 
+#[pymethods]
+impl RpcCustomErrorFieldless {
+    /// Raise the matching ``RpcError`` subclass.
+    ///
+    /// Raises:
+    ///     RpcError: Always.
+    ///
+    pub fn raise_for_error(&self, py: Python<'_>) -> PyResult<()> {
+        let error = self.clone().into_py(py);
+        match self {
+            Self::TransactionSignatureVerificationFailure => {
+                raise_rpc_error::<RpcTransactionSignatureVerificationFailure>(
+                    py,
+                    "Transaction signature verification failure".to_string(),
+                    error,
+                    None,
+                )
+            }
+            Self::NoSnapshot => {
+                raise_rpc_error::<RpcNoSnapshot>(py, "No snapshot".to_string(), error, None)
+            }
+            Self::TransactionHistoryNotAvailable => {
+                raise_rpc_error::<RpcTransactionHistoryNotAvailable>(
+                    py,
+                    "Transaction history is not available from this node".to_string(),
+                    error,
+                    None,
+                )
+            }
+            Self::TransactionSignatureLenMismatch => {
+                raise_rpc_error::<RpcTransactionSignatureLenMismatch>(
+                    py,
+                    "Transaction signature length mismatch".to_string(),
+                    error,
+                    None,
+                )
+            }
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, From, Into)]
 #[pyclass(module = "solders.rpc.errors", subclass)]
 pub struct BlockNotAvailable {
@@ -59,7 +108,7 @@ impl BlockNotAvailable {
     }
 }
 
-error_message!(BlockNotAvailableMessage);
+error_message!(BlockNotAvailableMessage, RpcBlockNotAvailable);
 
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, From, Into)]
 #[pyclass(module = "solders.rpc.errors", subclass)]
@@ -81,7 +130,7 @@ impl NodeUnhealthy {
     }
 }
 
-error_message!(NodeUnhealthyMessage, NodeUnhealthy);
+error_message!(NodeUnhealthyMessage, NodeUnhealthy, RpcNodeUnhealthy);
 
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, From, Into)]
 #[pyclass(module = "solders.rpc.errors", subclass)]
@@ -104,7 +153,10 @@ impl TransactionPrecompileVerificationFailure {
     }
 }
 
-error_message!(TransactionPrecompileVerificationFailureMessage);
+error_message!(
+    TransactionPrecompileVerificationFailureMessage,
+    RpcTransactionPrecompileVerificationFailure
+);
 
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, From, Into)]
 #[pyclass(module = "solders.rpc.errors", subclass)]
@@ -125,7 +177,7 @@ impl SlotSkipped {
     }
 }
 
-error_message!(SlotSkippedMessage);
+error_message!(SlotSkippedMessage, RpcSlotSkipped);
 
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, From, Into)]
 #[pyclass(module = "solders.rpc.errors", subclass)]
@@ -146,7 +198,10 @@ impl LongTermStorageSlotSkipped {
     }
 }
 
-error_message!(LongTermStorageSlotSkippedMessage);
+error_message!(
+    LongTermStorageSlotSkippedMessage,
+    RpcLongTermStorageSlotSkipped
+);
 
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, From, Into)]
 #[pyclass(module = "solders.rpc.errors", subclass)]
@@ -168,7 +223,10 @@ impl KeyExcludedFromSecondaryIndex {
     }
 }
 
-error_message!(KeyExcludedFromSecondaryIndexMessage);
+error_message!(
+    KeyExcludedFromSecondaryIndexMessage,
+    RpcKeyExcludedFromSecondaryIndex
+);
 
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, From, Into)]
 #[pyclass(module = "solders.rpc.errors", subclass)]
@@ -189,7 +247,7 @@ impl ScanError {
     }
 }
 
-error_message!(ScanErrorMessage);
+error_message!(ScanErrorMessage, RpcScanError);
 
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, From, Into)]
 #[pyclass(module = "solders.rpc.errors", subclass)]
@@ -210,7 +268,10 @@ impl BlockStatusNotAvailableYet {
     }
 }
 
-error_message!(BlockStatusNotAvailableYetMessage);
+error_message!(
+    BlockStatusNotAvailableYetMessage,
+    RpcBlockStatusNotAvailableYet
+);
 
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, From, Into)]
 #[pyclass(module = "solders.rpc.errors", subclass)]
@@ -232,7 +293,11 @@ impl MinContextSlotNotReached {
     }
 }
 
-error_message!(MinContextSlotNotReachedMessage, MinContextSlotNotReached);
+error_message!(
+    MinContextSlotNotReachedMessage,
+    MinContextSlotNotReached,
+    RpcMinContextSlotNotReached
+);
 
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, From, Into)]
 #[pyclass(module = "solders.rpc.errors", subclass)]
@@ -255,10 +320,13 @@ impl UnsupportedTransactionVersion {
     }
 }
 
-error_message!(UnsupportedTransactionVersionMessage);
+error_message!(
+    UnsupportedTransactionVersionMessage,
+    RpcUnsupportedTransactionVersion
+);
 
-error_message!(ParseErrorMessage);
-error_message!(InvalidRequestMessage);
-error_message!(MethodNotFoundMessage);
-error_message!(InvalidParamsMessage);
-error_message!(InternalErrorMessage);
+error_message!(ParseErrorMessage, RpcParseError);
+error_message!(InvalidRequestMessage, RpcInvalidRequest);
+error_message!(MethodNotFoundMessage, RpcMethodNotFound);
+error_message!(InvalidParamsMessage, RpcInvalidParams);
+error_message!(InternalErrorMessage, RpcInternalError);
@@ -1,10 +1,13 @@
 use derive_more::{From, Into};
 use pyo3::prelude::*;
 use serde::{Deserialize, Serialize};
+use solana_sdk::transaction::TransactionError as TransactionErrorOriginal;
+use solders_custom_error::{default_resolver, CustomErrorResolver};
 use solders_macros::{common_methods, richcmp_eq_only};
 use solders_rpc_common::RpcSimulateTransactionResult;
-use solders_rpc_errors_common::error_message;
+use solders_rpc_errors_common::{raise_rpc_error, RpcBlockhashNotFound, RpcPreflightFailure};
 use solders_traits_core::transaction_status_boilerplate;
+use solders_transaction_error::{TransactionErrorFieldless, TransactionErrorType};
 
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, From, Into)]
 #[pyclass(module = "solders.rpc.errors", subclass)]
@@ -25,9 +28,75 @@ impl SendTransactionPreflightFailure {
     pub fn new(message: String, result: RpcSimulateTransactionResult) -> Self {
         (message, result).into()
     }
+
+    /// Describe the failure, naming any custom program error.
+    ///
+    /// The custom error is resolved against the program that returned it, as found
+    /// in the simulation logs.
+    ///
+    /// Args:
+    ///     resolver (Optional[CustomErrorResolver]): The resolver to use. Defaults to the
+    ///         default resolver.
+    ///
+    /// Returns:
+    ///     str: The description, or ``message`` if the simulation result has no error.
+    ///
+    #[pyo3(signature = (resolver=None))]
+    pub fn describe(&self, resolver: Option<CustomErrorResolver>) -> String {
+        match self.result.err() {
+            Some(err) => resolver
+                .unwrap_or_else(default_resolver)
+                .describe_transaction_error(&err.into(), &self.result.logs().unwrap_or_default()),
+            None => self.message.clone(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, From, Into)]
+#[pyclass(module = "solders.rpc.errors", subclass)]
+#[serde(rename_all = "camelCase")]
+pub struct SendTransactionPreflightFailureMessage {
+    #[pyo3(get)]
+    message: String,
+    #[pyo3(get)]
+    data: RpcSimulateTransactionResult,
 }
 
-error_message!(
-    SendTransactionPreflightFailureMessage,
-    RpcSimulateTransactionResult
-);
+transaction_status_boilerplate!(SendTransactionPreflightFailureMessage);
+
+#[richcmp_eq_only]
+#[common_methods]
+#[pymethods]
+impl SendTransactionPreflightFailureMessage {
+    #[new]
+    pub fn new(message: String, data: RpcSimulateTransactionResult) -> Self {
+        (message, data).into()
+    }
+
+    /// Raise the error.
+    ///
+    /// Custom program errors in the message are named with the default resolver,
+    /// using the simulation logs to find the program that returned them.
+    ///
+    /// Raises:
+    ///     RpcBlockhashNotFound: If the simulation failed because the blockhash was not found.
+    ///     RpcPreflightFailure: Otherwise.
+    ///
+    pub fn raise_for_error(&self, py: Python<'_>) -> PyResult<()> {
+        let error = self.clone().into_py(py);
+        let data = Some(self.data.clone().into_py(py));
+        match self.data.err() {
+            Some(TransactionErrorType::Fieldless(TransactionErrorFieldless::BlockhashNotFound)) => {
+                raise_rpc_error::<RpcBlockhashNotFound>(py, self.message.clone(), error, data)
+            }
+            Some(err) => {
+                let err = TransactionErrorOriginal::from(err);
+                let described = default_resolver()
+                    .describe_transaction_error(&err, &self.data.logs().unwrap_or_default());
+                let message = self.message.replace(&err.to_string(), &described);
+                raise_rpc_error::<RpcPreflightFailure>(py, message, error, data)
+            }
+            None => raise_rpc_error::<RpcPreflightFailure>(py, self.message.clone(), error, data),
+        }
+    }
+}
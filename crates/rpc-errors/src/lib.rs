@@ -1,6 +1,7 @@
 use pyo3::{prelude::*, types::PyTuple, PyTypeInfo};
 use serde::{Deserialize, Serialize};
 use solders_macros::EnumIntoPy;
+use solders_rpc_errors_common::add_rpc_exceptions;
 use solders_rpc_errors_no_tx_status::{
     BlockCleanedUp, BlockCleanedUpMessage, BlockNotAvailable, BlockNotAvailableMessage,
     BlockStatusNotAvailableYet, BlockStatusNotAvailableYetMessage, InternalErrorMessage,
@@ -90,5 +91,6 @@ pub fn create_errors_mod(py: Python<'_>) -> PyResult<&PyModule> {
         "RpcCustomError",
         union.get_item(PyTuple::new(py, union_members))?,
     )?;
+    add_rpc_exceptions(py, m)?;
     Ok(m)
 }
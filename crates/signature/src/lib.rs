@@ -1,10 +1,11 @@
 use std::str::FromStr;
 
+use borsh::{BorshDeserialize, BorshSerialize};
 use derive_more::{From, Into};
 use pyo3::prelude::*;
 use serde::{Deserialize, Serialize};
 use solana_sdk::signature::{ParseSignatureError, Signature as SignatureOriginal, SIGNATURE_BYTES};
-use solders_macros::{common_methods, pyhash, richcmp_full};
+use solders_macros::{borsh_methods, common_methods, pyhash, richcmp_full};
 use solders_pubkey::Pubkey;
 
 use solders_traits_core::{
@@ -39,6 +40,7 @@ pub struct Signature(pub SignatureOriginal);
 #[pyhash]
 #[richcmp_full]
 #[common_methods]
+#[borsh_methods]
 #[pymethods]
 impl Signature {
     #[classattr]
@@ -153,6 +155,19 @@ impl PyFromBytesGeneral for Signature {
     }
 }
 solders_traits_core::common_methods_default!(Signature);
+solders_traits_core::borsh_methods_default!(Signature);
+
+impl BorshSerialize for Signature {
+    fn serialize<W: borsh::io::Write>(&self, writer: &mut W) -> borsh::io::Result<()> {
+        writer.write_all(self.0.as_ref())
+    }
+}
+
+impl BorshDeserialize for Signature {
+    fn deserialize_reader<R: borsh::io::Read>(reader: &mut R) -> borsh::io::Result<Self> {
+        <[u8; SIGNATURE_BYTES]>::deserialize_reader(reader).map(Self::new)
+    }
+}
 impl RichcmpFull for Signature {}
 pybytes_general_via_slice!(Signature);
 impl_display!(Signature);
@@ -1,15 +1,26 @@
+use derive_more::{From, Into};
 use dict_derive::{FromPyObject, IntoPyObject};
-use pyo3::{exceptions::PyValueError, prelude::*};
-use solana_program::address_lookup_table::instruction::{
-    close_lookup_table as close_lookup_table_original,
-    create_lookup_table as create_lookup_table_original,
-    create_lookup_table_signed as create_lookup_table_signed_original,
-    deactivate_lookup_table as deactivate_lookup_table_original,
-    extend_lookup_table as extend_lookup_table_original,
-    freeze_lookup_table as freeze_lookup_table_original,
+use pyo3::{exceptions::PyValueError, prelude::*, types::PyBytes};
+use solana_program::address_lookup_table::{
+    self,
+    instruction::{
+        close_lookup_table as close_lookup_table_original,
+        create_lookup_table as create_lookup_table_original,
+        create_lookup_table_signed as create_lookup_table_signed_original,
+        deactivate_lookup_table as deactivate_lookup_table_original,
+        extend_lookup_table as extend_lookup_table_original,
+        freeze_lookup_table as freeze_lookup_table_original,
+        ProgramInstruction as AddressLookupTableInstructionOriginal,
+    },
 };
 use solana_sdk::{
-    instruction::Instruction as InstructionOriginal,
+    instruction::{
+        CompiledInstruction as CompiledInstructionOriginal, Instruction as InstructionOriginal,
+    },
+    nonce::state::{
+        Data as NonceDataOriginal, DurableNonce, State as NonceStateOriginal,
+        Versions as NonceVersionsOriginal,
+    },
     pubkey::Pubkey as PubkeyOriginal,
     system_instruction::{
         advance_nonce_account as advance_nonce_account_original, allocate as allocate_original,
@@ -22,15 +33,21 @@ use solana_sdk::{
         create_nonce_account_with_seed as create_nonce_account_with_seed_original,
         transfer as transfer_original, transfer_many as transfer_many_original,
         transfer_with_seed as transfer_with_seed_original,
+        upgrade_nonce_account as upgrade_nonce_account_original,
         withdraw_nonce_account as withdraw_nonce_account_original,
         SystemInstruction as SystemInstructionOriginal,
     },
     system_program,
 };
 
-use solders_instruction::Instruction;
+use solders_hash::Hash as SolderHash;
+use solders_instruction::{CompiledInstruction, Instruction};
+use solders_macros::{common_methods_core, richcmp_eq_only};
 use solders_pubkey::Pubkey;
-use solders_traits::handle_py_err;
+use solders_traits::{handle_py_err, InstructionDecodeError};
+use solders_traits_core::{
+    to_py_value_err, CommonMethodsCore, PyBytesGeneral, PyFromBytesGeneral, RichcmpEqualityOnly,
+};
 
 fn convert_instructions_from_original(ixs: Vec<InstructionOriginal>) -> Vec<Instruction> {
     ixs.into_iter().map(Instruction::from).collect()
@@ -39,6 +56,8 @@ fn convert_instructions_from_original(ixs: Vec<InstructionOriginal>) -> Vec<Inst
 pub fn create_system_program_mod(py: Python<'_>) -> PyResult<&PyModule> {
     let system_program_mod = PyModule::new(py, "_system_program")?;
     system_program_mod.add("ID", Pubkey(system_program::ID))?;
+    system_program_mod.add("NONCE_STATE_SIZE", NonceStateOriginal::size())?;
+    system_program_mod.add_class::<NonceData>()?;
     let funcs = [
         wrap_pyfunction!(create_account, system_program_mod)?,
         wrap_pyfunction!(decode_create_account, system_program_mod)?,
@@ -67,6 +86,9 @@ pub fn create_system_program_mod(py: Python<'_>) -> PyResult<&PyModule> {
         wrap_pyfunction!(decode_withdraw_nonce_account, system_program_mod)?,
         wrap_pyfunction!(authorize_nonce_account, system_program_mod)?,
         wrap_pyfunction!(decode_authorize_nonce_account, system_program_mod)?,
+        wrap_pyfunction!(upgrade_nonce_account, system_program_mod)?,
+        wrap_pyfunction!(decode_upgrade_nonce_account, system_program_mod)?,
+        wrap_pyfunction!(decode_instruction, system_program_mod)?,
         // address_lookup_table_program
         wrap_pyfunction!(close_lookup_table, system_program_mod)?,
         wrap_pyfunction!(create_lookup_table, system_program_mod)?,
@@ -74,6 +96,7 @@ pub fn create_system_program_mod(py: Python<'_>) -> PyResult<&PyModule> {
         wrap_pyfunction!(deactivate_lookup_table, system_program_mod)?,
         wrap_pyfunction!(extend_lookup_table, system_program_mod)?,
         wrap_pyfunction!(freeze_lookup_table, system_program_mod)?,
+        wrap_pyfunction!(decode_lookup_table_instruction, system_program_mod)?,
     ];
     for func in funcs {
         system_program_mod.add_function(func)?;
@@ -650,6 +673,261 @@ pub fn decode_authorize_nonce_account(
     }
 }
 
+#[derive(FromPyObject, IntoPyObject)]
+pub struct UpgradeNonceAccountParams {
+    nonce_pubkey: Pubkey,
+}
+
+#[pyfunction]
+pub fn upgrade_nonce_account(params: UpgradeNonceAccountParams) -> Instruction {
+    upgrade_nonce_account_original(params.nonce_pubkey.into()).into()
+}
+
+#[pyfunction]
+pub fn decode_upgrade_nonce_account(
+    instruction: Instruction,
+) -> PyResult<UpgradeNonceAccountParams> {
+    let nonce_pubkey = instruction.0.accounts[0].pubkey;
+    let parsed_data = handle_py_err(bincode::deserialize::<SystemInstructionOriginal>(
+        instruction.0.data.as_slice(),
+    ))?;
+    match parsed_data {
+        SystemInstructionOriginal::UpgradeNonceAccount => Ok(UpgradeNonceAccountParams {
+            nonce_pubkey: nonce_pubkey.into(),
+        }),
+        _ => Err(PyValueError::new_err(
+            "Not an UpgradeNonceAccount instruction",
+        )),
+    }
+}
+
+/// The params of any system instruction, tagged with the instruction name.
+pub enum SystemInstructionParams {
+    CreateAccount(CreateAccountParams),
+    Assign(AssignParams),
+    Transfer(TransferParams),
+    CreateAccountWithSeed(CreateAccountWithSeedParams),
+    AdvanceNonceAccount(AdvanceNonceAccountParams),
+    WithdrawNonceAccount(WithdrawNonceAccountParams),
+    InitializeNonceAccount(InitializeNonceAccountParams),
+    AuthorizeNonceAccount(AuthorizeNonceAccountParams),
+    Allocate(AllocateParams),
+    AllocateWithSeed(AllocateWithSeedParams),
+    AssignWithSeed(AssignWithSeedParams),
+    TransferWithSeed(TransferWithSeedParams),
+    UpgradeNonceAccount(UpgradeNonceAccountParams),
+}
+
+impl IntoPy<PyObject> for SystemInstructionParams {
+    fn into_py(self, py: Python<'_>) -> PyObject {
+        match self {
+            Self::CreateAccount(p) => ("CreateAccount", p).into_py(py),
+            Self::Assign(p) => ("Assign", p).into_py(py),
+            Self::Transfer(p) => ("Transfer", p).into_py(py),
+            Self::CreateAccountWithSeed(p) => ("CreateAccountWithSeed", p).into_py(py),
+            Self::AdvanceNonceAccount(p) => ("AdvanceNonceAccount", p).into_py(py),
+            Self::WithdrawNonceAccount(p) => ("WithdrawNonceAccount", p).into_py(py),
+            Self::InitializeNonceAccount(p) => ("InitializeNonceAccount", p).into_py(py),
+            Self::AuthorizeNonceAccount(p) => ("AuthorizeNonceAccount", p).into_py(py),
+            Self::Allocate(p) => ("Allocate", p).into_py(py),
+            Self::AllocateWithSeed(p) => ("AllocateWithSeed", p).into_py(py),
+            Self::AssignWithSeed(p) => ("AssignWithSeed", p).into_py(py),
+            Self::TransferWithSeed(p) => ("TransferWithSeed", p).into_py(py),
+            Self::UpgradeNonceAccount(p) => ("UpgradeNonceAccount", p).into_py(py),
+        }
+    }
+}
+
+#[derive(pyo3::FromPyObject)]
+pub enum InstructionToDecode {
+    Instruction(Instruction),
+    Compiled(CompiledInstruction),
+}
+
+fn decode_err(msg: String) -> PyErr {
+    InstructionDecodeError::new_err(msg)
+}
+
+/// Decode system instruction data given the instruction's account keys.
+pub fn decode_system_instruction_data(
+    data: &[u8],
+    accounts: &[PubkeyOriginal],
+) -> PyResult<SystemInstructionParams> {
+    let parsed = bincode::deserialize::<SystemInstructionOriginal>(data)
+        .map_err(|e| decode_err(format!("Invalid system instruction data: {e}")))?;
+    let key = |idx: usize| -> PyResult<Pubkey> {
+        accounts.get(idx).map(|k| Pubkey(*k)).ok_or_else(|| {
+            decode_err(format!(
+                "System instruction is missing account at index {idx}"
+            ))
+        })
+    };
+    Ok(match parsed {
+        SystemInstructionOriginal::CreateAccount {
+            lamports,
+            space,
+            owner,
+        } => SystemInstructionParams::CreateAccount(CreateAccountParams {
+            from_pubkey: key(0)?,
+            to_pubkey: key(1)?,
+            lamports,
+            space,
+            owner: owner.into(),
+        }),
+        SystemInstructionOriginal::Assign { owner } => {
+            SystemInstructionParams::Assign(AssignParams {
+                pubkey: key(0)?,
+                owner: owner.into(),
+            })
+        }
+        SystemInstructionOriginal::Transfer { lamports } => {
+            SystemInstructionParams::Transfer(TransferParams {
+                from_pubkey: key(0)?,
+                to_pubkey: key(1)?,
+                lamports,
+            })
+        }
+        SystemInstructionOriginal::CreateAccountWithSeed {
+            base,
+            seed,
+            lamports,
+            space,
+            owner,
+        } => SystemInstructionParams::CreateAccountWithSeed(CreateAccountWithSeedParams {
+            from_pubkey: key(0)?,
+            to_pubkey: key(1)?,
+            base: base.into(),
+            seed,
+            lamports,
+            space,
+            owner: owner.into(),
+        }),
+        SystemInstructionOriginal::AdvanceNonceAccount => {
+            SystemInstructionParams::AdvanceNonceAccount(AdvanceNonceAccountParams {
+                nonce_pubkey: key(0)?,
+                authorized_pubkey: key(2)?,
+            })
+        }
+        SystemInstructionOriginal::WithdrawNonceAccount(lamports) => {
+            SystemInstructionParams::WithdrawNonceAccount(WithdrawNonceAccountParams {
+                nonce_pubkey: key(0)?,
+                to_pubkey: key(1)?,
+                authorized_pubkey: key(4)?,
+                lamports,
+            })
+        }
+        SystemInstructionOriginal::InitializeNonceAccount(authority) => {
+            SystemInstructionParams::InitializeNonceAccount(InitializeNonceAccountParams {
+                nonce_pubkey: key(0)?,
+                authority: authority.into(),
+            })
+        }
+        SystemInstructionOriginal::AuthorizeNonceAccount(new_authority) => {
+            SystemInstructionParams::AuthorizeNonceAccount(AuthorizeNonceAccountParams {
+                nonce_pubkey: key(0)?,
+                authorized_pubkey: key(1)?,
+                new_authority: new_authority.into(),
+            })
+        }
+        SystemInstructionOriginal::Allocate { space } => {
+            SystemInstructionParams::Allocate(AllocateParams {
+                pubkey: key(0)?,
+                space,
+            })
+        }
+        SystemInstructionOriginal::AllocateWithSeed {
+            base,
+            seed,
+            space,
+            owner,
+        } => SystemInstructionParams::AllocateWithSeed(AllocateWithSeedParams {
+            address: key(0)?,
+            base: base.into(),
+            seed,
+            space,
+            owner: owner.into(),
+        }),
+        SystemInstructionOriginal::AssignWithSeed { base, seed, owner } => {
+            SystemInstructionParams::AssignWithSeed(AssignWithSeedParams {
+                address: key(0)?,
+                base: base.into(),
+                seed,
+                owner: owner.into(),
+            })
+        }
+        SystemInstructionOriginal::TransferWithSeed {
+            lamports,
+            from_seed,
+            from_owner,
+        } => SystemInstructionParams::TransferWithSeed(TransferWithSeedParams {
+            from_pubkey: key(0)?,
+            from_base: key(1)?,
+            from_seed,
+            from_owner: from_owner.into(),
+            to_pubkey: key(2)?,
+            lamports,
+        }),
+        SystemInstructionOriginal::UpgradeNonceAccount => {
+            SystemInstructionParams::UpgradeNonceAccount(UpgradeNonceAccountParams {
+                nonce_pubkey: key(0)?,
+            })
+        }
+    })
+}
+
+/// Decode any system instruction.
+///
+/// Args:
+///     instruction (Instruction | CompiledInstruction): The instruction to decode.
+///     keys (Optional[Sequence[Pubkey]]): The account keys of the message the instruction belongs to.
+///         Required when ``instruction`` is a ``CompiledInstruction``.
+///
+/// Returns:
+///     Tuple[str, dict]: The instruction name, e.g. ``"Transfer"``, and its params.
+///
+/// Raises:
+///     InstructionDecodeError: if the instruction is not a valid system instruction.
+///
+#[pyfunction]
+#[pyo3(signature = (instruction, keys=None))]
+pub fn decode_instruction(
+    instruction: InstructionToDecode,
+    keys: Option<Vec<Pubkey>>,
+) -> PyResult<SystemInstructionParams> {
+    let (program_id, data, accounts): (PubkeyOriginal, Vec<u8>, Vec<PubkeyOriginal>) =
+        match instruction {
+            InstructionToDecode::Instruction(ix) => (
+                ix.0.program_id,
+                ix.0.data,
+                ix.0.accounts.into_iter().map(|a| a.pubkey).collect(),
+            ),
+            InstructionToDecode::Compiled(ix) => {
+                let ix = CompiledInstructionOriginal::from(ix);
+                let keys = keys.ok_or_else(|| {
+                    decode_err("keys must be provided to decode a CompiledInstruction".into())
+                })?;
+                let resolve = |idx: u8| -> PyResult<PubkeyOriginal> {
+                    keys.get(usize::from(idx)).map(|k| k.0).ok_or_else(|| {
+                        decode_err(format!("Account index {idx} is out of range of keys"))
+                    })
+                };
+                let program_id = resolve(ix.program_id_index)?;
+                let accounts = ix
+                    .accounts
+                    .iter()
+                    .map(|idx| resolve(*idx))
+                    .collect::<PyResult<Vec<_>>>()?;
+                (program_id, ix.data, accounts)
+            }
+        };
+    if program_id != system_program::ID {
+        return Err(decode_err(format!(
+            "Program {program_id} is not the system program"
+        )));
+    }
+    decode_system_instruction_data(&data, &accounts)
+}
+
 #[derive(FromPyObject, IntoPyObject)]
 pub struct CloseLookupTableParams {
     lookup_table_address: Pubkey,
@@ -753,3 +1031,254 @@ pub fn freeze_lookup_table(params: FreezeLookupTableParams) -> Instruction {
     )
     .into()
 }
+
+/// The state of an initialized durable nonce account.
+///
+/// ``bytes(nonce_data)`` gives the data of the nonce account,
+/// and :meth:`NonceData.from_bytes` parses it.
+///
+/// Args:
+///     authority (Pubkey): Address of the account that signs transactions using the nonce account.
+///     durable_nonce (Hash): The stored nonce value, i.e. the ``recent_blockhash``
+///         that transactions using this nonce account must use.
+///     lamports_per_signature (int): The fee per signature associated with the nonce.
+///
+/// Example:
+///     >>> from solders.hash import Hash
+///     >>> from solders.pubkey import Pubkey
+///     >>> from solders.system_program import NonceData
+///     >>> nonce = Hash.new_unique()
+///     >>> data = NonceData(Pubkey.new_unique(), nonce, 5000)
+///     >>> parsed = NonceData.from_bytes(bytes(data))
+///     >>> assert parsed.durable_nonce == nonce
+///     >>> assert parsed.verify_recent_blockhash(nonce)
+///
+#[pyclass(module = "solders.system_program", subclass)]
+#[derive(Clone, Debug, PartialEq, Eq, From, Into)]
+pub struct NonceData(pub NonceVersionsOriginal);
+
+impl NonceData {
+    fn data(&self) -> PyResult<&NonceDataOriginal> {
+        match self.0.state() {
+            NonceStateOriginal::Initialized(data) => Ok(data),
+            NonceStateOriginal::Uninitialized => {
+                Err(PyValueError::new_err("Nonce account is uninitialized"))
+            }
+        }
+    }
+}
+
+#[richcmp_eq_only]
+#[common_methods_core]
+#[pymethods]
+impl NonceData {
+    #[new]
+    pub fn new(
+        authority: Pubkey,
+        durable_nonce: SolderHash,
+        lamports_per_signature: u64,
+    ) -> PyResult<Self> {
+        // DurableNonce::from_blockhash hashes its input, so a stored nonce can only be
+        // wrapped through DurableNonce's serde impl, which encodes it as the bare hash.
+        let durable_nonce: DurableNonce =
+            bincode::deserialize(durable_nonce.as_ref()).map_err(|e| to_py_value_err(&e))?;
+        Ok(
+            NonceVersionsOriginal::new(NonceStateOriginal::Initialized(NonceDataOriginal::new(
+                authority.into(),
+                durable_nonce,
+                lamports_per_signature,
+            )))
+            .into(),
+        )
+    }
+
+    /// Pubkey: Address of the account that signs transactions using the nonce account.
+    #[getter]
+    pub fn authority(&self) -> PyResult<Pubkey> {
+        Ok(self.data()?.authority.into())
+    }
+
+    /// Hash: The stored nonce value, used as the ``recent_blockhash`` of durable-nonce transactions.
+    #[getter]
+    pub fn durable_nonce(&self) -> PyResult<SolderHash> {
+        Ok(self.data()?.blockhash().into())
+    }
+
+    /// int: The fee per signature associated with the nonce.
+    #[getter]
+    pub fn lamports_per_signature(&self) -> PyResult<u64> {
+        Ok(self.data()?.get_lamports_per_signature())
+    }
+
+    /// bool: Whether this is a legacy nonce, which must be upgraded with
+    /// ``UpgradeNonceAccount`` before it can be used.
+    #[getter]
+    pub fn is_legacy(&self) -> bool {
+        matches!(self.0, NonceVersionsOriginal::Legacy(_))
+    }
+
+    /// Check that a transaction's ``recent_blockhash`` matches the stored nonce.
+    ///
+    /// This is the check the runtime performs on durable-nonce transactions,
+    /// so it always fails for legacy nonces.
+    ///
+    /// Args:
+    ///     recent_blockhash (Hash): The blockhash of the transaction's message.
+    ///
+    /// Returns:
+    ///     bool: ``True`` if the transaction can use this nonce.
+    ///
+    pub fn verify_recent_blockhash(&self, recent_blockhash: SolderHash) -> bool {
+        self.0
+            .verify_recent_blockhash(recent_blockhash.as_ref())
+            .is_some()
+    }
+
+    /// Deserialize the data of a nonce account.
+    ///
+    /// Args:
+    ///     data (bytes): The account data.
+    ///
+    /// Returns:
+    ///     NonceData: The parsed nonce state.
+    ///
+    /// Raises:
+    ///     ValueError: if the data is malformed or the nonce account is uninitialized.
+    ///
+    #[staticmethod]
+    pub fn from_bytes(data: &[u8]) -> PyResult<Self> {
+        Self::py_from_bytes(data)
+    }
+}
+
+impl std::fmt::Display for NonceData {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl PyBytesGeneral for NonceData {
+    fn pybytes_general<'a>(&self, py: Python<'a>) -> &'a PyBytes {
+        PyBytes::new(py, &bincode::serialize(&self.0).unwrap())
+    }
+}
+
+impl PyFromBytesGeneral for NonceData {
+    fn py_from_bytes_general(raw: &[u8]) -> PyResult<Self> {
+        let versions =
+            bincode::deserialize::<NonceVersionsOriginal>(raw).map_err(|e| to_py_value_err(&e))?;
+        match versions.state() {
+            NonceStateOriginal::Initialized(_) => Ok(versions.into()),
+            NonceStateOriginal::Uninitialized => {
+                Err(PyValueError::new_err("Nonce account is uninitialized"))
+            }
+        }
+    }
+}
+
+impl CommonMethodsCore for NonceData {}
+impl RichcmpEqualityOnly for NonceData {}
+
+/// The params of an address lookup table instruction, tagged with the instruction name.
+pub enum AddressLookupTableInstructionParams {
+    CreateLookupTable(CreateLookupTableParams),
+    FreezeLookupTable(FreezeLookupTableParams),
+    ExtendLookupTable(ExtendLookupTableParams),
+    DeactivateLookupTable(DeactivateLookupTableParams),
+    CloseLookupTable(CloseLookupTableParams),
+}
+
+impl IntoPy<PyObject> for AddressLookupTableInstructionParams {
+    fn into_py(self, py: Python<'_>) -> PyObject {
+        match self {
+            Self::CreateLookupTable(p) => ("CreateLookupTable", p).into_py(py),
+            Self::FreezeLookupTable(p) => ("FreezeLookupTable", p).into_py(py),
+            Self::ExtendLookupTable(p) => ("ExtendLookupTable", p).into_py(py),
+            Self::DeactivateLookupTable(p) => ("DeactivateLookupTable", p).into_py(py),
+            Self::CloseLookupTable(p) => ("CloseLookupTable", p).into_py(py),
+        }
+    }
+}
+
+/// Decode address lookup table instruction data given the instruction's account keys.
+pub fn decode_lookup_table_instruction_data(
+    data: &[u8],
+    accounts: &[PubkeyOriginal],
+) -> PyResult<AddressLookupTableInstructionParams> {
+    let parsed =
+        bincode::deserialize::<AddressLookupTableInstructionOriginal>(data).map_err(|e| {
+            decode_err(format!(
+                "Invalid address lookup table instruction data: {e}"
+            ))
+        })?;
+    let key = |idx: usize| -> PyResult<Pubkey> {
+        accounts.get(idx).map(|k| Pubkey(*k)).ok_or_else(|| {
+            decode_err(format!(
+                "Address lookup table instruction is missing account at index {idx}"
+            ))
+        })
+    };
+    Ok(match parsed {
+        AddressLookupTableInstructionOriginal::CreateLookupTable { recent_slot, .. } => {
+            AddressLookupTableInstructionParams::CreateLookupTable(CreateLookupTableParams {
+                authority_address: key(1)?,
+                payer_address: key(2)?,
+                recent_slot,
+            })
+        }
+        AddressLookupTableInstructionOriginal::FreezeLookupTable => {
+            AddressLookupTableInstructionParams::FreezeLookupTable(FreezeLookupTableParams {
+                lookup_table_address: key(0)?,
+                authority_address: key(1)?,
+            })
+        }
+        AddressLookupTableInstructionOriginal::ExtendLookupTable { new_addresses } => {
+            AddressLookupTableInstructionParams::ExtendLookupTable(ExtendLookupTableParams {
+                lookup_table_address: key(0)?,
+                authority_address: key(1)?,
+                payer_address: accounts.get(2).map(|k| Pubkey(*k)),
+                new_addresses: new_addresses.into_iter().map(Pubkey).collect(),
+            })
+        }
+        AddressLookupTableInstructionOriginal::DeactivateLookupTable => {
+            AddressLookupTableInstructionParams::DeactivateLookupTable(
+                DeactivateLookupTableParams {
+                    lookup_table_address: key(0)?,
+                    authority_address: key(1)?,
+                },
+            )
+        }
+        AddressLookupTableInstructionOriginal::CloseLookupTable => {
+            AddressLookupTableInstructionParams::CloseLookupTable(CloseLookupTableParams {
+                lookup_table_address: key(0)?,
+                authority_address: key(1)?,
+                recipient_address: key(2)?,
+            })
+        }
+    })
+}
+
+/// Decode any address lookup table instruction.
+///
+/// Args:
+///     instruction (Instruction): The instruction to decode.
+///
+/// Returns:
+///     Tuple[str, dict]: The instruction name, e.g. ``"ExtendLookupTable"``, and its params.
+///
+/// Raises:
+///     InstructionDecodeError: if the instruction is not a valid address lookup table instruction.
+///
+#[pyfunction]
+pub fn decode_lookup_table_instruction(
+    instruction: Instruction,
+) -> PyResult<AddressLookupTableInstructionParams> {
+    if instruction.0.program_id != address_lookup_table::program::ID {
+        return Err(decode_err(format!(
+            "Program {} is not the address lookup table program",
+            instruction.0.program_id
+        )));
+    }
+    let accounts: Vec<PubkeyOriginal> = instruction.0.accounts.iter().map(|a| a.pubkey).collect();
+    decode_lookup_table_instruction_data(&instruction.0.data, &accounts)
+}
@@ -1,3 +1,4 @@
+use borsh::{BorshDeserialize, BorshSerialize};
 use pyo3::{
     exceptions::{PyTypeError, PyValueError},
     prelude::*,
@@ -215,6 +216,27 @@ pub trait PyFromBytesCbor<'b>: Deserialize<'b> {
     }
 }
 
+pub trait PyBytesBorsh: BorshSerialize {
+    fn pybytes_borsh<'a>(&self, py: Python<'a>) -> &'a PyBytes {
+        PyBytes::new(py, &borsh::to_vec(self).unwrap())
+    }
+}
+
+pub trait PyFromBytesBorsh: BorshDeserialize {
+    fn py_from_bytes_borsh(raw: &[u8]) -> PyResult<Self> {
+        let deser = borsh::from_slice::<Self>(raw);
+        handle_py_value_err(deser)
+    }
+}
+
+#[macro_export]
+macro_rules! borsh_methods_default {
+    ($ty:ty) => {
+        impl $crate::PyBytesBorsh for $ty {}
+        impl $crate::PyFromBytesBorsh for $ty {}
+    };
+}
+
 pub trait PyFromBytesGeneral: Sized {
     fn py_from_bytes_general(raw: &[u8]) -> PyResult<Self>;
 }
@@ -124,6 +124,13 @@ create_exception!(
     "Raised when the Rust cbor library returns an error during (de)serialization."
 );
 
+create_exception!(
+    solders,
+    InstructionDecodeError,
+    PyException,
+    "Raised when instruction data cannot be decoded."
+);
+
 create_exception!(
     solders,
     PubkeyError,
@@ -5,7 +5,9 @@ use solana_sdk::{
     instruction::InstructionError as InstructionErrorOriginal,
     transaction::TransactionError as TransactionErrorOriginal,
 };
+use solders_custom_error::{default_resolver, CustomErrorResolver, ResolvedError};
 use solders_macros::{common_methods, richcmp_eq_only, EnumIntoPy};
+use solders_pubkey::Pubkey;
 use solders_traits_core::transaction_status_boilerplate;
 
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, From, Into)]
@@ -406,6 +408,55 @@ impl TransactionErrorInstructionError {
     pub fn err(&self) -> InstructionErrorType {
         self.0 .1.clone()
     }
+
+    /// Resolve a custom program error to a named error.
+    ///
+    /// Args:
+    ///     program_id (Pubkey): The program invoked by the failed instruction.
+    ///     resolver (Optional[CustomErrorResolver]): The resolver to use. Defaults to the
+    ///         default resolver.
+    ///
+    /// Returns:
+    ///     Optional[ResolvedError]: The named error, or ``None`` if this is not a custom
+    ///     error or the code is unknown.
+    ///
+    #[pyo3(signature = (program_id, resolver=None))]
+    pub fn resolve(
+        &self,
+        program_id: Pubkey,
+        resolver: Option<CustomErrorResolver>,
+    ) -> Option<ResolvedError> {
+        match &self.0 .1 {
+            InstructionErrorType::Tagged(InstructionErrorTagged::Custom(code)) => resolver
+                .unwrap_or_else(default_resolver)
+                .resolve(program_id, code.0),
+            _ => None,
+        }
+    }
+
+    /// Describe the error, naming the custom error if it can be resolved.
+    ///
+    /// Args:
+    ///     program_id (Pubkey): The program invoked by the failed instruction.
+    ///     resolver (Optional[CustomErrorResolver]): The resolver to use. Defaults to the
+    ///         default resolver.
+    ///
+    /// Returns:
+    ///     str: The description, e.g.
+    ///     ``"Error processing Instruction 0: TokenError::InsufficientFunds: Insufficient funds"``.
+    ///
+    #[pyo3(signature = (program_id, resolver=None))]
+    pub fn describe(&self, program_id: Pubkey, resolver: Option<CustomErrorResolver>) -> String {
+        let index = self.0 .0;
+        match self.resolve(program_id, resolver) {
+            Some(resolved) => format!(
+                "Error processing Instruction {index}: {}",
+                resolved.describe()
+            ),
+            None => TransactionErrorOriginal::InstructionError(index, self.0 .1.clone().into())
+                .to_string(),
+        }
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, From, Into)]
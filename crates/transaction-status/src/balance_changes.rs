@@ -0,0 +1,385 @@
+use std::str::FromStr;
+
+use pyo3::{exceptions::PyValueError, prelude::*};
+use serde::{Deserialize, Serialize};
+use solana_transaction_status::{
+    UiLoadedAddresses as UiLoadedAddressesOriginal,
+    UiTransactionStatusMeta as UiTransactionStatusMetaOriginal,
+    UiTransactionTokenBalance as UiTransactionTokenBalanceOriginal,
+};
+use solders_macros::{common_methods, richcmp_eq_only};
+use solders_pubkey::Pubkey;
+use solders_traits_core::transaction_status_boilerplate;
+
+/// The lamport balance change of a single account.
+///
+/// Args:
+///     pubkey (Pubkey): The account address.
+///     pre_balance (int): The balance before the transaction.
+///     post_balance (int): The balance after the transaction.
+///     fee (int): The transaction fee charged to this account. Non-zero only for the fee payer.
+///
+#[pyclass(module = "solders.transaction_status", subclass)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AccountBalanceChange {
+    pubkey: Pubkey,
+    pre_balance: u64,
+    post_balance: u64,
+    fee: u64,
+}
+
+transaction_status_boilerplate!(AccountBalanceChange);
+
+#[richcmp_eq_only]
+#[common_methods]
+#[pymethods]
+impl AccountBalanceChange {
+    #[new]
+    pub fn new(pubkey: Pubkey, pre_balance: u64, post_balance: u64, fee: u64) -> Self {
+        Self {
+            pubkey,
+            pre_balance,
+            post_balance,
+            fee,
+        }
+    }
+
+    /// Pubkey: The account address.
+    #[getter]
+    pub fn pubkey(&self) -> Pubkey {
+        self.pubkey
+    }
+
+    /// int: The balance before the transaction.
+    #[getter]
+    pub fn pre_balance(&self) -> u64 {
+        self.pre_balance
+    }
+
+    /// int: The balance after the transaction.
+    #[getter]
+    pub fn post_balance(&self) -> u64 {
+        self.post_balance
+    }
+
+    /// int: The transaction fee charged to this account.
+    #[getter]
+    pub fn fee(&self) -> u64 {
+        self.fee
+    }
+
+    /// int: The balance change, including any fee paid.
+    #[getter]
+    pub fn delta(&self) -> i128 {
+        i128::from(self.post_balance) - i128::from(self.pre_balance)
+    }
+
+    /// int: The balance change, not counting the transaction fee.
+    #[getter]
+    pub fn delta_excluding_fee(&self) -> i128 {
+        self.delta() + i128::from(self.fee)
+    }
+}
+
+/// The token balance change of one owner for one mint.
+///
+/// Balances of all the owner's token accounts for the mint are summed.
+///
+/// Args:
+///     owner (Pubkey): The token account owner. If the node did not report an owner,
+///         this is the token account address instead.
+///     mint (Pubkey): The token mint.
+///     decimals (int): The mint decimals.
+///     pre_amount (int): The raw amount before the transaction.
+///     post_amount (int): The raw amount after the transaction.
+///     program_id (Optional[Pubkey]): The token program, if reported.
+///
+#[pyclass(module = "solders.transaction_status", subclass)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TokenBalanceChange {
+    owner: Pubkey,
+    mint: Pubkey,
+    decimals: u8,
+    pre_amount: u64,
+    post_amount: u64,
+    program_id: Option<Pubkey>,
+}
+
+transaction_status_boilerplate!(TokenBalanceChange);
+
+#[richcmp_eq_only]
+#[common_methods]
+#[pymethods]
+impl TokenBalanceChange {
+    #[new]
+    pub fn new(
+        owner: Pubkey,
+        mint: Pubkey,
+        decimals: u8,
+        pre_amount: u64,
+        post_amount: u64,
+        program_id: Option<Pubkey>,
+    ) -> Self {
+        Self {
+            owner,
+            mint,
+            decimals,
+            pre_amount,
+            post_amount,
+            program_id,
+        }
+    }
+
+    /// Pubkey: The token account owner.
+    #[getter]
+    pub fn owner(&self) -> Pubkey {
+        self.owner
+    }
+
+    /// Pubkey: The token mint.
+    #[getter]
+    pub fn mint(&self) -> Pubkey {
+        self.mint
+    }
+
+    /// int: The mint decimals.
+    #[getter]
+    pub fn decimals(&self) -> u8 {
+        self.decimals
+    }
+
+    /// int: The raw amount before the transaction.
+    #[getter]
+    pub fn pre_amount(&self) -> u64 {
+        self.pre_amount
+    }
+
+    /// int: The raw amount after the transaction.
+    #[getter]
+    pub fn post_amount(&self) -> u64 {
+        self.post_amount
+    }
+
+    /// Optional[Pubkey]: The token program, if reported.
+    #[getter]
+    pub fn program_id(&self) -> Option<Pubkey> {
+        self.program_id
+    }
+
+    /// int: The raw amount change.
+    #[getter]
+    pub fn delta(&self) -> i128 {
+        i128::from(self.post_amount) - i128::from(self.pre_amount)
+    }
+
+    /// float: The amount change, adjusted for decimals.
+    #[getter]
+    pub fn ui_delta(&self) -> f64 {
+        self.delta() as f64 / 10f64.powi(i32::from(self.decimals))
+    }
+}
+
+/// The SOL and token balance changes of a transaction.
+///
+/// Args:
+///     fee_payer (Pubkey): The fee payer.
+///     fee (int): The transaction fee.
+///     sol (Sequence[AccountBalanceChange]): The lamport changes of every account in the transaction,
+///         in account key order.
+///     token (Sequence[TokenBalanceChange]): The token changes per (owner, mint).
+///
+#[pyclass(module = "solders.transaction_status", subclass)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BalanceChanges {
+    fee_payer: Pubkey,
+    fee: u64,
+    sol: Vec<AccountBalanceChange>,
+    token: Vec<TokenBalanceChange>,
+}
+
+transaction_status_boilerplate!(BalanceChanges);
+
+#[richcmp_eq_only]
+#[common_methods]
+#[pymethods]
+impl BalanceChanges {
+    #[new]
+    pub fn new(
+        fee_payer: Pubkey,
+        fee: u64,
+        sol: Vec<AccountBalanceChange>,
+        token: Vec<TokenBalanceChange>,
+    ) -> Self {
+        Self {
+            fee_payer,
+            fee,
+            sol,
+            token,
+        }
+    }
+
+    /// Pubkey: The fee payer.
+    #[getter]
+    pub fn fee_payer(&self) -> Pubkey {
+        self.fee_payer
+    }
+
+    /// int: The transaction fee.
+    #[getter]
+    pub fn fee(&self) -> u64 {
+        self.fee
+    }
+
+    /// List[AccountBalanceChange]: The lamport changes of every account, in account key order.
+    #[getter]
+    pub fn sol(&self) -> Vec<AccountBalanceChange> {
+        self.sol.clone()
+    }
+
+    /// List[TokenBalanceChange]: The token changes per (owner, mint).
+    #[getter]
+    pub fn token(&self) -> Vec<TokenBalanceChange> {
+        self.token.clone()
+    }
+
+    /// Get the lamport change of one account.
+    ///
+    /// Args:
+    ///     pubkey (Pubkey): The account address.
+    ///
+    /// Returns:
+    ///     Optional[AccountBalanceChange]: The change, or ``None`` if the account is not in the transaction.
+    ///
+    pub fn sol_change(&self, pubkey: Pubkey) -> Option<AccountBalanceChange> {
+        self.sol.iter().find(|c| c.pubkey == pubkey).cloned()
+    }
+
+    /// Get the token changes of one owner.
+    ///
+    /// Args:
+    ///     owner (Pubkey): The token account owner.
+    ///
+    /// Returns:
+    ///     List[TokenBalanceChange]: The owner's changes, one per mint.
+    ///
+    pub fn token_changes(&self, owner: Pubkey) -> Vec<TokenBalanceChange> {
+        self.token
+            .iter()
+            .filter(|c| c.owner == owner)
+            .cloned()
+            .collect()
+    }
+}
+
+fn parse_pubkey(s: &str) -> PyResult<Pubkey> {
+    Pubkey::from_str(s).map_err(|e| PyValueError::new_err(format!("Invalid pubkey {s}: {e}")))
+}
+
+fn add_token_balances(
+    changes: &mut Vec<TokenBalanceChange>,
+    balances: Vec<UiTransactionTokenBalanceOriginal>,
+    keys: &[Pubkey],
+    is_post: bool,
+) -> PyResult<()> {
+    for balance in balances {
+        let maybe_owner: Option<String> = balance.owner.into();
+        let owner = match maybe_owner {
+            Some(o) => parse_pubkey(&o)?,
+            None => *keys
+                .get(usize::from(balance.account_index))
+                .ok_or_else(|| {
+                    PyValueError::new_err(format!(
+                        "Token balance account index {} is out of range",
+                        balance.account_index
+                    ))
+                })?,
+        };
+        let mint = parse_pubkey(&balance.mint)?;
+        let maybe_program_id: Option<String> = balance.program_id.into();
+        let program_id = maybe_program_id.map(|p| parse_pubkey(&p)).transpose()?;
+        let amount: u64 = balance.ui_token_amount.amount.parse().map_err(|e| {
+            PyValueError::new_err(format!(
+                "Invalid token amount {}: {e}",
+                balance.ui_token_amount.amount
+            ))
+        })?;
+        let idx = match changes
+            .iter()
+            .position(|c| c.owner == owner && c.mint == mint)
+        {
+            Some(idx) => idx,
+            None => {
+                changes.push(TokenBalanceChange::new(
+                    owner,
+                    mint,
+                    balance.ui_token_amount.decimals,
+                    0,
+                    0,
+                    program_id,
+                ));
+                changes.len() - 1
+            }
+        };
+        let change = &mut changes[idx];
+        if is_post {
+            change.post_amount += amount;
+        } else {
+            change.pre_amount += amount;
+        }
+        if change.program_id.is_none() {
+            change.program_id = program_id;
+        }
+    }
+    Ok(())
+}
+
+/// Join the balances in ``meta`` with the transaction's account keys.
+///
+/// If ``account_keys`` holds only the static keys of a v0 transaction,
+/// the meta's loaded addresses are appended.
+pub(crate) fn compute_balance_changes(
+    meta: &UiTransactionStatusMetaOriginal,
+    account_keys: Vec<Pubkey>,
+) -> PyResult<BalanceChanges> {
+    let mut keys = account_keys;
+    if keys.len() < meta.pre_balances.len() {
+        let maybe_loaded: Option<UiLoadedAddressesOriginal> = meta.loaded_addresses.clone().into();
+        if let Some(loaded) = maybe_loaded {
+            for key in loaded.writable.iter().chain(loaded.readonly.iter()) {
+                keys.push(parse_pubkey(key)?);
+            }
+        }
+    }
+    if keys.len() != meta.pre_balances.len() || keys.len() != meta.post_balances.len() {
+        return Err(PyValueError::new_err(format!(
+            "Got {} account keys but {} pre balances and {} post balances",
+            keys.len(),
+            meta.pre_balances.len(),
+            meta.post_balances.len()
+        )));
+    }
+    let fee_payer = *keys
+        .first()
+        .ok_or_else(|| PyValueError::new_err("Transaction has no account keys"))?;
+    let sol = keys
+        .iter()
+        .zip(meta.pre_balances.iter().zip(meta.post_balances.iter()))
+        .enumerate()
+        .map(|(idx, (pubkey, (pre, post)))| {
+            AccountBalanceChange::new(*pubkey, *pre, *post, if idx == 0 { meta.fee } else { 0 })
+        })
+        .collect();
+    let mut token = vec![];
+    let pre_token: Option<Vec<UiTransactionTokenBalanceOriginal>> =
+        meta.pre_token_balances.clone().into();
+    let post_token: Option<Vec<UiTransactionTokenBalanceOriginal>> =
+        meta.post_token_balances.clone().into();
+    add_token_balances(&mut token, pre_token.unwrap_or_default(), &keys, false)?;
+    add_token_balances(&mut token, post_token.unwrap_or_default(), &keys, true)?;
+    Ok(BalanceChanges {
+        fee_payer,
+        fee: meta.fee,
+        sol,
+        token,
+    })
+}
@@ -0,0 +1,208 @@
+use std::str::FromStr;
+
+use pyo3::{exceptions::PyValueError, prelude::*};
+use solana_sdk::{
+    instruction::CompiledInstruction as CompiledInstructionOriginal,
+    message::v0::LoadedAddresses as LoadedAddressesOriginal, pubkey::Pubkey as PubkeyOriginal,
+    transaction_context::TransactionReturnData as TransactionReturnDataOriginal,
+};
+use solana_transaction_status::{
+    BlockEncodingOptions, ConfirmedBlock, EncodedTransactionWithStatusMeta as EncodedOriginal,
+    InnerInstruction as InnerInstructionOriginal, InnerInstructions as InnerInstructionsOriginal,
+    TransactionDetails as TransactionDetailsOriginal, TransactionStatusMeta,
+    TransactionTokenBalance, TransactionWithStatusMeta,
+    UiConfirmedBlock as UiConfirmedBlockOriginal,
+    UiInnerInstructions as UiInnerInstructionsOriginal, UiInstruction as UiInstructionOriginal,
+    UiLoadedAddresses as UiLoadedAddressesOriginal,
+    UiTransactionEncoding as UiTransactionEncodingOriginal,
+    UiTransactionReturnData as UiTransactionReturnDataOriginal,
+    UiTransactionStatusMeta as UiTransactionStatusMetaOriginal,
+    UiTransactionTokenBalance as UiTransactionTokenBalanceOriginal,
+    VersionedTransactionWithStatusMeta,
+};
+use solders_transaction::VersionedTransaction;
+
+fn parse_pubkey(s: &str) -> PyResult<PubkeyOriginal> {
+    PubkeyOriginal::from_str(s)
+        .map_err(|e| PyValueError::new_err(format!("Invalid pubkey {s}: {e}")))
+}
+
+fn to_inner_instructions(ui: UiInnerInstructionsOriginal) -> PyResult<InnerInstructionsOriginal> {
+    let instructions = ui
+        .instructions
+        .into_iter()
+        .map(|ix| match ix {
+            UiInstructionOriginal::Compiled(c) => {
+                let data = bs58::decode(&c.data).into_vec().map_err(|e| {
+                    PyValueError::new_err(format!("Invalid instruction data {}: {e}", c.data))
+                })?;
+                Ok(InnerInstructionOriginal {
+                    instruction: CompiledInstructionOriginal {
+                        program_id_index: c.program_id_index,
+                        accounts: c.accounts,
+                        data,
+                    },
+                    stack_height: c.stack_height,
+                })
+            }
+            UiInstructionOriginal::Parsed(_) => Err(PyValueError::new_err(
+                "Inner instructions must be compiled instructions, not jsonParsed ones",
+            )),
+        })
+        .collect::<PyResult<Vec<_>>>()?;
+    Ok(InnerInstructionsOriginal {
+        index: ui.index,
+        instructions,
+    })
+}
+
+fn to_token_balance(ui: UiTransactionTokenBalanceOriginal) -> TransactionTokenBalance {
+    let owner: Option<String> = ui.owner.into();
+    let program_id: Option<String> = ui.program_id.into();
+    // The RPC omits these fields when they are empty, so an empty string round-trips.
+    TransactionTokenBalance {
+        account_index: ui.account_index,
+        mint: ui.mint,
+        ui_token_amount: ui.ui_token_amount,
+        owner: owner.unwrap_or_default(),
+        program_id: program_id.unwrap_or_default(),
+    }
+}
+
+fn to_return_data(ui: UiTransactionReturnDataOriginal) -> PyResult<TransactionReturnDataOriginal> {
+    let data = base64::decode(&ui.data.0)
+        .map_err(|e| PyValueError::new_err(format!("Invalid return data {}: {e}", ui.data.0)))?;
+    Ok(TransactionReturnDataOriginal {
+        program_id: parse_pubkey(&ui.program_id)?,
+        data,
+    })
+}
+
+fn to_loaded_addresses(ui: UiLoadedAddressesOriginal) -> PyResult<LoadedAddressesOriginal> {
+    Ok(LoadedAddressesOriginal {
+        writable: ui
+            .writable
+            .iter()
+            .map(|k| parse_pubkey(k))
+            .collect::<PyResult<Vec<_>>>()?,
+        readonly: ui
+            .readonly
+            .iter()
+            .map(|k| parse_pubkey(k))
+            .collect::<PyResult<Vec<_>>>()?,
+    })
+}
+
+/// Recover the status meta that the RPC node encodes from.
+fn to_status_meta(meta: UiTransactionStatusMetaOriginal) -> PyResult<TransactionStatusMeta> {
+    let inner_instructions: Option<Vec<UiInnerInstructionsOriginal>> =
+        meta.inner_instructions.into();
+    let pre_token_balances: Option<Vec<UiTransactionTokenBalanceOriginal>> =
+        meta.pre_token_balances.into();
+    let post_token_balances: Option<Vec<UiTransactionTokenBalanceOriginal>> =
+        meta.post_token_balances.into();
+    let loaded_addresses: Option<UiLoadedAddressesOriginal> = meta.loaded_addresses.into();
+    let return_data: Option<UiTransactionReturnDataOriginal> = meta.return_data.into();
+    Ok(TransactionStatusMeta {
+        status: meta.err.map_or(Ok(()), Err),
+        fee: meta.fee,
+        pre_balances: meta.pre_balances,
+        post_balances: meta.post_balances,
+        inner_instructions: inner_instructions
+            .map(|v| v.into_iter().map(to_inner_instructions).collect())
+            .transpose()?,
+        log_messages: meta.log_messages.into(),
+        pre_token_balances: pre_token_balances
+            .map(|v| v.into_iter().map(to_token_balance).collect()),
+        post_token_balances: post_token_balances
+            .map(|v| v.into_iter().map(to_token_balance).collect()),
+        rewards: meta.rewards.into(),
+        loaded_addresses: loaded_addresses
+            .map(to_loaded_addresses)
+            .transpose()?
+            .unwrap_or_default(),
+        return_data: return_data.map(to_return_data).transpose()?,
+        compute_units_consumed: meta.compute_units_consumed.into(),
+    })
+}
+
+/// Pair a transaction with its meta. Only legacy transactions may lack a meta.
+pub(crate) fn to_transaction_with_status_meta(
+    transaction: VersionedTransaction,
+    meta: Option<UiTransactionStatusMetaOriginal>,
+) -> PyResult<TransactionWithStatusMeta> {
+    match meta {
+        Some(m) => Ok(TransactionWithStatusMeta::Complete(
+            VersionedTransactionWithStatusMeta {
+                transaction: transaction.0,
+                meta: to_status_meta(m)?,
+            },
+        )),
+        None => transaction
+            .0
+            .into_legacy_transaction()
+            .map(TransactionWithStatusMeta::MissingMetadata)
+            .ok_or_else(|| PyValueError::new_err("Versioned transactions must have a status meta")),
+    }
+}
+
+pub(crate) fn encode_block(
+    block: ConfirmedBlock,
+    encoding: UiTransactionEncodingOriginal,
+    transaction_details: TransactionDetailsOriginal,
+    show_rewards: bool,
+    max_supported_transaction_version: Option<u8>,
+) -> PyResult<UiConfirmedBlockOriginal> {
+    block
+        .encode_with_options(
+            encoding,
+            BlockEncodingOptions {
+                transaction_details,
+                show_rewards,
+                max_supported_transaction_version,
+            },
+        )
+        .map_err(|e| PyValueError::new_err(e.to_string()))
+}
+
+pub(crate) fn encode_transaction(
+    tx: TransactionWithStatusMeta,
+    encoding: UiTransactionEncodingOriginal,
+    transaction_details: TransactionDetailsOriginal,
+    show_rewards: bool,
+    max_supported_transaction_version: Option<u8>,
+) -> PyResult<EncodedOriginal> {
+    match transaction_details {
+        TransactionDetailsOriginal::Full => tx
+            .encode(encoding, max_supported_transaction_version, show_rewards)
+            .map_err(|e| PyValueError::new_err(e.to_string())),
+        TransactionDetailsOriginal::Accounts => {
+            // The accounts-only encoding is only exposed through block encoding.
+            let block = ConfirmedBlock {
+                previous_blockhash: String::new(),
+                blockhash: String::new(),
+                parent_slot: 0,
+                transactions: vec![tx],
+                rewards: vec![],
+                block_time: None,
+                block_height: None,
+            };
+            let encoded = encode_block(
+                block,
+                encoding,
+                transaction_details,
+                show_rewards,
+                max_supported_transaction_version,
+            )?;
+            encoded
+                .transactions
+                .and_then(|txs| txs.into_iter().next())
+                .ok_or_else(|| PyValueError::new_err("Block encoding dropped the transaction"))
+        }
+        TransactionDetailsOriginal::Signatures | TransactionDetailsOriginal::None => {
+            Err(PyValueError::new_err(
+                "transaction_details must be full or accounts for a single transaction",
+            ))
+        }
+    }
+}
@@ -4,7 +4,7 @@ extern crate base64;
 use pythonize::{depythonize, pythonize};
 use solders_account_decoder::UiTokenAmount;
 use solders_hash::Hash as SolderHash;
-use solders_message::MessageHeader;
+use solders_log_parser::{parse_log_messages, ParsedLogs};
 use solders_pubkey::Pubkey;
 use solders_signature::Signature;
 use solders_traits_core::{
@@ -26,6 +26,7 @@ use solders_transaction_status_struct::TransactionStatus;
 use std::str::FromStr;
 
 use pyo3::{
+    exceptions::PyValueError,
     prelude::*,
     pyclass::CompareOp,
     types::{PyBytes, PyTuple},
@@ -33,13 +34,29 @@ use pyo3::{
 };
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use solana_sdk::{clock::UnixTimestamp, slot_history::Slot};
+use solana_sdk::{
+    clock::UnixTimestamp,
+    hash::Hash as HashOriginal,
+    instruction::CompiledInstruction as CompiledInstructionOriginal,
+    message::{
+        legacy::Message as LegacyMessageOriginal,
+        v0::{
+            Message as MessageV0Original,
+            MessageAddressTableLookup as MessageAddressTableLookupOriginal,
+        },
+        VersionedMessage as VersionedMessageOriginal,
+    },
+    pubkey::Pubkey as PubkeyOriginal,
+    signature::Signature as SignatureOriginal,
+    slot_history::Slot,
+    transaction::VersionedTransaction as VersionedTransactionOriginal,
+};
 use solana_transaction_status::{
     parse_accounts::{
         ParsedAccount as ParsedAccountOriginal, ParsedAccountSource as ParsedAccountSourceOriginal,
     },
     parse_instruction::ParsedInstruction as ParsedInstructionOriginal,
-    EncodedTransaction as EncodedTransactionOriginal,
+    ConfirmedBlock, EncodedTransaction as EncodedTransactionOriginal,
     EncodedTransactionWithStatusMeta as EncodedTransactionWithStatusMetaOriginal,
     Reward as RewardOriginal, RewardType as RewardTypeOriginal,
     TransactionBinaryEncoding as TransactionBinaryEncodingOriginal,
@@ -55,8 +72,15 @@ use solana_transaction_status::{
     UiTransactionTokenBalance as UiTransactionTokenBalanceOriginal,
 };
 use solders_macros::{common_methods, enum_original_mapping, richcmp_eq_only, EnumIntoPy};
+use solders_message::{MessageHeader, VersionedMessage};
 use solders_transaction::{TransactionVersion, VersionedTransaction};
 
+pub mod balance_changes;
+use balance_changes::compute_balance_changes;
+pub use balance_changes::{AccountBalanceChange, BalanceChanges, TokenBalanceChange};
+mod encode;
+use encode::{encode_block, encode_transaction, to_transaction_with_status_meta};
+
 #[derive(Serialize, Deserialize, Clone, Copy, Debug, Eq, Hash, PartialEq)]
 #[serde(rename_all = "camelCase")]
 #[enum_original_mapping(TransactionBinaryEncodingOriginal)]
@@ -149,6 +173,14 @@ impl UiAddressTableLookup {
     }
 }
 
+fn parse_ui_field<T>(s: &str, what: &str) -> PyResult<T>
+where
+    T: FromStr,
+    T::Err: std::fmt::Display,
+{
+    T::from_str(s).map_err(|e| PyValueError::new_err(format!("Invalid {what} {s}: {e}")))
+}
+
 /// A duplicate representation of a Message, in raw format, for pretty JSON serialization
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, From, Into)]
 #[pyclass(module = "solders.transaction_status", subclass)]
@@ -156,6 +188,62 @@ pub struct UiRawMessage(UiRawMessageOriginal);
 
 transaction_status_boilerplate!(UiRawMessage);
 
+impl UiRawMessage {
+    fn to_versioned_message_original(&self) -> PyResult<VersionedMessageOriginal> {
+        let header = self.0.header;
+        let account_keys = self
+            .0
+            .account_keys
+            .iter()
+            .map(|k| parse_ui_field::<PubkeyOriginal>(k, "account key"))
+            .collect::<PyResult<Vec<_>>>()?;
+        let recent_blockhash =
+            parse_ui_field::<HashOriginal>(&self.0.recent_blockhash, "recent blockhash")?;
+        let instructions = self
+            .0
+            .instructions
+            .iter()
+            .map(|ix| {
+                let data = bs58::decode(&ix.data).into_vec().map_err(|e| {
+                    PyValueError::new_err(format!("Invalid instruction data {}: {e}", ix.data))
+                })?;
+                Ok(CompiledInstructionOriginal {
+                    program_id_index: ix.program_id_index,
+                    accounts: ix.accounts.clone(),
+                    data,
+                })
+            })
+            .collect::<PyResult<Vec<_>>>()?;
+        Ok(match &self.0.address_table_lookups {
+            None => VersionedMessageOriginal::Legacy(LegacyMessageOriginal {
+                header,
+                account_keys,
+                recent_blockhash,
+                instructions,
+            }),
+            Some(lookups) => {
+                let address_table_lookups = lookups
+                    .iter()
+                    .map(|l| {
+                        Ok(MessageAddressTableLookupOriginal {
+                            account_key: parse_ui_field(&l.account_key, "lookup table address")?,
+                            writable_indexes: l.writable_indexes.clone(),
+                            readonly_indexes: l.readonly_indexes.clone(),
+                        })
+                    })
+                    .collect::<PyResult<Vec<_>>>()?;
+                VersionedMessageOriginal::V0(MessageV0Original {
+                    header,
+                    account_keys,
+                    recent_blockhash,
+                    instructions,
+                    address_table_lookups,
+                })
+            }
+        })
+    }
+}
+
 #[richcmp_eq_only]
 #[common_methods]
 #[pymethods]
@@ -215,6 +303,20 @@ impl UiRawMessage {
             .clone()
             .map(|v| v.into_iter().map(UiAddressTableLookup::from).collect())
     }
+
+    /// Convert to a message.
+    ///
+    /// Returns a legacy message if ``address_table_lookups`` is ``None``, otherwise a v0 message.
+    ///
+    /// Returns:
+    ///     VersionedMessage: The message.
+    ///
+    /// Raises:
+    ///     ValueError: If a field cannot be parsed.
+    ///
+    pub fn to_versioned_message(&self) -> PyResult<VersionedMessage> {
+        self.to_versioned_message_original().map(Into::into)
+    }
 }
 
 #[pyclass(module = "solders.transaction_status")]
@@ -543,6 +645,41 @@ impl UiTransaction {
     pub fn message(&self) -> UiMessage {
         self.0.message.clone().into()
     }
+
+    /// Convert to a ``VersionedTransaction``, so that signatures can be verified locally.
+    ///
+    /// Only transactions fetched with the ``json`` encoding can be converted.
+    ///
+    /// Returns:
+    ///     VersionedTransaction: The transaction.
+    ///
+    /// Raises:
+    ///     ValueError: If the transaction was fetched with the ``jsonParsed`` encoding,
+    ///         or a field cannot be parsed.
+    ///
+    pub fn to_versioned_transaction(&self) -> PyResult<VersionedTransaction> {
+        let message =
+            match &self.0.message {
+                UiMessageOriginal::Raw(raw) => {
+                    UiRawMessage::from(raw.clone()).to_versioned_message_original()?
+                }
+                UiMessageOriginal::Parsed(_) => return Err(PyValueError::new_err(
+                    "jsonParsed transactions cannot be converted back to a VersionedTransaction. \
+                     Fetch the transaction with the json, base58 or base64 encoding instead.",
+                )),
+            };
+        let signatures = self
+            .0
+            .signatures
+            .iter()
+            .map(|s| parse_ui_field::<SignatureOriginal>(s, "signature"))
+            .collect::<PyResult<Vec<_>>>()?;
+        Ok(VersionedTransactionOriginal {
+            signatures,
+            message,
+        }
+        .into())
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, FromPyObject, EnumIntoPy)]
@@ -565,6 +702,40 @@ impl From<EncodedTransaction> for EncodedVersionedTransaction {
     }
 }
 
+impl EncodedVersionedTransaction {
+    /// Recover the ``VersionedTransaction``.
+    /// Fails for jsonParsed transactions and the accounts details level.
+    pub fn decode(&self) -> PyResult<VersionedTransaction> {
+        match self {
+            Self::Binary(tx) => Ok(tx.clone()),
+            Self::Json(tx) => tx.to_versioned_transaction(),
+            Self::Accounts(_) => Err(PyValueError::new_err(
+                "Transactions fetched with the accounts details level cannot be converted \
+                 back to a VersionedTransaction.",
+            )),
+        }
+    }
+
+    /// The account keys listed in the transaction.
+    /// For jsonParsed and accounts-only encodings these include loaded addresses.
+    pub fn account_keys(&self) -> Vec<Pubkey> {
+        match self {
+            Self::Binary(tx) => {
+                tx.0.message
+                    .static_account_keys()
+                    .iter()
+                    .map(|k| Pubkey(*k))
+                    .collect()
+            }
+            Self::Json(tx) => match tx.message() {
+                UiMessage::Raw(msg) => msg.account_keys(),
+                UiMessage::Parsed(msg) => msg.account_keys().iter().map(|a| a.pubkey()).collect(),
+            },
+            Self::Accounts(list) => list.account_keys().iter().map(|a| a.pubkey()).collect(),
+        }
+    }
+}
+
 impl From<EncodedVersionedTransaction> for EncodedTransaction {
     fn from(e: EncodedVersionedTransaction) -> Self {
         match e {
@@ -917,6 +1088,33 @@ impl UiTransactionStatusMeta {
     pub fn log_messages(&self) -> Option<Vec<String>> {
         self.0.log_messages.clone().into()
     }
+
+    /// Parse the log messages into a call tree.
+    ///
+    /// Returns:
+    ///     Optional[ParsedLogs]: The parsed logs, or ``None`` if the node did not return logs.
+    ///
+    pub fn parse_logs(&self) -> Option<ParsedLogs> {
+        let maybe_logs: Option<Vec<String>> = self.0.log_messages.clone().into();
+        maybe_logs.map(|logs| parse_log_messages(&logs))
+    }
+
+    /// Compute the SOL and token balance changes of the transaction.
+    ///
+    /// Args:
+    ///     account_keys (Sequence[Pubkey]): The transaction's account keys. For v0 transactions
+    ///         you may pass only the static keys, in which case the loaded addresses
+    ///         from this meta are appended.
+    ///
+    /// Returns:
+    ///     BalanceChanges: The balance changes.
+    ///
+    /// Raises:
+    ///     ValueError: If the account keys don't line up with the balances.
+    ///
+    pub fn balance_changes(&self, account_keys: Vec<Pubkey>) -> PyResult<BalanceChanges> {
+        compute_balance_changes(&self.0, account_keys)
+    }
     #[getter]
     pub fn pre_token_balances(&self) -> Option<Vec<UiTransactionTokenBalance>> {
         let maybe_balances: Option<Vec<UiTransactionTokenBalanceOriginal>> =
@@ -989,6 +1187,76 @@ impl EncodedTransactionWithStatusMeta {
     pub fn version(&self) -> Option<TransactionVersion> {
         self.0.version.clone().map(|v| v.into())
     }
+
+    /// Compute the SOL and token balance changes of the transaction.
+    ///
+    /// Returns:
+    ///     BalanceChanges: The balance changes.
+    ///
+    /// Raises:
+    ///     ValueError: If there is no meta or the account keys don't line up with the balances.
+    ///
+    pub fn balance_changes(&self) -> PyResult<BalanceChanges> {
+        let meta = self
+            .0
+            .meta
+            .as_ref()
+            .ok_or_else(|| PyValueError::new_err("Transaction has no status meta"))?;
+        compute_balance_changes(meta, self.transaction().account_keys())
+    }
+
+    /// Recover the ``VersionedTransaction`` from whichever encoding it was fetched with.
+    ///
+    /// Returns:
+    ///     VersionedTransaction: The transaction.
+    ///
+    /// Raises:
+    ///     ValueError: If the transaction was fetched with the ``jsonParsed`` encoding
+    ///         or the ``accounts`` details level.
+    ///
+    pub fn decode_transaction(&self) -> PyResult<VersionedTransaction> {
+        self.transaction().decode()
+    }
+
+    /// Encode a transaction and its status meta the way an RPC node would for ``getTransaction``.
+    ///
+    /// Args:
+    ///     transaction (VersionedTransaction): The transaction.
+    ///     meta (Optional[UiTransactionStatusMeta]): The status meta. Its inner instructions must be
+    ///         compiled instructions, as returned with the ``json`` or binary encodings.
+    ///         Only legacy transactions may omit it.
+    ///     encoding (UiTransactionEncoding): The encoding to use. ``jsonParsed`` uses the built-in
+    ///         instruction parsers.
+    ///     max_supported_transaction_version (Optional[int]): The highest transaction version to encode.
+    ///     show_rewards (bool): Whether to include rewards in the meta.
+    ///     transaction_details (TransactionDetails): ``full`` or ``accounts``.
+    ///
+    /// Returns:
+    ///     EncodedTransactionWithStatusMeta: The encoded transaction.
+    ///
+    /// Raises:
+    ///     ValueError: If the transaction version is unsupported, the meta cannot be converted
+    ///         or the details level is not ``full`` or ``accounts``.
+    ///
+    #[staticmethod]
+    #[pyo3(signature = (transaction, meta, encoding, max_supported_transaction_version=None, show_rewards=true, transaction_details=TransactionDetails::Full))]
+    pub fn from_transaction(
+        transaction: VersionedTransaction,
+        meta: Option<UiTransactionStatusMeta>,
+        encoding: UiTransactionEncoding,
+        max_supported_transaction_version: Option<u8>,
+        show_rewards: bool,
+        transaction_details: TransactionDetails,
+    ) -> PyResult<Self> {
+        encode_transaction(
+            to_transaction_with_status_meta(transaction, meta.map(|m| m.0))?,
+            encoding.into(),
+            transaction_details.into(),
+            show_rewards,
+            max_supported_transaction_version,
+        )
+        .map(Self)
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, From, Into)]
@@ -1159,6 +1427,67 @@ impl UiConfirmedBlock {
     pub fn block_height(&self) -> Option<u64> {
         self.0.block_height
     }
+
+    /// Encode a block the way an RPC node would for ``getBlock``.
+    ///
+    /// Args:
+    ///     previous_blockhash (Hash): The parent block's blockhash.
+    ///     blockhash (Hash): The block's blockhash.
+    ///     parent_slot (int): The parent block's slot.
+    ///     transactions (Sequence[Tuple[VersionedTransaction, Optional[UiTransactionStatusMeta]]]):
+    ///         The transactions and their status metas, in block order.
+    ///         See :meth:`EncodedTransactionWithStatusMeta.from_transaction`.
+    ///     rewards (Sequence[Reward]): The block rewards.
+    ///     block_time (Optional[int]): The estimated production time.
+    ///     block_height (Optional[int]): The block height.
+    ///     encoding (UiTransactionEncoding): The transaction encoding.
+    ///     transaction_details (TransactionDetails): The level of transaction detail.
+    ///     show_rewards (bool): Whether to include rewards.
+    ///     max_supported_transaction_version (Optional[int]): The highest transaction version to encode.
+    ///
+    /// Returns:
+    ///     UiConfirmedBlock: The encoded block.
+    ///
+    /// Raises:
+    ///     ValueError: If a transaction version is unsupported or a meta cannot be converted.
+    ///
+    #[staticmethod]
+    #[pyo3(signature = (previous_blockhash, blockhash, parent_slot, transactions, rewards, block_time, block_height, encoding, transaction_details=TransactionDetails::Full, show_rewards=true, max_supported_transaction_version=None))]
+    #[allow(clippy::too_many_arguments)]
+    pub fn from_transactions(
+        previous_blockhash: SolderHash,
+        blockhash: SolderHash,
+        parent_slot: Slot,
+        transactions: Vec<(VersionedTransaction, Option<UiTransactionStatusMeta>)>,
+        rewards: Rewards,
+        block_time: Option<UnixTimestamp>,
+        block_height: Option<u64>,
+        encoding: UiTransactionEncoding,
+        transaction_details: TransactionDetails,
+        show_rewards: bool,
+        max_supported_transaction_version: Option<u8>,
+    ) -> PyResult<Self> {
+        let block = ConfirmedBlock {
+            previous_blockhash: previous_blockhash.to_string(),
+            blockhash: blockhash.to_string(),
+            parent_slot,
+            transactions: transactions
+                .into_iter()
+                .map(|(tx, meta)| to_transaction_with_status_meta(tx, meta.map(|m| m.0)))
+                .collect::<PyResult<Vec<_>>>()?,
+            rewards: rewards.into_iter().map(|r| r.into()).collect(),
+            block_time,
+            block_height,
+        };
+        encode_block(
+            block,
+            encoding.into(),
+            transaction_details.into(),
+            show_rewards,
+            max_supported_transaction_version,
+        )
+        .map(Self)
+    }
 }
 
 pub fn create_transaction_status_mod(py: Python<'_>) -> PyResult<&PyModule> {
@@ -1195,6 +1524,9 @@ pub fn create_transaction_status_mod(py: Python<'_>) -> PyResult<&PyModule> {
     m.add_class::<TransactionStatus>()?;
     m.add_class::<EncodedConfirmedTransactionWithStatusMeta>()?;
     m.add_class::<UiConfirmedBlock>()?;
+    m.add_class::<AccountBalanceChange>()?;
+    m.add_class::<TokenBalanceChange>()?;
+    m.add_class::<BalanceChanges>()?;
     let typing = py.import("typing")?;
     let union = typing.getattr("Union")?;
     let ui_parsed_instruction_members = vec![
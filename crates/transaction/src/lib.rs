@@ -1,11 +1,13 @@
 #![allow(deprecated)]
 use derive_more::{From, Into};
-use pyo3::{prelude::*, types::PyBytes};
+use pyo3::{exceptions::PyValueError, prelude::*, types::PyBytes};
 use serde::{Deserialize, Serialize};
 use solana_sdk::{
+    message::VersionedMessage as VersionedMessageOriginal,
     pubkey::Pubkey as PubkeyOriginal,
     sanitize::Sanitize,
     signature::Signature as SignatureOriginal,
+    signer::SignerError as SignerErrorOriginal,
     transaction::{
         get_nonce_pubkey_from_instruction, uses_durable_nonce, Legacy as LegacyOriginal,
         Transaction as TransactionOriginal, TransactionVersion as TransactionVersionOriginal,
@@ -14,10 +16,10 @@ use solana_sdk::{
 };
 use solders_macros::{common_methods, richcmp_eq_only, EnumIntoPy};
 use solders_pubkey::{convert_optional_pubkey, Pubkey};
-use solders_traits::handle_py_err;
+use solders_traits::{handle_py_err, to_py_err, SignerTraitWrapper};
 use solders_traits_core::{
-    impl_display, py_from_bytes_general_via_bincode, pybytes_general_via_bincode,
-    CommonMethodsCore, RichcmpEqualityOnly,
+    impl_display, py_from_bytes_general_via_bincode, pybytes_general_via_bincode, to_py_value_err,
+    CommonMethods, CommonMethodsCore, PyFromBytesBincode, PyFromBytesGeneral, RichcmpEqualityOnly,
 };
 
 use solders_hash::Hash as SolderHash;
@@ -172,6 +174,328 @@ impl VersionedTransaction {
     }
 }
 
+/// A transaction message that is still collecting signatures.
+///
+/// Use this to pass a transaction between parties that sign it offline.
+/// It keeps track of which of the message's required signers have already
+/// signed, and every signature is verified against the message when it is added.
+///
+/// Args:
+///     message (Message | MessageV0): The message to be signed.
+///     nonce_account (Optional[Pubkey]): The durable nonce account used by the message, if any.
+///     last_valid_block_height (Optional[int]): The last block height at which the message's
+///         blockhash is valid, if known.
+///
+/// Example:
+///     >>> from solders.pubkey import Pubkey
+///     >>> from solders.instruction import Instruction, AccountMeta
+///     >>> from solders.message import MessageV0
+///     >>> from solders.hash import Hash
+///     >>> from solders.keypair import Keypair
+///     >>> from solders.transaction import PartiallySignedTransaction
+///     >>> payer, cosigner = Keypair(), Keypair()
+///     >>> ix = Instruction(Pubkey.new_unique(), b"", [AccountMeta(cosigner.pubkey(), True, False)])
+///     >>> message = MessageV0.try_compile(payer.pubkey(), [ix], [], Hash.new_unique())
+///     >>> partial = PartiallySignedTransaction(message)
+///     >>> partial.sign([payer])
+///     >>> assert partial.missing_signers() == [cosigner.pubkey()]
+///     >>> received = PartiallySignedTransaction.from_bytes(bytes(partial))
+///     >>> received.sign([cosigner])
+///     >>> tx = received.to_transaction()
+///
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[pyclass(module = "solders.transaction", subclass)]
+pub struct PartiallySignedTransaction {
+    message: VersionedMessage,
+    signers: Vec<Pubkey>,
+    signatures: Vec<Option<Signature>>,
+    nonce_account: Option<Pubkey>,
+    last_valid_block_height: Option<u64>,
+}
+
+fn required_signers(message: &VersionedMessage) -> Vec<Pubkey> {
+    let original = VersionedMessageOriginal::from(message.clone());
+    let num_signers = usize::from(original.header().num_required_signatures);
+    original
+        .static_account_keys()
+        .iter()
+        .take(num_signers)
+        .map(|k| Pubkey::from(*k))
+        .collect()
+}
+
+impl PartiallySignedTransaction {
+    fn message_data(&self) -> Vec<u8> {
+        VersionedMessageOriginal::from(self.message.clone()).serialize()
+    }
+
+    /// Check that a deserialized envelope is consistent with its message.
+    fn validated(self) -> PyResult<Self> {
+        if self.signers != required_signers(&self.message) {
+            return Err(PyValueError::new_err(
+                "signers do not match the message's required signers",
+            ));
+        }
+        if self.signatures.len() != self.signers.len() {
+            return Err(PyValueError::new_err(format!(
+                "expected {} signatures, found {}",
+                self.signers.len(),
+                self.signatures.len()
+            )));
+        }
+        let message_data = self.message_data();
+        for (signer, signature) in self.signers.iter().zip(self.signatures.iter()) {
+            if let Some(sig) = signature {
+                if !sig.verify(*signer, &message_data) {
+                    return Err(PyValueError::new_err(format!(
+                        "signature does not verify for {signer}"
+                    )));
+                }
+            }
+        }
+        Ok(self)
+    }
+
+    fn insert_signature(
+        &mut self,
+        pubkey: &Pubkey,
+        signature: Signature,
+        message_data: &[u8],
+    ) -> Result<(), SignerErrorOriginal> {
+        let position = self
+            .signers
+            .iter()
+            .position(|signer| signer == pubkey)
+            .ok_or(SignerErrorOriginal::KeypairPubkeyMismatch)?;
+        if !signature.verify(*pubkey, message_data) {
+            return Err(SignerErrorOriginal::InvalidInput(format!(
+                "signature does not verify for {pubkey}"
+            )));
+        }
+        self.signatures[position] = Some(signature);
+        Ok(())
+    }
+}
+
+impl RichcmpEqualityOnly for PartiallySignedTransaction {}
+pybytes_general_via_bincode!(PartiallySignedTransaction);
+
+impl PyFromBytesBincode<'_> for PartiallySignedTransaction {}
+impl PyFromBytesGeneral for PartiallySignedTransaction {
+    fn py_from_bytes_general(raw: &[u8]) -> PyResult<Self> {
+        Self::py_from_bytes_bincode(raw)?.validated()
+    }
+}
+
+impl CommonMethodsCore for PartiallySignedTransaction {}
+impl CommonMethods<'_> for PartiallySignedTransaction {
+    fn py_from_json(raw: &str) -> PyResult<Self> {
+        let deser: Self = serde_json::from_str(raw).map_err(|e| to_py_value_err(&e))?;
+        deser.validated()
+    }
+}
+
+impl std::fmt::Display for PartiallySignedTransaction {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+#[richcmp_eq_only]
+#[common_methods]
+#[pymethods]
+impl PartiallySignedTransaction {
+    #[new]
+    #[pyo3(signature = (message, nonce_account=None, last_valid_block_height=None))]
+    pub fn new(
+        message: VersionedMessage,
+        nonce_account: Option<Pubkey>,
+        last_valid_block_height: Option<u64>,
+    ) -> Self {
+        let signers = required_signers(&message);
+        let signatures = vec![None; signers.len()];
+        Self {
+            message,
+            signers,
+            signatures,
+            nonce_account,
+            last_valid_block_height,
+        }
+    }
+
+    /// Collect the valid signatures of an existing transaction.
+    ///
+    /// Signatures that are missing (all zeroes) or do not verify are left out.
+    ///
+    /// Args:
+    ///     tx (VersionedTransaction): The (possibly partially signed) transaction.
+    ///     nonce_account (Optional[Pubkey]): The durable nonce account used by the message, if any.
+    ///     last_valid_block_height (Optional[int]): The last block height at which the message's
+    ///         blockhash is valid, if known.
+    ///
+    /// Returns:
+    ///     PartiallySignedTransaction: The envelope.
+    ///
+    #[staticmethod]
+    #[pyo3(signature = (tx, nonce_account=None, last_valid_block_height=None))]
+    pub fn from_transaction(
+        tx: VersionedTransaction,
+        nonce_account: Option<Pubkey>,
+        last_valid_block_height: Option<u64>,
+    ) -> Self {
+        let mut envelope = Self::new(
+            tx.0.message.clone().into(),
+            nonce_account,
+            last_valid_block_height,
+        );
+        let message_data = envelope.message_data();
+        let signers = envelope.signers.clone();
+        for (signer, signature) in signers.iter().zip(tx.0.signatures) {
+            if signature != SignatureOriginal::default() {
+                // signatures that don't verify are simply not collected
+                let _ = envelope.insert_signature(signer, signature.into(), &message_data);
+            }
+        }
+        envelope
+    }
+
+    /// Message | MessageV0: The message being signed.
+    #[getter]
+    pub fn message(&self) -> VersionedMessage {
+        self.message.clone()
+    }
+
+    /// List[Pubkey]: The pubkeys that must sign the message, in signature order.
+    #[getter]
+    pub fn signers(&self) -> Vec<Pubkey> {
+        self.signers.clone()
+    }
+
+    /// List[Optional[Signature]]: The collected signatures, aligned with ``signers``.
+    #[getter]
+    pub fn signatures(&self) -> Vec<Option<Signature>> {
+        self.signatures.clone()
+    }
+
+    /// Optional[Pubkey]: The durable nonce account used by the message, if any.
+    #[getter]
+    pub fn nonce_account(&self) -> Option<Pubkey> {
+        self.nonce_account
+    }
+
+    /// Optional[int]: The last block height at which the message's blockhash is valid, if known.
+    #[getter]
+    pub fn last_valid_block_height(&self) -> Option<u64> {
+        self.last_valid_block_height
+    }
+
+    /// The required signers that have not signed yet.
+    ///
+    /// Returns:
+    ///     List[Pubkey]: The missing signers.
+    ///
+    pub fn missing_signers(&self) -> Vec<Pubkey> {
+        self.signers
+            .iter()
+            .zip(self.signatures.iter())
+            .filter(|(_, sig)| sig.is_none())
+            .map(|(signer, _)| *signer)
+            .collect()
+    }
+
+    /// Check if every required signer has signed.
+    ///
+    /// Returns:
+    ///     bool: True if no signatures are missing.
+    ///
+    pub fn is_fully_signed(&self) -> bool {
+        self.signatures.iter().all(Option::is_some)
+    }
+
+    /// Add a signature produced elsewhere.
+    ///
+    /// Args:
+    ///     pubkey (Pubkey): The signer's pubkey.
+    ///     signature (Signature): The signer's signature of the message.
+    ///
+    /// Raises:
+    ///     SignerError: if ``pubkey`` is not a required signer or the signature does not verify.
+    ///
+    pub fn add_signature(&mut self, pubkey: Pubkey, signature: Signature) -> PyResult<()> {
+        let message_data = self.message_data();
+        handle_py_err(self.insert_signature(&pubkey, signature, &message_data))
+    }
+
+    /// Sign the message with the given signers.
+    ///
+    /// Args:
+    ///     keypairs (Sequence[Keypair | Presigner]): The signers. Each must be a required signer.
+    ///
+    /// Raises:
+    ///     SignerError: if a signer is not required or fails to sign.
+    ///
+    pub fn sign(&mut self, keypairs: Vec<Signer>) -> PyResult<()> {
+        let message_data = self.message_data();
+        for keypair in keypairs {
+            let pubkey: Pubkey = handle_py_err(keypair.try_pubkey())?;
+            let signature: Signature = handle_py_err(keypair.try_sign_message(&message_data))?;
+            handle_py_err(self.insert_signature(&pubkey, signature, &message_data))?;
+        }
+        Ok(())
+    }
+
+    /// Merge the signatures collected by another envelope for the same message.
+    ///
+    /// Args:
+    ///     other (PartiallySignedTransaction): The other envelope.
+    ///
+    /// Raises:
+    ///     SignerError: if the messages differ or any of the other envelope's signatures do not verify.
+    ///
+    pub fn merge(&mut self, other: &Self) -> PyResult<()> {
+        if self.message != other.message {
+            return Err(to_py_err(SignerErrorOriginal::InvalidInput(
+                "cannot merge signatures for a different message".to_string(),
+            )));
+        }
+        let message_data = self.message_data();
+        for (signer, signature) in other.signers.iter().zip(other.signatures.iter()) {
+            if let Some(sig) = signature {
+                handle_py_err(self.insert_signature(signer, *sig, &message_data))?;
+            }
+        }
+        if self.nonce_account.is_none() {
+            self.nonce_account = other.nonce_account;
+        }
+        if self.last_valid_block_height.is_none() {
+            self.last_valid_block_height = other.last_valid_block_height;
+        }
+        Ok(())
+    }
+
+    /// Build the signed transaction.
+    ///
+    /// Returns:
+    ///     VersionedTransaction: The fully signed transaction.
+    ///
+    /// Raises:
+    ///     SignerError: if any required signature is missing.
+    ///
+    pub fn to_transaction(&self) -> PyResult<VersionedTransaction> {
+        let signatures: Vec<Signature> = handle_py_err(
+            self.signatures
+                .iter()
+                .map(|s| s.ok_or(SignerErrorOriginal::NotEnoughSigners))
+                .collect::<Result<Vec<Signature>, _>>(),
+        )?;
+        Ok(VersionedTransaction::populate(
+            self.message.clone(),
+            signatures,
+        ))
+    }
+}
+
 #[pyclass(module = "solders.transaction", subclass)]
 #[derive(Debug, PartialEq, Default, Eq, Clone, Serialize, Deserialize, From, Into)]
 /// An atomically-commited sequence of instructions.
@@ -358,6 +682,64 @@ impl Transaction {
         .into()
     }
 
+    #[staticmethod]
+    /// Create a fully-signed transaction that uses a durable nonce instead of a recent blockhash.
+    ///
+    /// An ``AdvanceNonceAccount`` instruction is prepended to ``instructions``
+    /// and the stored nonce is used as the message's ``recent_blockhash``.
+    ///
+    /// Args:
+    ///    instructions (Sequence[Instruction]): The instructions to include in the transaction message.
+    ///    payer (Optional[Pubkey]): The transaction fee payer.
+    ///    signing_keypairs (Sequence[Keypair | Presigner]): The keypairs that will sign the transaction.
+    ///        Must include the nonce authority.
+    ///    nonce_account_pubkey (Pubkey): The nonce account.
+    ///    nonce_authority_pubkey (Pubkey): The nonce account's authority.
+    ///    durable_nonce (Hash): The nonce value stored in the nonce account,
+    ///        i.e. :attr:`~solders.system_program.NonceData.durable_nonce`.
+    ///
+    /// Returns:
+    ///     Transaction: The signed transaction.
+    ///
+    /// Raises:
+    ///     SignerError: if signing fails.
+    ///
+    /// Example:
+    ///     >>> from solders.keypair import Keypair
+    ///     >>> from solders.instruction import Instruction
+    ///     >>> from solders.transaction import Transaction
+    ///     >>> from solders.pubkey import Pubkey
+    ///     >>> from solders.hash import Hash
+    ///     >>> from solders.system_program import NonceData
+    ///     >>> payer = Keypair()
+    ///     >>> nonce_account = Pubkey.new_unique()  # replace with a real nonce account
+    ///     >>> nonce_data = NonceData(payer.pubkey(), Hash.new_unique(), 5000)  # replace with the parsed account data
+    ///     >>> instruction = Instruction(Pubkey.default(), bytes([1]), [])
+    ///     >>> tx = Transaction.new_signed_with_nonce(
+    ///     ...     [instruction], payer.pubkey(), [payer], nonce_account, payer.pubkey(), nonce_data.durable_nonce
+    ///     ... )
+    ///     >>> assert nonce_data.verify_recent_blockhash(tx.message.recent_blockhash)
+    ///
+    #[pyo3(signature = (instructions, payer, signing_keypairs, nonce_account_pubkey, nonce_authority_pubkey, durable_nonce))]
+    pub fn new_signed_with_nonce(
+        instructions: Vec<Instruction>,
+        payer: Option<Pubkey>,
+        signing_keypairs: Vec<Signer>,
+        nonce_account_pubkey: &Pubkey,
+        nonce_authority_pubkey: &Pubkey,
+        durable_nonce: SolderHash,
+    ) -> PyResult<Self> {
+        let message = Message::new_with_nonce(
+            instructions,
+            payer,
+            nonce_account_pubkey,
+            nonce_authority_pubkey,
+        );
+        let mut tx = TransactionOriginal::new_unsigned(message.into());
+        handle_py_err(tx.try_sign(&SignerVec(signing_keypairs), durable_nonce.into()))?;
+        Ok(tx.into())
+    }
+
     #[staticmethod]
     /// Create a fully-signed transaction from pre-compiled instructions.
     ///
@@ -3,21 +3,28 @@ import contextlib as __ctxlib
 from solders.solders import __version__ as _version_untyped  # type: ignore
 from solders.solders import (
     address_lookup_table_account,
+    anchor_idl,
+    borsh,
     clock,
     commitment_config,
     compute_budget,
+    custom_error,
     epoch_info,
+    epoch_rewards,
     epoch_schedule,
     errors,
     hash,
     instruction,
+    instruction_decoder,
     keypair,
+    log_parser,
     message,
     null_signer,
     presigner,
     pubkey,
     rent,
     signature,
+    stake_history,
     token,
     transaction,
 )
@@ -32,20 +39,37 @@ with __ctxlib.suppress(ImportError):
 
 __has_ring = False
 with __ctxlib.suppress(ImportError):
-    from solders.solders import account, account_decoder, rpc, transaction_status
+    from solders.solders import (
+        account,
+        account_classifier,
+        account_decoder,
+        rpc,
+        transaction_status,
+    )
 
     __has_ring = True
 
 
-__ring_modules = ["account", "account_decoder", "rpc", "transaction_status"]
+__ring_modules = [
+    "account",
+    "account_classifier",
+    "account_decoder",
+    "rpc",
+    "transaction_status",
+]
 
 __all_core = [
     "address_lookup_table_account",
+    "anchor_idl",
+    "borsh",
     "commitment_config",
+    "custom_error",
     "errors",
     "hash",
     "instruction",
+    "instruction_decoder",
     "keypair",
+    "log_parser",
     "message",
     "null_signer",
     "presigner",
@@ -1,9 +1,31 @@
-from typing import Any, Dict, Final, List, Sequence, Tuple
+from typing import Any, Dict, Final, List, Optional, Sequence, Tuple, Union
 
-from solders.instruction import Instruction
+from solders.hash import Hash
+from solders.instruction import CompiledInstruction, Instruction
 from solders.pubkey import Pubkey
 
 ID: Final[Pubkey]
+NONCE_STATE_SIZE: Final[int]
+
+class NonceData:
+    def __init__(
+        self, authority: Pubkey, durable_nonce: Hash, lamports_per_signature: int
+    ) -> None: ...
+    @property
+    def authority(self) -> Pubkey: ...
+    @property
+    def durable_nonce(self) -> Hash: ...
+    @property
+    def lamports_per_signature(self) -> int: ...
+    @property
+    def is_legacy(self) -> bool: ...
+    def verify_recent_blockhash(self, recent_blockhash: Hash) -> bool: ...
+    @staticmethod
+    def from_bytes(data: bytes) -> "NonceData": ...
+    def __bytes__(self) -> bytes: ...
+    def __richcmp__(self, other: "NonceData", op: int) -> bool: ...
+    def __repr__(self) -> str: ...
+    def __str__(self) -> str: ...
 
 def create_account(params: Dict[str, Any]) -> Instruction: ...
 def decode_create_account(instruction: Instruction) -> Dict[str, Any]: ...
@@ -47,6 +69,12 @@ def withdraw_nonce_account(params: Dict[str, Any]) -> Instruction: ...
 def decode_withdraw_nonce_account(instruction: Instruction) -> Dict[str, Any]: ...
 def authorize_nonce_account(params: Dict[str, Any]) -> Instruction: ...
 def decode_authorize_nonce_account(instruction: Instruction) -> Dict[str, Any]: ...
+def upgrade_nonce_account(params: Dict[str, Any]) -> Instruction: ...
+def decode_upgrade_nonce_account(instruction: Instruction) -> Dict[str, Any]: ...
+def decode_instruction(
+    instruction: Union[Instruction, CompiledInstruction],
+    keys: Optional[Sequence[Pubkey]] = None,
+) -> Tuple[str, Dict[str, Any]]: ...
 def close_lookup_table(params: Dict[str, Any]) -> Instruction: ...
 def create_lookup_table(params: Dict[str, Any]) -> Tuple[Instruction, Pubkey]: ...
 def create_lookup_table_signed(
@@ -55,3 +83,6 @@ def create_lookup_table_signed(
 def deactivate_lookup_table(params: Dict[str, Any]) -> Instruction: ...
 def extend_lookup_table(params: Dict[str, Any]) -> Instruction: ...
 def freeze_lookup_table(params: Dict[str, Any]) -> Instruction: ...
+def decode_lookup_table_instruction(
+    instruction: Instruction,
+) -> Tuple[str, Dict[str, Any]]: ...
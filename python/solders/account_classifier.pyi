@@ -0,0 +1,33 @@
+from typing import List, Optional, Union
+
+from solders.account import Account
+from solders.anchor_idl import Idl
+from solders.pubkey import Pubkey
+from solders.rpc.filter import Memcmp, RpcFilterType
+from solders.rpc.responses import RpcKeyedAccount
+
+def anchor_account_discriminator(name: str) -> bytes: ...
+def discriminator_memcmp(discriminator: bytes, offset: int = 0) -> Memcmp: ...
+
+class AccountClassifier:
+    def __init__(self, include_native: bool = True) -> None: ...
+    def register(
+        self,
+        name: str,
+        discriminator: bytes,
+        owner: Optional[Pubkey] = None,
+        offset: int = 0,
+        data_size: Optional[int] = None,
+    ) -> None: ...
+    def register_anchor(self, name: str, owner: Optional[Pubkey] = None) -> None: ...
+    def add_idl(self, idl: Idl, owner: Optional[Pubkey] = None) -> None: ...
+    def names(self) -> List[str]: ...
+    def classify(
+        self,
+        account: Union[Account, RpcKeyedAccount, bytes],
+        owner: Optional[Pubkey] = None,
+    ) -> Optional[str]: ...
+    def memcmp(self, name: str) -> Memcmp: ...
+    def filters(self, name: str) -> List[RpcFilterType]: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, o: object) -> bool: ...
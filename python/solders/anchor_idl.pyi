@@ -0,0 +1,73 @@
+from typing import Any, Callable, Dict, List, Optional, Sequence, Tuple, Union
+
+from solders.bankrun import BanksTransactionMeta
+from solders.instruction import AccountMeta, Instruction
+from solders.instruction_decoder import InstructionNode
+from solders.pubkey import Pubkey
+from solders.transaction_status import UiTransactionStatusMeta
+
+class Idl:
+    @staticmethod
+    def from_json(raw: str) -> "Idl": ...
+    @property
+    def address(self) -> Optional[Pubkey]: ...
+    @property
+    def name(self) -> str: ...
+    @property
+    def version(self) -> str: ...
+    @property
+    def is_legacy(self) -> bool: ...
+    def instruction_names(self) -> List[str]: ...
+    def account_names(self) -> List[str]: ...
+    def event_names(self) -> List[str]: ...
+    def type_names(self) -> List[str]: ...
+    def instruction_discriminator(self, name: str) -> bytes: ...
+    def account_discriminator(self, name: str) -> bytes: ...
+    def encode_instruction_data(self, name: str, args: Dict[str, Any]) -> bytes: ...
+    def build_instruction(
+        self,
+        name: str,
+        args: Dict[str, Any],
+        accounts: Dict[str, Any],
+        remaining_accounts: Optional[Sequence[AccountMeta]] = None,
+        program_id: Optional[Pubkey] = None,
+    ) -> Instruction: ...
+    def decode_instruction(self, data: bytes) -> Tuple[str, Dict[str, Any]]: ...
+    def encode_account(self, name: str, value: Any) -> bytes: ...
+    def decode_account(
+        self, data: bytes, name: Optional[str] = None
+    ) -> Tuple[str, Any]: ...
+    def encode_type(self, name: str, value: Any) -> bytes: ...
+    def decode_type(self, name: str, data: bytes) -> Any: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, o: object) -> bool: ...
+
+class AnchorEvent:
+    def __init__(self, name: str, data: Any, program_id: Pubkey) -> None: ...
+    @property
+    def name(self) -> str: ...
+    @property
+    def data(self) -> Any: ...
+    @property
+    def program_id(self) -> Pubkey: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, o: object) -> bool: ...
+
+class EventDecoder:
+    def __init__(self) -> None: ...
+    def add_idl(self, idl: Idl, program_id: Optional[Pubkey] = None) -> None: ...
+    def register(
+        self,
+        discriminator: bytes,
+        name: str,
+        schema: Union[Dict[str, Any], str, Callable[[bytes], Any]],
+        program_id: Optional[Pubkey] = None,
+    ) -> None: ...
+    def decode_logs(self, log_messages: Sequence[str]) -> List[AnchorEvent]: ...
+    def decode_meta(
+        self, meta: Union[BanksTransactionMeta, UiTransactionStatusMeta]
+    ) -> List[AnchorEvent]: ...
+    def decode_instructions(
+        self, instructions: Sequence[Union[Instruction, InstructionNode]]
+    ) -> List[AnchorEvent]: ...
+    def __repr__(self) -> str: ...
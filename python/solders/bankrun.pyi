@@ -1,15 +1,22 @@
+from os import PathLike
 from pathlib import Path
-from typing import List, Optional, Sequence, Tuple, Union
+from typing import Dict, List, Optional, Sequence, Tuple, Union
 
 from solders.account import Account
+from solders.address_lookup_table_account import SlotHashes
 from solders.clock import Clock
 from solders.commitment_config import CommitmentLevel
+from solders.epoch_rewards import EpochRewards
+from solders.epoch_schedule import EpochSchedule
 from solders.hash import Hash
 from solders.keypair import Keypair
+from solders.log_parser import ParsedLogs
 from solders.message import Message
 from solders.pubkey import Pubkey
 from solders.rent import Rent
+from solders.rpc.filter import Memcmp, RpcFilterTypeFieldless
 from solders.signature import Signature
+from solders.stake_history import StakeHistory
 from solders.transaction import Transaction, VersionedTransaction
 from solders.transaction_status import (
     TransactionErrorType,
@@ -38,6 +45,17 @@ class BanksClient:
     async def get_latest_blockhash(
         self, commitment: Optional[CommitmentLevel] = None
     ) -> Tuple[Hash, int]: ...
+    async def get_multiple_accounts(
+        self,
+        addresses: Sequence[Pubkey],
+        commitment: Optional[CommitmentLevel] = None,
+    ) -> List[Optional[Account]]: ...
+    async def get_program_accounts(
+        self,
+        program_id: Pubkey,
+        filters: Optional[Sequence[Union[int, Memcmp, RpcFilterTypeFieldless]]] = None,
+        commitment: Optional[CommitmentLevel] = None,
+    ) -> List[Tuple[Pubkey, Account]]: ...
     async def get_rent(self) -> Rent: ...
     async def get_slot(self, commitment: Optional[CommitmentLevel] = None) -> int: ...
     async def get_transaction_status(
@@ -50,6 +68,10 @@ class BanksClient:
         self,
         transaction: Union[Transaction, VersionedTransaction],
     ) -> BanksTransactionMeta: ...
+    async def process_transaction_with_metadata(
+        self,
+        transaction: Union[Transaction, VersionedTransaction],
+    ) -> BanksTransactionResultWithMeta: ...
     async def send_transaction(
         self, transaction: Union[Transaction, VersionedTransaction]
     ) -> None: ...
@@ -59,6 +81,63 @@ class BanksClient:
         commitment: Optional[CommitmentLevel] = None,
     ) -> BanksTransactionResultWithMeta: ...
 
+class SyncBanksClient:
+    def get_account(
+        self, address: Pubkey, commitment: Optional[CommitmentLevel] = None
+    ) -> Optional[Account]: ...
+    def get_balance(
+        self,
+        address: Pubkey,
+        commitment: Optional[CommitmentLevel] = None,
+    ) -> int: ...
+    def get_block_height(
+        self, commitment: Optional[CommitmentLevel] = None
+    ) -> int: ...
+    def get_clock(self) -> Clock: ...
+    def get_fee_for_message(
+        self,
+        message: Message,
+        commitment: Optional[CommitmentLevel] = None,
+    ) -> Optional[int]: ...
+    def get_latest_blockhash(
+        self, commitment: Optional[CommitmentLevel] = None
+    ) -> Tuple[Hash, int]: ...
+    def get_multiple_accounts(
+        self,
+        addresses: Sequence[Pubkey],
+        commitment: Optional[CommitmentLevel] = None,
+    ) -> List[Optional[Account]]: ...
+    def get_program_accounts(
+        self,
+        program_id: Pubkey,
+        filters: Optional[Sequence[Union[int, Memcmp, RpcFilterTypeFieldless]]] = None,
+        commitment: Optional[CommitmentLevel] = None,
+    ) -> List[Tuple[Pubkey, Account]]: ...
+    def get_rent(self) -> Rent: ...
+    def get_slot(self, commitment: Optional[CommitmentLevel] = None) -> int: ...
+    def get_transaction_status(
+        self, signature: Signature
+    ) -> Optional[TransactionStatus]: ...
+    def get_transaction_statuses(
+        self, signatures: Sequence[Signature]
+    ) -> List[Optional[TransactionStatus]]: ...
+    def process_transaction(
+        self,
+        transaction: Union[Transaction, VersionedTransaction],
+    ) -> BanksTransactionMeta: ...
+    def process_transaction_with_metadata(
+        self,
+        transaction: Union[Transaction, VersionedTransaction],
+    ) -> BanksTransactionResultWithMeta: ...
+    def send_transaction(
+        self, transaction: Union[Transaction, VersionedTransaction]
+    ) -> None: ...
+    def simulate_transaction(
+        self,
+        transaction: Union[Transaction, VersionedTransaction],
+        commitment: Optional[CommitmentLevel] = None,
+    ) -> BanksTransactionResultWithMeta: ...
+
 class BanksClientError(Exception): ...
 
 class BanksTransactionMeta:
@@ -68,6 +147,7 @@ class BanksTransactionMeta:
     def log_messages(self) -> List[str]: ...
     @property
     def return_data(self) -> Optional[TransactionReturnData]: ...
+    def parse_logs(self) -> ParsedLogs: ...
     def __init__(
         self,
         compute_units_consumed: int,
@@ -104,10 +184,17 @@ class BanksTransactionResultWithMeta:
     def __repr__(self) -> str: ...
     def __richcmp__(self, other: "BanksTransactionResultWithMeta", op: int) -> bool: ...
 
+class BankSnapshot:
+    @property
+    def num_recorded_accounts(self) -> int: ...
+    def __repr__(self) -> str: ...
+
 class ProgramTestContext:
     @property
     def banks_client(self) -> BanksClient: ...
     @property
+    def sync_banks_client(self) -> SyncBanksClient: ...
+    @property
     def last_blockhash(self) -> Hash: ...
     @property
     def payer(self) -> Keypair: ...
@@ -117,20 +204,69 @@ class ProgramTestContext:
         number_of_credits: int,
     ) -> None: ...
     def set_account(self, address: Pubkey, account: Account) -> None: ...
+    def get_active_features(self) -> Dict[Pubkey, int]: ...
+    def load_accounts(
+        self, path: Union[str, PathLike]
+    ) -> List[Tuple[Pubkey, Account]]: ...
+    def dump_account(self, address: Pubkey, path: Union[str, PathLike]) -> str: ...
+    def add_program(
+        self,
+        program_id: Pubkey,
+        program: Union[bytes, str, PathLike],
+        upgradeable: bool = False,
+        upgrade_authority: Optional[Pubkey] = None,
+    ) -> None: ...
     def set_clock(self, clock: Clock) -> None: ...
     def set_rent(self, rent: Rent) -> None: ...
+    def set_epoch_schedule(self, epoch_schedule: EpochSchedule) -> None: ...
+    def set_epoch_rewards(self, epoch_rewards: EpochRewards) -> None: ...
+    def set_stake_history(self, stake_history: StakeHistory) -> None: ...
+    def set_slot_hashes(self, slot_hashes: SlotHashes) -> None: ...
     def warp_to_slot(self, warp_slot: int) -> None: ...
+    def warp_to_epoch(self, warp_epoch: int) -> None: ...
+    def warp_forward_force_reward_interval_end(self) -> None: ...
+    def snapshot(self) -> BankSnapshot: ...
+    def restore(self, snapshot: BankSnapshot) -> None: ...
 
 async def start(
     programs: Optional[Sequence[Tuple[str, Pubkey]]] = None,
-    accounts: Optional[Sequence[Tuple[Pubkey, Account]]] = None,
+    accounts: Optional[
+        Sequence[Union[Tuple[Pubkey, Account], str, PathLike]]
+    ] = None,
     compute_max_units: Optional[int] = None,
     transaction_account_lock_limit: Optional[int] = None,
+    payer: Optional[Keypair] = None,
+    payer_lamports: Optional[int] = None,
+    rent: Optional[Rent] = None,
+    activated_features: Optional[Sequence[Pubkey]] = None,
+    deactivated_features: Optional[Sequence[Pubkey]] = None,
 ) -> ProgramTestContext: ...
 async def start_anchor(
     path: Path,
     extra_programs: Optional[Sequence[Tuple[str, Pubkey]]] = None,
-    accounts: Optional[Sequence[Tuple[Pubkey, Account]]] = None,
+    accounts: Optional[
+        Sequence[Union[Tuple[Pubkey, Account], str, PathLike]]
+    ] = None,
+    compute_max_units: Optional[int] = None,
+    transaction_account_lock_limit: Optional[int] = None,
+    cluster: str = "localnet",
+    clone_dir: Optional[Path] = None,
+    payer: Optional[Keypair] = None,
+    payer_lamports: Optional[int] = None,
+    rent: Optional[Rent] = None,
+    activated_features: Optional[Sequence[Pubkey]] = None,
+    deactivated_features: Optional[Sequence[Pubkey]] = None,
+) -> ProgramTestContext: ...
+def start_sync(
+    programs: Optional[Sequence[Tuple[str, Pubkey]]] = None,
+    accounts: Optional[
+        Sequence[Union[Tuple[Pubkey, Account], str, PathLike]]
+    ] = None,
     compute_max_units: Optional[int] = None,
     transaction_account_lock_limit: Optional[int] = None,
+    payer: Optional[Keypair] = None,
+    payer_lamports: Optional[int] = None,
+    rent: Optional[Rent] = None,
+    activated_features: Optional[Sequence[Pubkey]] = None,
+    deactivated_features: Optional[Sequence[Pubkey]] = None,
 ) -> ProgramTestContext: ...
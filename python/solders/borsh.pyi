@@ -0,0 +1,12 @@
+from typing import Any, Dict, Optional, Union
+
+class BorshSchema:
+    def __init__(
+        self,
+        schema: Union[str, Dict[str, Any]],
+        types: Optional[Dict[str, Dict[str, Any]]] = None,
+    ) -> None: ...
+    def encode(self, value: Any) -> bytes: ...
+    def decode(self, data: bytes, strict: bool = True) -> Any: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, o: object) -> bool: ...
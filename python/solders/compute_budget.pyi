@@ -1,3 +1,5 @@
+from typing import Any, Dict, Tuple
+
 from solders.instruction import Instruction
 from solders.pubkey import Pubkey
 
@@ -6,3 +8,4 @@ ID: Pubkey
 def request_heap_frame(bytes_: int) -> Instruction: ...
 def set_compute_unit_limit(units: int) -> Instruction: ...
 def set_compute_unit_price(micro_lamports: int) -> Instruction: ...
+def decode_instruction(instruction: Instruction) -> Tuple[str, Dict[str, Any]]: ...
@@ -0,0 +1,44 @@
+from typing import List, Optional
+
+from solders.pubkey import Pubkey
+
+class ResolvedError:
+    def __init__(
+        self, program_id: Pubkey, code: int, name: str, message: str
+    ) -> None: ...
+    @property
+    def program_id(self) -> Pubkey: ...
+    @property
+    def code(self) -> int: ...
+    @property
+    def name(self) -> str: ...
+    @property
+    def message(self) -> str: ...
+    def describe(self) -> str: ...
+    def __bytes__(self) -> bytes: ...
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
+    def __eq__(self, other: object) -> bool: ...
+    @staticmethod
+    def from_bytes(raw: bytes) -> "ResolvedError": ...
+    def to_json(self) -> str: ...
+    @staticmethod
+    def from_json(raw: str) -> "ResolvedError": ...
+
+class CustomErrorResolver:
+    def __init__(self) -> None: ...
+    @staticmethod
+    def empty() -> "CustomErrorResolver": ...
+    def register(self, program_id: Pubkey, code: int, name: str, message: str) -> None: ...
+    def register_anchor_program(self, program_id: Pubkey) -> None: ...
+    def register_anchor_idl(
+        self, idl: str, program_id: Optional[Pubkey] = None
+    ) -> Pubkey: ...
+    def unregister(self, program_id: Pubkey) -> bool: ...
+    def program_ids(self) -> List[Pubkey]: ...
+    def resolve(self, program_id: Pubkey, code: int) -> Optional[ResolvedError]: ...
+    def __contains__(self, program_id: Pubkey) -> bool: ...
+    def __len__(self) -> int: ...
+
+def get_default_resolver() -> CustomErrorResolver: ...
+def set_default_resolver(resolver: CustomErrorResolver) -> None: ...
@@ -0,0 +1,22 @@
+class EpochRewards:
+    def __init__(
+        self,
+        total_rewards: int,
+        distributed_rewards: int,
+        distribution_complete_block_height: int,
+    ) -> None: ...
+    @property
+    def total_rewards(self) -> int: ...
+    @property
+    def distributed_rewards(self) -> int: ...
+    @property
+    def distribution_complete_block_height(self) -> int: ...
+    def __bytes__(self) -> bytes: ...
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
+    def __richcmp__(self, other: "EpochRewards", op: int) -> bool: ...
+    @staticmethod
+    def from_bytes(raw_bytes: bytes) -> "EpochRewards": ...
+    def to_json(self) -> str: ...
+    @staticmethod
+    def from_json(raw: str) -> "EpochRewards": ...
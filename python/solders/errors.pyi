@@ -2,3 +2,4 @@ class SignerError(Exception): ...
 class BincodeError(Exception): ...
 class CborError(Exception): ...
 class SerdeJSONError(Exception): ...
+class InstructionDecodeError(Exception): ...
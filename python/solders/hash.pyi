@@ -18,6 +18,9 @@ class Hash:
     def __hash__(self) -> int: ...
     @staticmethod
     def from_bytes(raw_bytes: bytes) -> "Hash": ...
+    def to_borsh(self) -> bytes: ...
+    @staticmethod
+    def from_borsh(data: bytes) -> "Hash": ...
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> "Hash": ...
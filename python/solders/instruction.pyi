@@ -17,6 +17,9 @@ class AccountMeta:
     def __bytes__(self) -> bytes: ...
     @staticmethod
     def from_bytes(data: bytes) -> "AccountMeta": ...
+    def to_borsh(self) -> bytes: ...
+    @staticmethod
+    def from_borsh(data: bytes) -> "AccountMeta": ...
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> "AccountMeta": ...
@@ -39,6 +42,9 @@ class Instruction:
     def __bytes__(self) -> bytes: ...
     @staticmethod
     def from_bytes(data: bytes) -> "Instruction": ...
+    def to_borsh(self) -> bytes: ...
+    @staticmethod
+    def from_borsh(data: bytes) -> "Instruction": ...
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> "Instruction": ...
@@ -0,0 +1,104 @@
+from typing import Any, Callable, List, Optional, Sequence, Tuple, Union
+
+from solders.instruction import Instruction
+from solders.log_parser import ParsedLogs
+from solders.pubkey import Pubkey
+from solders.transaction import Transaction, VersionedTransaction
+from solders.transaction_status import EncodedTransactionWithStatusMeta, UiTransaction
+
+class DecodedInstruction:
+    def __init__(
+        self,
+        program_id: Pubkey,
+        name: str,
+        params: Any,
+        accounts: Sequence[Pubkey],
+    ) -> None: ...
+    @property
+    def program_id(self) -> Pubkey: ...
+    @property
+    def name(self) -> str: ...
+    @property
+    def params(self) -> Any: ...
+    @property
+    def accounts(self) -> List[Pubkey]: ...
+
+class UnknownInstruction:
+    def __init__(
+        self,
+        program_id: Pubkey,
+        data: bytes,
+        accounts: Sequence[Pubkey],
+        error: Optional[str] = None,
+    ) -> None: ...
+    def __eq__(self, other: object) -> bool: ...
+    @property
+    def program_id(self) -> Pubkey: ...
+    @property
+    def data(self) -> bytes: ...
+    @property
+    def accounts(self) -> List[Pubkey]: ...
+    @property
+    def error(self) -> Optional[str]: ...
+
+class InstructionDecoderRegistry:
+    def __init__(self) -> None: ...
+    @staticmethod
+    def empty() -> "InstructionDecoderRegistry": ...
+    def register(
+        self,
+        program_id: Pubkey,
+        decoder: Callable[[bytes, List[Pubkey]], Tuple[str, Any]],
+    ) -> None: ...
+    def unregister(self, program_id: Pubkey) -> bool: ...
+    def program_ids(self) -> List[Pubkey]: ...
+    def __contains__(self, program_id: Pubkey) -> bool: ...
+    def __len__(self) -> int: ...
+    def decode_instruction(
+        self, instruction: Instruction
+    ) -> Union[DecodedInstruction, UnknownInstruction]: ...
+    def decode_transaction(
+        self,
+        tx: Union[Transaction, VersionedTransaction, UiTransaction],
+        loaded_addresses: Optional[Tuple[Sequence[Pubkey], Sequence[Pubkey]]] = None,
+    ) -> List[Union[DecodedInstruction, UnknownInstruction]]: ...
+
+class InstructionNode:
+    def __init__(
+        self,
+        program_id: Pubkey,
+        accounts: Sequence[Pubkey],
+        data: Optional[bytes],
+        stack_height: int,
+        children: Sequence["InstructionNode"],
+    ) -> None: ...
+    @property
+    def program_id(self) -> Pubkey: ...
+    @property
+    def accounts(self) -> List[Pubkey]: ...
+    @property
+    def data(self) -> Optional[bytes]: ...
+    @property
+    def stack_height(self) -> int: ...
+    @property
+    def children(self) -> List["InstructionNode"]: ...
+    def walk_all(self) -> List["InstructionNode"]: ...
+    def find(self, program_id: Pubkey) -> List["InstructionNode"]: ...
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
+    def __bytes__(self) -> bytes: ...
+    def __eq__(self, other: object) -> bool: ...
+    @staticmethod
+    def from_bytes(raw: bytes) -> "InstructionNode": ...
+    def to_json(self) -> str: ...
+    @staticmethod
+    def from_json(raw: str) -> "InstructionNode": ...
+
+def build_instruction_tree(
+    tx: EncodedTransactionWithStatusMeta,
+) -> List[InstructionNode]: ...
+def build_instruction_tree_from_logs(
+    tx: Union[Transaction, VersionedTransaction],
+    logs: ParsedLogs,
+    loaded_addresses: Optional[Tuple[Sequence[Pubkey], Sequence[Pubkey]]] = None,
+) -> List[InstructionNode]: ...
@@ -0,0 +1,80 @@
+from typing import List, Optional, Sequence
+
+from solders.pubkey import Pubkey
+
+class ProgramInvocation:
+    def __init__(
+        self,
+        program_id: Pubkey,
+        depth: int,
+        logs: Sequence[str],
+        data: Sequence[Sequence[bytes]],
+        other_logs: Sequence[str],
+        inner: Sequence["ProgramInvocation"],
+        compute_units_consumed: Optional[int] = None,
+        compute_units_budget: Optional[int] = None,
+        return_data: Optional[bytes] = None,
+        success: Optional[bool] = None,
+        error: Optional[str] = None,
+    ) -> None: ...
+    @property
+    def program_id(self) -> Pubkey: ...
+    @property
+    def depth(self) -> int: ...
+    @property
+    def logs(self) -> List[str]: ...
+    @property
+    def data(self) -> List[List[bytes]]: ...
+    @property
+    def other_logs(self) -> List[str]: ...
+    @property
+    def inner(self) -> List["ProgramInvocation"]: ...
+    @property
+    def compute_units_consumed(self) -> Optional[int]: ...
+    @property
+    def compute_units_budget(self) -> Optional[int]: ...
+    @property
+    def return_data(self) -> Optional[bytes]: ...
+    @property
+    def success(self) -> Optional[bool]: ...
+    @property
+    def error(self) -> Optional[str]: ...
+    def flatten(self) -> List["ProgramInvocation"]: ...
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
+    def __bytes__(self) -> bytes: ...
+    def __eq__(self, other: object) -> bool: ...
+    @staticmethod
+    def from_bytes(data: bytes) -> "ProgramInvocation": ...
+    def to_json(self) -> str: ...
+    @staticmethod
+    def from_json(raw: str) -> "ProgramInvocation": ...
+
+class ParsedLogs:
+    def __init__(
+        self,
+        invocations: Sequence[ProgramInvocation],
+        other_logs: Sequence[str],
+        truncated: bool,
+    ) -> None: ...
+    @property
+    def invocations(self) -> List[ProgramInvocation]: ...
+    @property
+    def other_logs(self) -> List[str]: ...
+    @property
+    def truncated(self) -> bool: ...
+    @property
+    def success(self) -> Optional[bool]: ...
+    def flatten(self) -> List[ProgramInvocation]: ...
+    def program_logs(self, program_id: Optional[Pubkey] = None) -> List[str]: ...
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
+    def __bytes__(self) -> bytes: ...
+    def __eq__(self, other: object) -> bool: ...
+    @staticmethod
+    def from_bytes(data: bytes) -> "ParsedLogs": ...
+    def to_json(self) -> str: ...
+    @staticmethod
+    def from_json(raw: str) -> "ParsedLogs": ...
+
+def parse_logs(log_messages: Sequence[str]) -> ParsedLogs: ...
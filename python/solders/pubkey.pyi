@@ -29,6 +29,9 @@ class Pubkey:
     def __hash__(self) -> int: ...
     @staticmethod
     def from_bytes(raw: bytes) -> "Pubkey": ...
+    def to_borsh(self) -> bytes: ...
+    @staticmethod
+    def from_borsh(data: bytes) -> "Pubkey": ...
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> "Pubkey": ...
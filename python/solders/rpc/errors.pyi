@@ -1,5 +1,6 @@
-from typing import Optional, Union
+from typing import NoReturn, Optional, Union
 
+from solders.custom_error import CustomErrorResolver
 from solders.rpc.responses import RpcSimulateTransactionResult
 from solders.transaction_status import TransactionErrorType
 
@@ -21,6 +22,7 @@ class BlockCleanedUp:
 class BlockCleanedUpMessage:
     message: str
     def __init__(self, message: str) -> None: ...
+    def raise_for_error(self) -> NoReturn: ...
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> "BlockCleanedUpMessage": ...
@@ -36,6 +38,7 @@ class SendTransactionPreflightFailure:
     message: str
     result: RpcSimulateTransactionResult
     def __init__(self, message: str, data: RpcSimulateTransactionResult) -> None: ...
+    def describe(self, resolver: Optional[CustomErrorResolver] = None) -> str: ...
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> "SendTransactionPreflightFailure": ...
@@ -51,6 +54,7 @@ class SendTransactionPreflightFailureMessage:
     message: str
     data: RpcSimulateTransactionResult
     def __init__(self, message: str, data: RpcSimulateTransactionResult) -> None: ...
+    def raise_for_error(self) -> NoReturn: ...
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> "SendTransactionPreflightFailureMessage": ...
@@ -68,6 +72,7 @@ class RpcCustomErrorFieldless:
     TransactionHistoryNotAvailable: "RpcCustomErrorFieldless"
     TransactionSignatureLenMismatch: "RpcCustomErrorFieldless"
     Base64Zstd: "RpcCustomErrorFieldless"
+    def raise_for_error(self) -> NoReturn: ...
     def __int__(self) -> int: ...
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
@@ -90,6 +95,7 @@ class BlockNotAvailable:
 class BlockNotAvailableMessage:
     message: str
     def __init__(self, message: str) -> None: ...
+    def raise_for_error(self) -> NoReturn: ...
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> "BlockNotAvailableMessage": ...
@@ -119,6 +125,7 @@ class NodeUnhealthyMessage:
     message: str
     data: NodeUnhealthy
     def __init__(self, message: str, data: NodeUnhealthy) -> None: ...
+    def raise_for_error(self) -> NoReturn: ...
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> "NodeUnhealthyMessage": ...
@@ -147,6 +154,7 @@ class TransactionPrecompileVerificationFailure:
 class TransactionPrecompileVerificationFailureMessage:
     message: str
     def __init__(self, message: str) -> None: ...
+    def raise_for_error(self) -> NoReturn: ...
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> "TransactionPrecompileVerificationFailureMessage": ...
@@ -177,6 +185,7 @@ class SlotSkipped:
 class SlotSkippedMessage:
     message: str
     def __init__(self, message: str) -> None: ...
+    def raise_for_error(self) -> NoReturn: ...
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> "SlotSkippedMessage": ...
@@ -205,6 +214,7 @@ class LongTermStorageSlotSkipped:
 class LongTermStorageSlotSkippedMessage:
     message: str
     def __init__(self, message: str) -> None: ...
+    def raise_for_error(self) -> NoReturn: ...
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> "LongTermStorageSlotSkippedMessage": ...
@@ -233,6 +243,7 @@ class KeyExcludedFromSecondaryIndex:
 class KeyExcludedFromSecondaryIndexMessage:
     message: str
     def __init__(self, message: str) -> None: ...
+    def raise_for_error(self) -> NoReturn: ...
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> "KeyExcludedFromSecondaryIndexMessage": ...
@@ -261,6 +272,7 @@ class ScanError:
 class ScanErrorMessage:
     message: str
     def __init__(self, message: str) -> None: ...
+    def raise_for_error(self) -> NoReturn: ...
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> "ScanErrorMessage": ...
@@ -289,6 +301,7 @@ class BlockStatusNotAvailableYet:
 class BlockStatusNotAvailableYetMessage:
     message: str
     def __init__(self, message: str) -> None: ...
+    def raise_for_error(self) -> NoReturn: ...
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> "BlockStatusNotAvailableYetMessage": ...
@@ -318,6 +331,7 @@ class MinContextSlotNotReachedMessage:
     message: str
     data: MinContextSlotNotReached
     def __init__(self, message: str, data: MinContextSlotNotReached) -> None: ...
+    def raise_for_error(self) -> NoReturn: ...
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> "MinContextSlotNotReachedMessage": ...
@@ -346,6 +360,7 @@ class UnsupportedTransactionVersion:
 class UnsupportedTransactionVersionMessage:
     message: str
     def __init__(self, message: str) -> None: ...
+    def raise_for_error(self) -> NoReturn: ...
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> "UnsupportedTransactionVersionMessage": ...
@@ -360,6 +375,7 @@ class UnsupportedTransactionVersionMessage:
 class ParseErrorMessage:
     message: str
     def __init__(self, message: str) -> None: ...
+    def raise_for_error(self) -> NoReturn: ...
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> "ParseErrorMessage": ...
@@ -374,6 +390,7 @@ class ParseErrorMessage:
 class InvalidRequestMessage:
     message: str
     def __init__(self, message: str) -> None: ...
+    def raise_for_error(self) -> NoReturn: ...
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> "InvalidRequestMessage": ...
@@ -388,6 +405,7 @@ class InvalidRequestMessage:
 class MethodNotFoundMessage:
     message: str
     def __init__(self, message: str) -> None: ...
+    def raise_for_error(self) -> NoReturn: ...
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> "MethodNotFoundMessage": ...
@@ -402,6 +420,7 @@ class MethodNotFoundMessage:
 class InvalidParamsMessage:
     message: str
     def __init__(self, message: str) -> None: ...
+    def raise_for_error(self) -> NoReturn: ...
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> "InvalidParamsMessage": ...
@@ -416,6 +435,7 @@ class InvalidParamsMessage:
 class InternalErrorMessage:
     message: str
     def __init__(self, message: str) -> None: ...
+    def raise_for_error(self) -> NoReturn: ...
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> "InternalErrorMessage": ...
@@ -443,3 +463,30 @@ RpcCustomError = Union[
     MinContextSlotNotReached,
     UnsupportedTransactionVersion,
 ]
+
+class RpcError(Exception):
+    error: object
+    data: Optional[object]
+
+class RpcParseError(RpcError): ...
+class RpcInvalidRequest(RpcError): ...
+class RpcMethodNotFound(RpcError): ...
+class RpcInvalidParams(RpcError): ...
+class RpcInternalError(RpcError): ...
+class RpcTransactionSignatureVerificationFailure(RpcError): ...
+class RpcNoSnapshot(RpcError): ...
+class RpcTransactionHistoryNotAvailable(RpcError): ...
+class RpcTransactionSignatureLenMismatch(RpcError): ...
+class RpcBlockCleanedUp(RpcError): ...
+class RpcPreflightFailure(RpcError): ...
+class RpcBlockhashNotFound(RpcPreflightFailure): ...
+class RpcBlockNotAvailable(RpcError): ...
+class RpcNodeUnhealthy(RpcError): ...
+class RpcTransactionPrecompileVerificationFailure(RpcError): ...
+class RpcSlotSkipped(RpcError): ...
+class RpcLongTermStorageSlotSkipped(RpcError): ...
+class RpcKeyExcludedFromSecondaryIndex(RpcError): ...
+class RpcScanError(RpcError): ...
+class RpcBlockStatusNotAvailableYet(RpcError): ...
+class RpcMinContextSlotNotReached(RpcError): ...
+class RpcUnsupportedTransactionVersion(RpcError): ...
@@ -78,6 +78,7 @@ class GetAccountInfoResp:
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> Resp[GetAccountInfoResp]: ...
+    def raise_for_error(self) -> "GetAccountInfoResp": ...
     @staticmethod
     def from_bytes(data: bytes) -> GetAccountInfoResp: ...
     def __str__(self) -> str: ...
@@ -95,6 +96,7 @@ class GetAccountInfoJsonParsedResp:
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> Resp[GetAccountInfoJsonParsedResp]: ...
+    def raise_for_error(self) -> "GetAccountInfoJsonParsedResp": ...
     @staticmethod
     def from_bytes(data: bytes) -> GetAccountInfoJsonParsedResp: ...
     def __str__(self) -> str: ...
@@ -112,6 +114,7 @@ class GetAccountInfoMaybeJsonParsedResp:
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> Resp[GetAccountInfoMaybeJsonParsedResp]: ...
+    def raise_for_error(self) -> "GetAccountInfoMaybeJsonParsedResp": ...
     @staticmethod
     def from_bytes(data: bytes) -> GetAccountInfoMaybeJsonParsedResp: ...
     def __str__(self) -> str: ...
@@ -127,6 +130,7 @@ class GetBalanceResp:
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> Resp[GetBalanceResp]: ...
+    def raise_for_error(self) -> "GetBalanceResp": ...
     @staticmethod
     def from_bytes(data: bytes) -> GetBalanceResp: ...
     def __str__(self) -> str: ...
@@ -159,6 +163,7 @@ class GetBlockCommitmentResp:
     def value(self) -> RpcBlockCommitment: ...
     @staticmethod
     def from_json(raw: str) -> Resp[GetBlockCommitmentResp]: ...
+    def raise_for_error(self) -> "GetBlockCommitmentResp": ...
     @staticmethod
     def from_bytes(data: bytes) -> GetBlockCommitmentResp: ...
     def __str__(self) -> str: ...
@@ -174,6 +179,7 @@ class GetBlockHeightResp:
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> Resp[GetBlockHeightResp]: ...
+    def raise_for_error(self) -> "GetBlockHeightResp": ...
     @staticmethod
     def from_bytes(data: bytes) -> GetBlockHeightResp: ...
     def __str__(self) -> str: ...
@@ -235,6 +241,7 @@ class GetBlockProductionResp:
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> Resp[GetBlockProductionResp]: ...
+    def raise_for_error(self) -> "GetBlockProductionResp": ...
     @staticmethod
     def from_bytes(data: bytes) -> GetBlockProductionResp: ...
     def __str__(self) -> str: ...
@@ -250,6 +257,7 @@ class GetBlockResp:
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> Resp[GetBlockResp]: ...
+    def raise_for_error(self) -> "GetBlockResp": ...
     @staticmethod
     def from_bytes(data: bytes) -> GetBlockResp: ...
     def __str__(self) -> str: ...
@@ -265,6 +273,7 @@ class GetBlocksResp:
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> Resp[GetBlocksResp]: ...
+    def raise_for_error(self) -> "GetBlocksResp": ...
     @staticmethod
     def from_bytes(data: bytes) -> GetBlocksResp: ...
     def __str__(self) -> str: ...
@@ -280,6 +289,7 @@ class GetBlocksWithLimitResp:
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> Resp[GetBlocksWithLimitResp]: ...
+    def raise_for_error(self) -> "GetBlocksWithLimitResp": ...
     @staticmethod
     def from_bytes(data: bytes) -> GetBlocksWithLimitResp: ...
     def __str__(self) -> str: ...
@@ -295,6 +305,7 @@ class GetBlockTimeResp:
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> Resp[GetBlockTimeResp]: ...
+    def raise_for_error(self) -> "GetBlockTimeResp": ...
     @staticmethod
     def from_bytes(data: bytes) -> GetBlockTimeResp: ...
     def __str__(self) -> str: ...
@@ -343,6 +354,7 @@ class GetClusterNodesResp:
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> Resp[GetClusterNodesResp]: ...
+    def raise_for_error(self) -> "GetClusterNodesResp": ...
     @staticmethod
     def from_bytes(data: bytes) -> GetClusterNodesResp: ...
     def __str__(self) -> str: ...
@@ -358,6 +370,7 @@ class GetEpochInfoResp:
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> Resp[GetEpochInfoResp]: ...
+    def raise_for_error(self) -> "GetEpochInfoResp": ...
     @staticmethod
     def from_bytes(data: bytes) -> GetEpochInfoResp: ...
     def __str__(self) -> str: ...
@@ -373,6 +386,7 @@ class GetEpochScheduleResp:
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> Resp[GetEpochScheduleResp]: ...
+    def raise_for_error(self) -> "GetEpochScheduleResp": ...
     @staticmethod
     def from_bytes(data: bytes) -> GetEpochScheduleResp: ...
     def __str__(self) -> str: ...
@@ -388,6 +402,7 @@ class GetFeeForMessageResp:
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> Resp[GetFeeForMessageResp]: ...
+    def raise_for_error(self) -> "GetFeeForMessageResp": ...
     @staticmethod
     def from_bytes(data: bytes) -> GetFeeForMessageResp: ...
     def __str__(self) -> str: ...
@@ -403,6 +418,7 @@ class GetFirstAvailableBlockResp:
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> Resp[GetFirstAvailableBlockResp]: ...
+    def raise_for_error(self) -> "GetFirstAvailableBlockResp": ...
     @staticmethod
     def from_bytes(data: bytes) -> GetFirstAvailableBlockResp: ...
     def __str__(self) -> str: ...
@@ -418,6 +434,7 @@ class GetGenesisHashResp:
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> Resp[GetGenesisHashResp]: ...
+    def raise_for_error(self) -> "GetGenesisHashResp": ...
     @staticmethod
     def from_bytes(data: bytes) -> GetGenesisHashResp: ...
     def __str__(self) -> str: ...
@@ -433,6 +450,7 @@ class GetHealthResp:
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> Resp[GetHealthResp]: ...
+    def raise_for_error(self) -> "GetHealthResp": ...
     @staticmethod
     def from_bytes(data: bytes) -> GetHealthResp: ...
     def __str__(self) -> str: ...
@@ -492,6 +510,7 @@ class GetHighestSnapshotSlotResp:
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> Resp[GetHighestSnapshotSlotResp]: ...
+    def raise_for_error(self) -> "GetHighestSnapshotSlotResp": ...
     @staticmethod
     def from_bytes(data: bytes) -> GetHighestSnapshotSlotResp: ...
     def __str__(self) -> str: ...
@@ -521,6 +540,7 @@ class GetIdentityResp:
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> Resp[GetIdentityResp]: ...
+    def raise_for_error(self) -> "GetIdentityResp": ...
     @staticmethod
     def from_bytes(data: bytes) -> GetIdentityResp: ...
     def __str__(self) -> str: ...
@@ -565,6 +585,7 @@ class GetInflationGovernorResp:
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> Resp[GetInflationGovernorResp]: ...
+    def raise_for_error(self) -> "GetInflationGovernorResp": ...
     @staticmethod
     def from_bytes(data: bytes) -> GetInflationGovernorResp: ...
     def __str__(self) -> str: ...
@@ -604,6 +625,7 @@ class GetInflationRateResp:
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> Resp[GetInflationRateResp]: ...
+    def raise_for_error(self) -> "GetInflationRateResp": ...
     @staticmethod
     def from_bytes(data: bytes) -> GetInflationRateResp: ...
     def __str__(self) -> str: ...
@@ -648,6 +670,7 @@ class GetInflationRewardResp:
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> Resp[GetInflationRewardResp]: ...
+    def raise_for_error(self) -> "GetInflationRewardResp": ...
     @staticmethod
     def from_bytes(data: bytes) -> GetInflationRewardResp: ...
     def __str__(self) -> str: ...
@@ -685,6 +708,7 @@ class GetLargestAccountsResp:
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> Resp[GetLargestAccountsResp]: ...
+    def raise_for_error(self) -> "GetLargestAccountsResp": ...
     @staticmethod
     def from_bytes(data: bytes) -> GetLargestAccountsResp: ...
     def __str__(self) -> str: ...
@@ -715,6 +739,7 @@ class GetLatestBlockhashResp:
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> Resp[GetLatestBlockhashResp]: ...
+    def raise_for_error(self) -> "GetLatestBlockhashResp": ...
     @staticmethod
     def from_bytes(data: bytes) -> GetLatestBlockhashResp: ...
     def __str__(self) -> str: ...
@@ -730,6 +755,7 @@ class GetLeaderScheduleResp:
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> Resp[GetLatestBlockhashResp]: ...
+    def raise_for_error(self) -> "GetLatestBlockhashResp": ...
     @staticmethod
     def from_bytes(data: bytes) -> GetLatestBlockhashResp: ...
     def __str__(self) -> str: ...
@@ -745,6 +771,7 @@ class GetMaxRetransmitSlotResp:
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> Resp[GetMaxRetransmitSlotResp]: ...
+    def raise_for_error(self) -> "GetMaxRetransmitSlotResp": ...
     @staticmethod
     def from_bytes(data: bytes) -> GetMaxRetransmitSlotResp: ...
     def __str__(self) -> str: ...
@@ -760,6 +787,7 @@ class GetMaxShredInsertSlotResp:
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> Resp[GetMaxShredInsertSlotResp]: ...
+    def raise_for_error(self) -> "GetMaxShredInsertSlotResp": ...
     @staticmethod
     def from_bytes(data: bytes) -> GetMaxShredInsertSlotResp: ...
     def __str__(self) -> str: ...
@@ -775,6 +803,7 @@ class GetMinimumBalanceForRentExemptionResp:
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> Resp[GetMinimumBalanceForRentExemptionResp]: ...
+    def raise_for_error(self) -> "GetMinimumBalanceForRentExemptionResp": ...
     @staticmethod
     def from_bytes(data: bytes) -> GetMinimumBalanceForRentExemptionResp: ...
     def __str__(self) -> str: ...
@@ -792,6 +821,7 @@ class GetMultipleAccountsResp:
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> Resp[GetAccountInfoResp]: ...
+    def raise_for_error(self) -> "GetAccountInfoResp": ...
     @staticmethod
     def from_bytes(data: bytes) -> GetAccountInfoResp: ...
     def __str__(self) -> str: ...
@@ -809,6 +839,7 @@ class GetMultipleAccountsJsonParsedResp:
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> Resp[GetMultipleAccountsJsonParsedResp]: ...
+    def raise_for_error(self) -> "GetMultipleAccountsJsonParsedResp": ...
     @staticmethod
     def from_bytes(data: bytes) -> GetMultipleAccountsJsonParsedResp: ...
     def __str__(self) -> str: ...
@@ -828,6 +859,7 @@ class GetMultipleAccountsMaybeJsonParsedResp:
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> Resp[GetMultipleAccountsMaybeJsonParsedResp]: ...
+    def raise_for_error(self) -> "GetMultipleAccountsMaybeJsonParsedResp": ...
     @staticmethod
     def from_bytes(data: bytes) -> GetMultipleAccountsMaybeJsonParsedResp: ...
     def __str__(self) -> str: ...
@@ -875,6 +907,7 @@ class GetProgramAccountsWithContextResp:
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> Resp[GetProgramAccountsWithContextResp]: ...
+    def raise_for_error(self) -> "GetProgramAccountsWithContextResp": ...
     @staticmethod
     def from_bytes(data: bytes) -> GetProgramAccountsWithContextResp: ...
     def __str__(self) -> str: ...
@@ -892,6 +925,7 @@ class GetProgramAccountsWithContextJsonParsedResp:
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> Resp[GetProgramAccountsWithContextJsonParsedResp]: ...
+    def raise_for_error(self) -> "GetProgramAccountsWithContextJsonParsedResp": ...
     @staticmethod
     def from_bytes(data: bytes) -> GetProgramAccountsWithContextJsonParsedResp: ...
     def __str__(self) -> str: ...
@@ -907,6 +941,7 @@ class GetProgramAccountsResp:
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> Resp[GetProgramAccountsResp]: ...
+    def raise_for_error(self) -> "GetProgramAccountsResp": ...
     @staticmethod
     def from_bytes(data: bytes) -> GetProgramAccountsResp: ...
     def __str__(self) -> str: ...
@@ -922,6 +957,7 @@ class GetProgramAccountsJsonParsedResp:
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> Resp[GetProgramAccountsJsonParsedResp]: ...
+    def raise_for_error(self) -> "GetProgramAccountsJsonParsedResp": ...
     @staticmethod
     def from_bytes(data: bytes) -> GetProgramAccountsJsonParsedResp: ...
     def __str__(self) -> str: ...
@@ -943,6 +979,9 @@ class GetProgramAccountsWithContextMaybeJsonParsedResp:
     def from_json(
         raw: str,
     ) -> Resp[GetProgramAccountsWithContextMaybeJsonParsedResp]: ...
+    def raise_for_error(
+        self,
+    ) -> "GetProgramAccountsWithContextMaybeJsonParsedResp": ...
     @staticmethod
     def from_bytes(data: bytes) -> GetProgramAccountsWithContextMaybeJsonParsedResp: ...
     def __str__(self) -> str: ...
@@ -960,6 +999,7 @@ class GetProgramAccountsMaybeJsonParsedResp:
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> Resp[GetProgramAccountsMaybeJsonParsedResp]: ...
+    def raise_for_error(self) -> "GetProgramAccountsMaybeJsonParsedResp": ...
     @staticmethod
     def from_bytes(data: bytes) -> GetProgramAccountsMaybeJsonParsedResp: ...
     def __str__(self) -> str: ...
@@ -1005,6 +1045,7 @@ class GetRecentPerformanceSamplesResp:
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> Resp[GetRecentPerformanceSamplesResp]: ...
+    def raise_for_error(self) -> "GetRecentPerformanceSamplesResp": ...
     @staticmethod
     def from_bytes(data: bytes) -> GetRecentPerformanceSamplesResp: ...
     def __str__(self) -> str: ...
@@ -1055,6 +1096,7 @@ class GetSignaturesForAddressResp:
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> Resp[GetSignaturesForAddressResp]: ...
+    def raise_for_error(self) -> "GetSignaturesForAddressResp": ...
     @staticmethod
     def from_bytes(data: bytes) -> GetSignaturesForAddressResp: ...
     def __str__(self) -> str: ...
@@ -1072,6 +1114,7 @@ class GetSignatureStatusesResp:
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> Resp[GetSignatureStatusesResp]: ...
+    def raise_for_error(self) -> "GetSignatureStatusesResp": ...
     @staticmethod
     def from_bytes(data: bytes) -> GetSignatureStatusesResp: ...
     def __str__(self) -> str: ...
@@ -1087,6 +1130,7 @@ class GetSlotResp:
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> Resp[GetSlotResp]: ...
+    def raise_for_error(self) -> "GetSlotResp": ...
     @staticmethod
     def from_bytes(data: bytes) -> GetSlotResp: ...
     def __str__(self) -> str: ...
@@ -1102,6 +1146,7 @@ class GetSlotLeaderResp:
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> Resp[GetSlotLeaderResp]: ...
+    def raise_for_error(self) -> "GetSlotLeaderResp": ...
     @staticmethod
     def from_bytes(data: bytes) -> GetSlotLeaderResp: ...
     def __str__(self) -> str: ...
@@ -1117,6 +1162,7 @@ class GetSlotLeadersResp:
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> Resp[GetSlotLeadersResp]: ...
+    def raise_for_error(self) -> "GetSlotLeadersResp": ...
     @staticmethod
     def from_bytes(data: bytes) -> GetSlotLeadersResp: ...
     def __str__(self) -> str: ...
@@ -1163,6 +1209,7 @@ class GetStakeActivationResp:
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> Resp[GetStakeActivationResp]: ...
+    def raise_for_error(self) -> "GetStakeActivationResp": ...
     @staticmethod
     def from_bytes(data: bytes) -> GetStakeActivationResp: ...
     def __str__(self) -> str: ...
@@ -1207,6 +1254,7 @@ class GetSupplyResp:
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> Resp[GetSupplyResp]: ...
+    def raise_for_error(self) -> "GetSupplyResp": ...
     @staticmethod
     def from_bytes(data: bytes) -> GetSupplyResp: ...
     def __str__(self) -> str: ...
@@ -1222,6 +1270,7 @@ class GetTokenAccountBalanceResp:
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> Resp[GetTokenAccountBalanceResp]: ...
+    def raise_for_error(self) -> "GetTokenAccountBalanceResp": ...
     @staticmethod
     def from_bytes(data: bytes) -> GetTokenAccountBalanceResp: ...
     def __str__(self) -> str: ...
@@ -1239,6 +1288,7 @@ class GetTokenAccountsByDelegateResp:
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> Resp[GetTokenAccountsByDelegateResp]: ...
+    def raise_for_error(self) -> "GetTokenAccountsByDelegateResp": ...
     @staticmethod
     def from_bytes(data: bytes) -> GetTokenAccountsByDelegateResp: ...
     def __str__(self) -> str: ...
@@ -1256,6 +1306,7 @@ class GetTokenAccountsByDelegateJsonParsedResp:
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> Resp[GetTokenAccountsByDelegateJsonParsedResp]: ...
+    def raise_for_error(self) -> "GetTokenAccountsByDelegateJsonParsedResp": ...
     @staticmethod
     def from_bytes(data: bytes) -> GetTokenAccountsByDelegateJsonParsedResp: ...
     def __str__(self) -> str: ...
@@ -1273,6 +1324,7 @@ class GetTokenAccountsByOwnerResp:
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> Resp[GetTokenAccountsByOwnerResp]: ...
+    def raise_for_error(self) -> "GetTokenAccountsByOwnerResp": ...
     @staticmethod
     def from_bytes(data: bytes) -> GetTokenAccountsByOwnerResp: ...
     def __str__(self) -> str: ...
@@ -1290,6 +1342,7 @@ class GetTokenAccountsByOwnerJsonParsedResp:
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> Resp[GetTokenAccountsByOwnerJsonParsedResp]: ...
+    def raise_for_error(self) -> "GetTokenAccountsByOwnerJsonParsedResp": ...
     @staticmethod
     def from_bytes(data: bytes) -> GetTokenAccountsByOwnerJsonParsedResp: ...
     def __str__(self) -> str: ...
@@ -1324,6 +1377,7 @@ class GetTokenLargestAccountsResp:
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> Resp[GetTokenLargestAccountsResp]: ...
+    def raise_for_error(self) -> "GetTokenLargestAccountsResp": ...
     @staticmethod
     def from_bytes(data: bytes) -> GetTokenLargestAccountsResp: ...
     def __str__(self) -> str: ...
@@ -1339,6 +1393,7 @@ class GetTokenSupplyResp:
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> Resp[GetTokenSupplyResp]: ...
+    def raise_for_error(self) -> "GetTokenSupplyResp": ...
     @staticmethod
     def from_bytes(data: bytes) -> GetTokenSupplyResp: ...
     def __str__(self) -> str: ...
@@ -1356,6 +1411,7 @@ class GetTransactionResp:
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> Resp[GetTransactionResp]: ...
+    def raise_for_error(self) -> "GetTransactionResp": ...
     @staticmethod
     def from_bytes(data: bytes) -> GetTransactionResp: ...
     def __str__(self) -> str: ...
@@ -1371,6 +1427,7 @@ class GetTransactionCountResp:
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> Resp[GetTransactionCountResp]: ...
+    def raise_for_error(self) -> "GetTransactionCountResp": ...
     @staticmethod
     def from_bytes(data: bytes) -> GetTransactionCountResp: ...
     def __str__(self) -> str: ...
@@ -1403,6 +1460,7 @@ class GetVersionResp:
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> Resp[GetVersionResp]: ...
+    def raise_for_error(self) -> "GetVersionResp": ...
     @staticmethod
     def from_bytes(data: bytes) -> GetVersionResp: ...
     def __str__(self) -> str: ...
@@ -1478,6 +1536,7 @@ class GetVoteAccountsResp:
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> Resp[GetVoteAccountsResp]: ...
+    def raise_for_error(self) -> "GetVoteAccountsResp": ...
     @staticmethod
     def from_bytes(data: bytes) -> GetVoteAccountsResp: ...
     def __str__(self) -> str: ...
@@ -1493,6 +1552,7 @@ class IsBlockhashValidResp:
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> Resp[IsBlockhashValidResp]: ...
+    def raise_for_error(self) -> "IsBlockhashValidResp": ...
     @staticmethod
     def from_bytes(data: bytes) -> IsBlockhashValidResp: ...
     def __str__(self) -> str: ...
@@ -1508,6 +1568,7 @@ class MinimumLedgerSlotResp:
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> Resp[MinimumLedgerSlotResp]: ...
+    def raise_for_error(self) -> "MinimumLedgerSlotResp": ...
     @staticmethod
     def from_bytes(data: bytes) -> MinimumLedgerSlotResp: ...
     def __str__(self) -> str: ...
@@ -1523,6 +1584,7 @@ class RequestAirdropResp:
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> Resp[RequestAirdropResp]: ...
+    def raise_for_error(self) -> "RequestAirdropResp": ...
     @staticmethod
     def from_bytes(data: bytes) -> RequestAirdropResp: ...
     def __str__(self) -> str: ...
@@ -1538,6 +1600,7 @@ class ValidatorExitResp:
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> Resp[ValidatorExitResp]: ...
+    def raise_for_error(self) -> "ValidatorExitResp": ...
     @staticmethod
     def from_bytes(data: bytes) -> ValidatorExitResp: ...
     def __str__(self) -> str: ...
@@ -1553,6 +1616,7 @@ class SendTransactionResp:
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> Resp[SendTransactionResp]: ...
+    def raise_for_error(self) -> "SendTransactionResp": ...
     @staticmethod
     def from_bytes(data: bytes) -> SendTransactionResp: ...
     def __str__(self) -> str: ...
@@ -1568,6 +1632,7 @@ class SimulateTransactionResp:
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> Resp[SimulateTransactionResp]: ...
+    def raise_for_error(self) -> "SimulateTransactionResp": ...
     @staticmethod
     def from_bytes(data: bytes) -> SimulateTransactionResp: ...
     def __str__(self) -> str: ...
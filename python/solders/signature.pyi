@@ -20,6 +20,9 @@ class Signature:
     def __hash__(self) -> int: ...
     @staticmethod
     def from_bytes(raw_bytes: bytes) -> "Signature": ...
+    def to_borsh(self) -> bytes: ...
+    @staticmethod
+    def from_borsh(data: bytes) -> "Signature": ...
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> "Signature": ...
@@ -0,0 +1,42 @@
+from typing import List, Optional, Sequence, Tuple
+
+MAX_ENTRIES: int
+
+class StakeHistoryEntry:
+    def __init__(
+        self, effective: int, activating: int = 0, deactivating: int = 0
+    ) -> None: ...
+    @property
+    def effective(self) -> int: ...
+    @property
+    def activating(self) -> int: ...
+    @property
+    def deactivating(self) -> int: ...
+    def __bytes__(self) -> bytes: ...
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
+    def __richcmp__(self, other: "StakeHistoryEntry", op: int) -> bool: ...
+    @staticmethod
+    def from_bytes(raw_bytes: bytes) -> "StakeHistoryEntry": ...
+    def to_json(self) -> str: ...
+    @staticmethod
+    def from_json(raw: str) -> "StakeHistoryEntry": ...
+
+class StakeHistory:
+    def __init__(
+        self, entries: Optional[Sequence[Tuple[int, StakeHistoryEntry]]] = None
+    ) -> None: ...
+    @property
+    def entries(self) -> List[Tuple[int, StakeHistoryEntry]]: ...
+    def get(self, epoch: int) -> Optional[StakeHistoryEntry]: ...
+    def add(self, epoch: int, entry: StakeHistoryEntry) -> None: ...
+    def __len__(self) -> int: ...
+    def __bytes__(self) -> bytes: ...
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
+    def __richcmp__(self, other: "StakeHistory", op: int) -> bool: ...
+    @staticmethod
+    def from_bytes(raw_bytes: bytes) -> "StakeHistory": ...
+    def to_json(self) -> str: ...
+    @staticmethod
+    def from_json(raw: str) -> "StakeHistory": ...
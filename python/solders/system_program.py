@@ -1,7 +1,9 @@
-from typing import cast, Tuple, Optional, List
+from typing import cast, Tuple, Optional, List, Sequence, Union
 from typing_extensions import Final, TypedDict
 
 from solders._system_program import ID as _ID
+from solders._system_program import NONCE_STATE_SIZE as _NONCE_STATE_SIZE
+from solders._system_program import NonceData
 from solders._system_program import advance_nonce_account as _advance_nonce_account
 from solders._system_program import allocate as _allocate
 from solders._system_program import allocate_with_seed as _allocate_with_seed
@@ -36,6 +38,10 @@ from solders._system_program import (
 from solders._system_program import (
     decode_initialize_nonce_account as _decode_initialize_nonce_account,
 )
+from solders._system_program import decode_instruction as _decode_instruction
+from solders._system_program import (
+    decode_lookup_table_instruction as _decode_lookup_table_instruction,
+)
 from solders._system_program import decode_transfer as _decode_transfer
 from solders._system_program import (
     decode_transfer_with_seed as _decode_transfer_with_seed,
@@ -66,12 +72,18 @@ from solders._system_program import (
 )
 from solders._system_program import transfer as _transfer
 from solders._system_program import transfer_with_seed as _transfer_with_seed
+from solders._system_program import upgrade_nonce_account as _upgrade_nonce_account
+from solders._system_program import (
+    decode_upgrade_nonce_account as _decode_upgrade_nonce_account,
+)
 from solders._system_program import withdraw_nonce_account as _withdraw_nonce_account
-from solders.instruction import Instruction
+from solders.instruction import CompiledInstruction, Instruction
 from solders.pubkey import Pubkey
 
 ID: Final[Pubkey] = _ID
 """Pubkey that identifies the System program."""
+NONCE_STATE_SIZE: Final[int] = _NONCE_STATE_SIZE
+"""The size in bytes of a nonce account's data."""
 
 
 class CreateAccountParams(TypedDict):
@@ -573,6 +585,90 @@ def decode_authorize_nonce_account(
     )
 
 
+class UpgradeNonceAccountParams(TypedDict):
+    """Upgrade nonce account system instruction params."""
+
+    nonce_pubkey: Pubkey
+    """Nonce account."""
+
+
+def upgrade_nonce_account(params: UpgradeNonceAccountParams) -> Instruction:
+    """Generate an instruction that upgrades a legacy Nonce account.
+
+    Args:
+        params (UpgradeNonceAccountParams): The UpgradeNonceAccount params.
+
+    Returns:
+        Instruction: The UpgradeNonceAccount instruction.
+    """
+    return _upgrade_nonce_account(dict(params))
+
+
+def decode_upgrade_nonce_account(
+    instruction: Instruction,
+) -> UpgradeNonceAccountParams:
+    """Decode an upgrade nonce account instruction and retrieve the instruction params.
+
+    Args:
+        instruction (Instruction): The UpgradeNonceAccount instruction.
+
+    Returns:
+        UpgradeNonceAccountParams: The params used to create the instruction.
+    """
+    return cast(UpgradeNonceAccountParams, _decode_upgrade_nonce_account(instruction))
+
+
+SystemInstructionParams = Union[
+    CreateAccountParams,
+    AssignParams,
+    TransferParams,
+    CreateAccountWithSeedParams,
+    AdvanceNonceAccountParams,
+    WithdrawNonceAccountParams,
+    InitializeNonceAccountParams,
+    AuthorizeNonceAccountParams,
+    AllocateParams,
+    AllocateWithSeedParams,
+    AssignWithSeedParams,
+    TransferWithSeedParams,
+    UpgradeNonceAccountParams,
+]
+"""The params of any system instruction."""
+
+
+def decode_instruction(
+    instruction: Union[Instruction, CompiledInstruction],
+    keys: Optional[Sequence[Pubkey]] = None,
+) -> Tuple[str, SystemInstructionParams]:
+    """Decode any system instruction.
+
+    Args:
+        instruction (Instruction | CompiledInstruction): The instruction to decode.
+        keys (Optional[Sequence[Pubkey]]): The account keys of the message the
+            instruction belongs to. Required when ``instruction`` is a
+            ``CompiledInstruction``.
+
+    Returns:
+        Tuple[str, SystemInstructionParams]: The instruction name, such as
+        ``"Transfer"``, and the params used to create the instruction.
+
+    Raises:
+        InstructionDecodeError: if the instruction is not a valid system instruction.
+
+    Example:
+        >>> from solders.pubkey import Pubkey
+        >>> from solders.system_program import transfer, TransferParams, decode_instruction
+        >>> sender, receiver = Pubkey.new_unique(), Pubkey.new_unique()
+        >>> ix = transfer(TransferParams(from_pubkey=sender, to_pubkey=receiver, lamports=1))
+        >>> name, params = decode_instruction(ix)
+        >>> name
+        'Transfer'
+    """
+    return cast(
+        Tuple[str, SystemInstructionParams], _decode_instruction(instruction, keys)
+    )
+
+
 class CloseLookupTableParams(TypedDict):
     """Close lookup table system transaction params."""
 
@@ -711,8 +807,43 @@ def freeze_lookup_table(params: FreezeLookupTableParams) -> Instruction:
     return _freeze_lookup_table(dict(params))
 
 
+AddressLookupTableInstructionParams = Union[
+    CreateLookupTableParams,
+    FreezeLookupTableParams,
+    ExtendLookupTableParams,
+    DeactivateLookupTableParams,
+    CloseLookupTableParams,
+]
+"""The params of any address lookup table instruction."""
+
+
+def decode_lookup_table_instruction(
+    instruction: Instruction,
+) -> Tuple[str, AddressLookupTableInstructionParams]:
+    """Decode any address lookup table instruction.
+
+    Args:
+        instruction (Instruction): The instruction to decode.
+
+    Returns:
+        Tuple[str, AddressLookupTableInstructionParams]: The instruction name, such as
+        ``"ExtendLookupTable"``, and the params used to create the instruction.
+
+    Raises:
+        InstructionDecodeError: if the instruction is not a valid address lookup table instruction.
+    """
+    return cast(
+        Tuple[str, AddressLookupTableInstructionParams],
+        _decode_lookup_table_instruction(instruction),
+    )
+
+
 __all__ = [
     "ID",
+    "NONCE_STATE_SIZE",
+    "NonceData",
+    "SystemInstructionParams",
+    "decode_instruction",
     "transfer_many",
     "create_nonce_account",
     "create_nonce_account_with_seed",
@@ -752,6 +883,9 @@ __all__ = [
     "AuthorizeNonceAccountParams",
     "authorize_nonce_account",
     "decode_authorize_nonce_account",
+    "UpgradeNonceAccountParams",
+    "upgrade_nonce_account",
+    "decode_upgrade_nonce_account",
     "CloseLookupTableParams",
     "close_lookup_table",
     "CreateLookupTableParams",
@@ -764,4 +898,6 @@ __all__ = [
     "extend_lookup_table",
     "FreezeLookupTableParams",
     "freeze_lookup_table",
+    "AddressLookupTableInstructionParams",
+    "decode_lookup_table_instruction",
 ]
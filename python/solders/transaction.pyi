@@ -1,5 +1,6 @@
 from typing import List, Optional, Sequence, Tuple, Union
 
+from solders.bankrun import BanksTransactionMeta
 from solders.hash import Hash
 from solders.instruction import CompiledInstruction, Instruction
 from solders.keypair import Keypair
@@ -39,6 +40,15 @@ class Transaction:
         recent_blockhash: Hash,
     ) -> "Transaction": ...
     @staticmethod
+    def new_signed_with_nonce(
+        instructions: Sequence[Instruction],
+        payer: Optional[Pubkey],
+        signing_keypairs: Sequence[Signer],
+        nonce_account_pubkey: Pubkey,
+        nonce_authority_pubkey: Pubkey,
+        durable_nonce: Hash,
+    ) -> "Transaction": ...
+    @staticmethod
     def new_with_compiled_instructions(
         from_keypairs: Sequence[Signer],
         keys: Sequence[Pubkey],
@@ -133,7 +143,47 @@ class VersionedTransaction:
     def from_legacy(tx: Transaction) -> "VersionedTransaction": ...
     def uses_durable_nonce(self) -> bool: ...
 
+class PartiallySignedTransaction:
+    def __init__(
+        self,
+        message: Union[Message, MessageV0],
+        nonce_account: Optional[Pubkey] = None,
+        last_valid_block_height: Optional[int] = None,
+    ) -> None: ...
+    @staticmethod
+    def from_transaction(
+        tx: VersionedTransaction,
+        nonce_account: Optional[Pubkey] = None,
+        last_valid_block_height: Optional[int] = None,
+    ) -> "PartiallySignedTransaction": ...
+    @property
+    def message(self) -> Union[Message, MessageV0]: ...
+    @property
+    def signers(self) -> List[Pubkey]: ...
+    @property
+    def signatures(self) -> List[Optional[Signature]]: ...
+    @property
+    def nonce_account(self) -> Optional[Pubkey]: ...
+    @property
+    def last_valid_block_height(self) -> Optional[int]: ...
+    def missing_signers(self) -> List[Pubkey]: ...
+    def is_fully_signed(self) -> bool: ...
+    def add_signature(self, pubkey: Pubkey, signature: Signature) -> None: ...
+    def sign(self, keypairs: Sequence[Signer]) -> None: ...
+    def merge(self, other: "PartiallySignedTransaction") -> None: ...
+    def to_transaction(self) -> VersionedTransaction: ...
+    def __bytes__(self) -> bytes: ...
+    @staticmethod
+    def from_bytes(data: bytes) -> "PartiallySignedTransaction": ...
+    def __richcmp__(self, other: "PartiallySignedTransaction", op: int) -> bool: ...
+    def __repr__(self) -> str: ...
+    def __str__(self) -> str: ...
+    def to_json(self) -> str: ...
+    @staticmethod
+    def from_json(raw: str) -> "PartiallySignedTransaction": ...
+
 class SanitizeError(Exception): ...
-class TransactionError(Exception): ...
+class TransactionError(Exception):
+    meta: Optional[BanksTransactionMeta]
 
 TransactionVersion = Union[Legacy, int]
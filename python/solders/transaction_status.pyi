@@ -1,11 +1,13 @@
-from typing import Dict, List, Optional, Sequence, Union
+from typing import Dict, List, Optional, Sequence, Tuple, Union
 
 from jsonalias import Json
 
 from solders.account_decoder import UiTokenAmount
 from solders.commitment_config import CommitmentConfig
+from solders.custom_error import CustomErrorResolver, ResolvedError
 from solders.hash import Hash
-from solders.message import MessageHeader
+from solders.log_parser import ParsedLogs
+from solders.message import MessageHeader, VersionedMessage
 from solders.pubkey import Pubkey
 from solders.signature import Signature
 from solders.transaction import TransactionVersion, VersionedTransaction
@@ -112,6 +114,7 @@ class UiRawMessage:
     def instructions(self) -> List[UiCompiledInstruction]: ...
     @property
     def address_table_lookups(self) -> Optional[List[UiAddressTableLookup]]: ...
+    def to_versioned_message(self) -> VersionedMessage: ...
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
     def __bytes__(self) -> bytes: ...
@@ -284,6 +287,7 @@ class UiTransaction:
     def signatures(self) -> List[Signature]: ...
     @property
     def message(self) -> UiMessage: ...
+    def to_versioned_transaction(self) -> VersionedTransaction: ...
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
     def __bytes__(self) -> bytes: ...
@@ -431,6 +435,8 @@ class UiTransactionStatusMeta:
     def inner_instructions(self) -> Optional[List[UiInnerInstructions]]: ...
     @property
     def log_messages(self) -> Optional[List[str]]: ...
+    def parse_logs(self) -> Optional[ParsedLogs]: ...
+    def balance_changes(self, account_keys: Sequence[Pubkey]) -> "BalanceChanges": ...
     @property
     def pre_token_balances(self) -> Optional[List[UiTransactionTokenBalance]]: ...
     @property
@@ -470,6 +476,17 @@ class EncodedTransactionWithStatusMeta:
     def meta(self) -> Optional[UiTransactionStatusMeta]: ...
     @property
     def version(self) -> Optional[TransactionVersion]: ...
+    def balance_changes(self) -> "BalanceChanges": ...
+    def decode_transaction(self) -> VersionedTransaction: ...
+    @staticmethod
+    def from_transaction(
+        transaction: VersionedTransaction,
+        meta: Optional[UiTransactionStatusMeta],
+        encoding: UiTransactionEncoding,
+        max_supported_transaction_version: Optional[int] = None,
+        show_rewards: bool = True,
+        transaction_details: TransactionDetails = TransactionDetails.Full,
+    ) -> "EncodedTransactionWithStatusMeta": ...
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
     def __bytes__(self) -> bytes: ...
@@ -484,6 +501,96 @@ class EncodedTransactionWithStatusMeta:
     @staticmethod
     def from_json(raw: str) -> "EncodedTransactionWithStatusMeta": ...
 
+class AccountBalanceChange:
+    def __init__(
+        self, pubkey: Pubkey, pre_balance: int, post_balance: int, fee: int
+    ) -> None: ...
+    @property
+    def pubkey(self) -> Pubkey: ...
+    @property
+    def pre_balance(self) -> int: ...
+    @property
+    def post_balance(self) -> int: ...
+    @property
+    def fee(self) -> int: ...
+    @property
+    def delta(self) -> int: ...
+    @property
+    def delta_excluding_fee(self) -> int: ...
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
+    def __bytes__(self) -> bytes: ...
+    def __eq__(self, other: object) -> bool: ...
+    @staticmethod
+    def from_bytes(raw: bytes) -> "AccountBalanceChange": ...
+    def to_json(self) -> str: ...
+    @staticmethod
+    def from_json(raw: str) -> "AccountBalanceChange": ...
+
+class TokenBalanceChange:
+    def __init__(
+        self,
+        owner: Pubkey,
+        mint: Pubkey,
+        decimals: int,
+        pre_amount: int,
+        post_amount: int,
+        program_id: Optional[Pubkey],
+    ) -> None: ...
+    @property
+    def owner(self) -> Pubkey: ...
+    @property
+    def mint(self) -> Pubkey: ...
+    @property
+    def decimals(self) -> int: ...
+    @property
+    def pre_amount(self) -> int: ...
+    @property
+    def post_amount(self) -> int: ...
+    @property
+    def program_id(self) -> Optional[Pubkey]: ...
+    @property
+    def delta(self) -> int: ...
+    @property
+    def ui_delta(self) -> float: ...
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
+    def __bytes__(self) -> bytes: ...
+    def __eq__(self, other: object) -> bool: ...
+    @staticmethod
+    def from_bytes(raw: bytes) -> "TokenBalanceChange": ...
+    def to_json(self) -> str: ...
+    @staticmethod
+    def from_json(raw: str) -> "TokenBalanceChange": ...
+
+class BalanceChanges:
+    def __init__(
+        self,
+        fee_payer: Pubkey,
+        fee: int,
+        sol: Sequence[AccountBalanceChange],
+        token: Sequence[TokenBalanceChange],
+    ) -> None: ...
+    @property
+    def fee_payer(self) -> Pubkey: ...
+    @property
+    def fee(self) -> int: ...
+    @property
+    def sol(self) -> List[AccountBalanceChange]: ...
+    @property
+    def token(self) -> List[TokenBalanceChange]: ...
+    def sol_change(self, pubkey: Pubkey) -> Optional[AccountBalanceChange]: ...
+    def token_changes(self, owner: Pubkey) -> List[TokenBalanceChange]: ...
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
+    def __bytes__(self) -> bytes: ...
+    def __eq__(self, other: object) -> bool: ...
+    @staticmethod
+    def from_bytes(raw: bytes) -> "BalanceChanges": ...
+    def to_json(self) -> str: ...
+    @staticmethod
+    def from_json(raw: str) -> "BalanceChanges": ...
+
 class InstructionErrorCustom:
     def __init__(self, code: int) -> None: ...
     @property
@@ -583,6 +690,12 @@ class TransactionErrorInstructionError:
     def index(self) -> int: ...
     @property
     def err(self) -> InstructionErrorType: ...
+    def resolve(
+        self, program_id: Pubkey, resolver: Optional[CustomErrorResolver] = None
+    ) -> Optional[ResolvedError]: ...
+    def describe(
+        self, program_id: Pubkey, resolver: Optional[CustomErrorResolver] = None
+    ) -> str: ...
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
     def __bytes__(self) -> bytes: ...
@@ -833,6 +946,22 @@ class UiConfirmedBlock:
         block_time: Optional[int] = None,
         block_height: Optional[int] = None,
     ) -> None: ...
+    @staticmethod
+    def from_transactions(
+        previous_blockhash: Hash,
+        blockhash: Hash,
+        parent_slot: int,
+        transactions: Sequence[
+            Tuple[VersionedTransaction, Optional[UiTransactionStatusMeta]]
+        ],
+        rewards: Sequence[Reward],
+        block_time: Optional[int],
+        block_height: Optional[int],
+        encoding: UiTransactionEncoding,
+        transaction_details: TransactionDetails = TransactionDetails.Full,
+        show_rewards: bool = True,
+        max_supported_transaction_version: Optional[int] = None,
+    ) -> UiConfirmedBlock: ...
     def to_json(self) -> str: ...
     @staticmethod
     def from_json(raw: str) -> UiConfirmedBlock: ...
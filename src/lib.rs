@@ -7,10 +7,17 @@ use pyo3::prelude::*;
 use rpc::create_rpc_mod;
 #[cfg(feature = "ring")]
 use solders_account::create_account_mod;
+use solders_anchor_idl::create_anchor_idl_mod;
+use solders_borsh::create_borsh_mod;
+use solders_custom_error::create_custom_error_mod;
 use solders_instruction::{AccountMeta, CompiledInstruction, Instruction};
+use solders_instruction_decoder::create_instruction_decoder_mod;
+use solders_log_parser::create_log_parser_mod;
 use solders_system_program::create_system_program_mod;
 use solders_token::create_token_mod;
-use solders_traits::{BincodeError, CborError, ParseHashError, SerdeJSONError, SignerError};
+use solders_traits::{
+    BincodeError, CborError, InstructionDecodeError, ParseHashError, SerdeJSONError, SignerError,
+};
 #[cfg(feature = "ring")]
 use solders_transaction_status::create_transaction_status_mod;
 use std::collections::HashMap;
@@ -19,6 +26,8 @@ pub mod message;
 use message::create_message_mod;
 pub mod transaction;
 #[cfg(feature = "ring")]
+use solders_account_classifier::create_account_classifier_mod;
+#[cfg(feature = "ring")]
 use solders_account_decoder::create_account_decoder_mod;
 use transaction::create_transaction_mod;
 pub mod address_lookup_table_account;
@@ -31,7 +40,9 @@ use solders_epoch_info::create_epoch_info_mod;
 use solders_hash::Hash as SolderHash;
 use solders_keypair::{null_signer::NullSigner, presigner::Presigner, Keypair};
 use solders_primitives::{
-    clock::create_clock_mod, epoch_schedule::create_epoch_schedule_mod, rent::create_rent_mod,
+    clock::create_clock_mod, epoch_rewards::create_epoch_rewards_mod,
+    epoch_schedule::create_epoch_schedule_mod, rent::create_rent_mod,
+    stake_history::create_stake_history_mod,
 };
 use solders_pubkey::Pubkey;
 use solders_signature::Signature;
@@ -64,6 +75,10 @@ fn solders(py: Python, m: &PyModule) -> PyResult<()> {
     errors_mod.add("SignerError", py.get_type::<SignerError>())?;
     errors_mod.add("CborError", py.get_type::<CborError>())?;
     errors_mod.add("SerdeJSONError", py.get_type::<SerdeJSONError>())?;
+    errors_mod.add(
+        "InstructionDecodeError",
+        py.get_type::<InstructionDecodeError>(),
+    )?;
     #[cfg(feature = "ring")]
     let rpc_mod = create_rpc_mod(py)?;
     let commitment_config_mod = PyModule::new(py, "commitment_config")?;
@@ -72,10 +87,14 @@ fn solders(py: Python, m: &PyModule) -> PyResult<()> {
     #[cfg(feature = "ring")]
     let transaction_status_mod = create_transaction_status_mod(py)?;
     #[cfg(feature = "ring")]
+    let account_classifier_mod = create_account_classifier_mod(py)?;
+    #[cfg(feature = "ring")]
     let account_decoder_mod = create_account_decoder_mod(py)?;
     #[cfg(feature = "ring")]
     let account_mod = create_account_mod(py)?;
     let epoch_schedule_mod = create_epoch_schedule_mod(py)?;
+    let epoch_rewards_mod = create_epoch_rewards_mod(py)?;
+    let stake_history_mod = create_stake_history_mod(py)?;
     let address_lookup_table_account_mod = create_address_lookup_table_account_mod(py)?;
     #[cfg(feature = "bankrun")]
     let bankrun_mod = solders_bankrun::create_bankrun_mod(py)?;
@@ -84,23 +103,36 @@ fn solders(py: Python, m: &PyModule) -> PyResult<()> {
     let epoch_info_mod = create_epoch_info_mod(py)?;
     let compute_budget_mod = create_compute_budget_mod(py)?;
     let token_mod = create_token_mod(py)?;
+    let instruction_decoder_mod = create_instruction_decoder_mod(py)?;
+    let log_parser_mod = create_log_parser_mod(py)?;
+    let custom_error_mod = create_custom_error_mod(py)?;
+    let anchor_idl_mod = create_anchor_idl_mod(py)?;
+    let borsh_mod = create_borsh_mod(py)?;
     let submodules = [
         #[cfg(feature = "ring")]
         account_mod,
         #[cfg(feature = "ring")]
+        account_classifier_mod,
+        #[cfg(feature = "ring")]
         account_decoder_mod,
         address_lookup_table_account_mod,
+        anchor_idl_mod,
         #[cfg(feature = "bankrun")]
         bankrun_mod,
+        borsh_mod,
         clock_mod,
         commitment_config_mod,
         compute_budget_mod,
+        custom_error_mod,
         epoch_info_mod,
+        epoch_rewards_mod,
         epoch_schedule_mod,
         errors_mod,
         hash_mod,
         instruction_mod,
+        instruction_decoder_mod,
         keypair_mod,
+        log_parser_mod,
         message_mod,
         null_signer_mod,
         presigner_mod,
@@ -109,6 +141,7 @@ fn solders(py: Python, m: &PyModule) -> PyResult<()> {
         #[cfg(feature = "ring")]
         rpc_mod,
         signature_mod,
+        stake_history_mod,
         system_program_mod,
         sysvar_mod,
         token_mod,
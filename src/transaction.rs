@@ -7,12 +7,13 @@ use pyo3::{
 use solders_traits::{SanitizeError, TransactionError};
 
 use solders_keypair::{null_signer::NullSigner, presigner::Presigner, Keypair};
-use solders_transaction::{Legacy, Transaction, VersionedTransaction};
+use solders_transaction::{Legacy, PartiallySignedTransaction, Transaction, VersionedTransaction};
 pub(crate) fn create_transaction_mod(py: Python<'_>) -> PyResult<&PyModule> {
     let m = PyModule::new(py, "transaction")?;
     m.add_class::<Transaction>()?;
     m.add_class::<VersionedTransaction>()?;
     m.add_class::<Legacy>()?;
+    m.add_class::<PartiallySignedTransaction>()?;
     m.add("SanitizeError", py.get_type::<SanitizeError>())?;
     m.add("TransactionError", py.get_type::<TransactionError>())?;
     let typing = py.import("typing")?;
@@ -1,6 +1,6 @@
 from pathlib import Path
 
-from pytest import mark
+from pytest import mark, raises
 from solders.bankrun import start_anchor
 from solders.pubkey import Pubkey
 
@@ -12,3 +12,46 @@ async def test_anchor() -> None:
     executable_account = await ctx.banks_client.get_account(program_id)
     assert executable_account is not None
     assert executable_account.executable
+
+
+@mark.asyncio
+async def test_anchor_test_config() -> None:
+    workspace = Path("tests/bankrun/anchor-example")
+    ctx = await start_anchor(workspace, clone_dir=workspace / "clones")
+    client = ctx.banks_client
+    genesis_program = Pubkey.from_string("37cBMsSxJQ2Vordg3mLmXJCawL5bv7G6YVjX8XR41ECi")
+    genesis_account = await client.get_account(genesis_program)
+    assert genesis_account is not None
+    assert genesis_account.executable
+    assert genesis_account.owner == Pubkey.from_string(
+        "BPFLoaderUpgradeab1e11111111111111111111111"
+    )
+    fixture_address = Pubkey.from_string("9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM")
+    fixture_account = await client.get_account(fixture_address)
+    assert fixture_account is not None
+    assert fixture_account.data == bytes(range(8))
+    # The entry's address wins over the pubkey stored in the file.
+    override_address = Pubkey.from_string(
+        "Ap6oQbTsFgDRuhiEoz3PNerCZcpRZvAmvNJpsCeXzkK8"
+    )
+    assert await client.get_account(override_address) == fixture_account
+    cloned_address = Pubkey.from_string("CYQ4wBm6tyZAiagxUytHsYFpLPmvpuCsHNPpU4xa1m13")
+    cloned_account = await client.get_account(cloned_address)
+    assert cloned_account is not None
+    assert cloned_account.data == b"cloned"
+
+
+@mark.asyncio
+async def test_anchor_cluster() -> None:
+    ctx = await start_anchor(Path("tests/bankrun/anchor-example"), cluster="devnet")
+    client = ctx.banks_client
+    devnet_id = Pubkey.from_string("74YASCoL7nr5TWSChTUyn49aBT3fpQiWA6TJmjeH9QrJ")
+    localnet_id = Pubkey.from_string("Fg6PaFpoGXkYsidMpWTK6W2BeZ7FEfcYkg476zPFsLnS")
+    devnet_account = await client.get_account(devnet_id)
+    assert devnet_account is not None
+    assert devnet_account.executable
+    assert await client.get_account(localnet_id) is None
+    cloned_address = Pubkey.from_string("CYQ4wBm6tyZAiagxUytHsYFpLPmvpuCsHNPpU4xa1m13")
+    assert await client.get_account(cloned_address) is None
+    with raises(ValueError):
+        await start_anchor(Path("tests/bankrun/anchor-example"), cluster="mainnet")
@@ -3,15 +3,22 @@ from pathlib import Path
 from typing import Optional, Tuple
 
 from pytest import mark, raises
+from solders import sysvar
 from solders.account import Account
-from solders.bankrun import BanksClient, ProgramTestContext, start
+from solders.address_lookup_table_account import SlotHashes
+from solders.bankrun import BanksClient, ProgramTestContext, start, start_sync
 from solders.clock import Clock
+from solders.epoch_rewards import EpochRewards
+from solders.epoch_schedule import EpochSchedule
+from solders.hash import Hash
 from solders.instruction import AccountMeta, Instruction
 from solders.keypair import Keypair
 from solders.message import Message
 from solders.pubkey import Pubkey
 from solders.rent import Rent
-from solders.system_program import transfer
+from solders.rpc.filter import Memcmp
+from solders.stake_history import StakeHistory, StakeHistoryEntry
+from solders.system_program import create_account, transfer
 from solders.transaction import Transaction, TransactionError, VersionedTransaction
 
 
@@ -125,8 +132,20 @@ async def test_compute_limit() -> None:
     blockhash = context.last_blockhash
     msg = Message.new_with_blockhash([ix], payer.pubkey(), blockhash)
     tx = VersionedTransaction(msg, [payer])
-    with raises(TransactionError):
+    with raises(TransactionError) as excinfo:
         await client.process_transaction(tx)
+    meta = excinfo.value.meta
+    assert meta is not None
+    assert meta.log_messages[0] == f"Program {program_id} invoke [1]"
+    assert meta.log_messages[-1].startswith(f"Program {program_id} failed")
+    other_ix = Instruction(program_id, bytes([1]), ix.accounts)
+    other_msg = Message.new_with_blockhash([other_ix], payer.pubkey(), blockhash)
+    result = await client.process_transaction_with_metadata(
+        VersionedTransaction(other_msg, [payer])
+    )
+    assert result.result is not None
+    assert result.meta is not None
+    assert result.meta.log_messages[0] == meta.log_messages[0]
 
 
 @mark.asyncio()
@@ -179,6 +198,46 @@ async def test_warp() -> None:
     context.warp_to_slot(new_slot)
     slot1 = await client.get_slot()
     assert slot1 == new_slot
+    blockhash = context.last_blockhash
+    context.warp_to_epoch(8)
+    assert (await client.get_clock()).epoch == 8
+    assert context.last_blockhash != blockhash
+    slot2 = await client.get_slot()
+    context.warp_forward_force_reward_interval_end()
+    assert await client.get_slot() == slot2 + 1
+
+
+@mark.asyncio
+async def test_sysvar_setters() -> None:
+    context = await start()
+    client = context.banks_client
+
+    async def sysvar_data(address: Pubkey) -> bytes:
+        account = await client.get_account(address)
+        assert account is not None
+        return account.data
+
+    epoch_schedule = EpochSchedule.custom(64, 64, False)
+    context.set_epoch_schedule(epoch_schedule)
+    raw = await sysvar_data(sysvar.EPOCH_SCHEDULE)
+    assert EpochSchedule.from_bytes(raw) == epoch_schedule
+    stake_history = StakeHistory(
+        [(1, StakeHistoryEntry(10)), (2, StakeHistoryEntry(20, 5))]
+    )
+    assert [epoch for epoch, _ in stake_history.entries] == [2, 1]
+    context.set_stake_history(stake_history)
+    raw = await sysvar_data(sysvar.STAKE_HISTORY)
+    assert StakeHistory.from_bytes(raw) == stake_history
+    epoch_rewards = EpochRewards(100, 40, 7)
+    context.set_epoch_rewards(epoch_rewards)
+    raw = await sysvar_data(
+        Pubkey.from_string("SysvarEpochRewards1111111111111111111111111")
+    )
+    assert EpochRewards.from_bytes(raw) == epoch_rewards
+    slot_hashes = SlotHashes([(5, Hash.default()), (4, Hash.new_unique())])
+    context.set_slot_hashes(slot_hashes)
+    raw = await sysvar_data(sysvar.SLOT_HASHES)
+    assert SlotHashes.from_bytes(raw) == slot_hashes
 
 
 @mark.asyncio
@@ -275,3 +334,278 @@ async def test_add_program_via_set_account() -> None:
     greeted_account_after = await client.get_account(greeted_pubkey)
     assert greeted_account_after is not None
     assert greeted_account_after.data == bytes([1, 0, 0, 0])
+
+
+@mark.asyncio
+async def test_snapshot_restore() -> None:
+    context = await start()
+    client = context.banks_client
+    payer = context.payer
+    receiver = Pubkey.new_unique()
+    overwritten = Pubkey.new_unique()
+    payer_balance_before = await client.get_balance(payer.pubkey())
+    snapshot = context.snapshot()
+    ix = transfer(
+        {"from_pubkey": payer.pubkey(), "to_pubkey": receiver, "lamports": 1_000_000}
+    )
+    msg = Message.new_with_blockhash([ix], payer.pubkey(), context.last_blockhash)
+    await client.process_transaction(VersionedTransaction(msg, [payer]))
+    context.set_account(overwritten, Account(lamports=5, data=b"", owner=receiver))
+    assert await client.get_balance(receiver) == 1_000_000
+    assert snapshot.num_recorded_accounts == 3
+    context.restore(snapshot)
+    assert await client.get_account(receiver) is None
+    assert await client.get_account(overwritten) is None
+    assert await client.get_balance(payer.pubkey()) == payer_balance_before
+    # the snapshot can be restored again after further changes
+    ix = transfer(
+        {"from_pubkey": payer.pubkey(), "to_pubkey": receiver, "lamports": 2_000_000}
+    )
+    msg = Message.new_with_blockhash([ix], payer.pubkey(), context.last_blockhash)
+    await client.process_transaction(VersionedTransaction(msg, [payer]))
+    assert await client.get_balance(receiver) == 2_000_000
+    context.restore(snapshot)
+    assert await client.get_account(receiver) is None
+    other = await start()
+    with raises(ValueError):
+        other.restore(snapshot)
+
+
+async def say_hello(context: ProgramTestContext, program_id: Pubkey) -> bytes:
+    greeted_pubkey = Pubkey.new_unique()
+    context.set_account(
+        greeted_pubkey, Account(lamports=5, data=bytes(4), owner=program_id)
+    )
+    ix = Instruction(
+        program_id,
+        bytes([0]),
+        [AccountMeta(greeted_pubkey, is_signer=False, is_writable=True)],
+    )
+    client = context.banks_client
+    payer = context.payer
+    blockhash = (await client.get_latest_blockhash())[0]
+    msg = Message.new_with_blockhash([ix], payer.pubkey(), blockhash)
+    await client.process_transaction(VersionedTransaction(msg, [payer]))
+    greeted = await client.get_account(greeted_pubkey)
+    assert greeted is not None
+    return greeted.data
+
+
+@mark.asyncio
+async def test_add_program() -> None:
+    program_path = Path("tests/fixtures/helloworld.so")
+    context = await start()
+    program_id = Pubkey.new_unique()
+    context.add_program(program_id, program_path.read_bytes())
+    assert await say_hello(context, program_id) == bytes([1, 0, 0, 0])
+    with raises(ValueError):
+        context.add_program(program_id, program_path, upgradeable=True)
+
+
+@mark.asyncio
+async def test_add_upgradeable_program() -> None:
+    loader = Pubkey.from_string("BPFLoaderUpgradeab1e11111111111111111111111")
+    context = await start()
+    client = context.banks_client
+    program_id = Pubkey.new_unique()
+    programdata_address = Pubkey.find_program_address([bytes(program_id)], loader)[0]
+    context.add_program(
+        program_id, str(Path("tests/fixtures/helloworld.so")), upgradeable=True
+    )
+    programdata = await client.get_account(programdata_address)
+    assert programdata is not None
+    assert programdata.owner == loader
+    assert programdata.data[13:45] == bytes(context.payer.pubkey())
+    assert await say_hello(context, program_id) == bytes([1, 0, 0, 0])
+    slot_before = await client.get_slot()
+    authority = Pubkey.new_unique()
+    # upgrade to a program that behaves differently
+    logging_elf = Path("tests/fixtures/spl_example_logging.so")
+    context.add_program(
+        program_id,
+        logging_elf,
+        upgradeable=True,
+        upgrade_authority=authority,
+    )
+    assert await client.get_slot() == slot_before + 1
+    programdata = await client.get_account(programdata_address)
+    assert programdata is not None
+    assert programdata.data[13:45] == bytes(authority)
+    assert programdata.data[45:] == logging_elf.read_bytes()
+    ix = Instruction(
+        program_id,
+        bytes([5, 10, 11, 12, 13, 14]),
+        [AccountMeta(Pubkey.new_unique(), is_signer=False, is_writable=True)],
+    )
+    blockhash = (await client.get_latest_blockhash())[0]
+    msg = Message.new_with_blockhash([ix], context.payer.pubkey(), blockhash)
+    meta = await client.process_transaction(VersionedTransaction(msg, [context.payer]))
+    assert "Program log: static string" in meta.log_messages
+
+
+@mark.asyncio
+async def test_account_fixtures(tmp_path: Path) -> None:
+    fixture_dir = Path("tests/fixtures/accounts")
+    fixture_address = Pubkey.from_string("9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM")
+    context = await start(accounts=[fixture_dir])
+    client = context.banks_client
+    fixture_account = await client.get_account(fixture_address)
+    assert fixture_account is not None
+    assert fixture_account.data == bytes(range(8))
+    assert fixture_account.lamports == 1461600
+    address = Pubkey.new_unique()
+    account = Account(lamports=123, data=b"hello", owner=Pubkey.new_unique())
+    context.set_account(address, account)
+    dumped = context.dump_account(address, tmp_path)
+    assert Path(dumped) == tmp_path / f"{address}.json"
+    other = await start(accounts=[(Pubkey.new_unique(), account), dumped])
+    restored = await other.banks_client.get_account(address)
+    assert restored is not None
+    assert restored.lamports == 123
+    assert restored.data == b"hello"
+    assert restored.owner == account.owner
+    loaded = other.load_accounts(fixture_dir / f"{fixture_address}.json")
+    assert [(addr, acc.data) for addr, acc in loaded] == [
+        (fixture_address, bytes(range(8)))
+    ]
+    assert await other.banks_client.get_balance(fixture_address) == 1461600
+    with raises(ValueError):
+        context.dump_account(Pubkey.new_unique(), tmp_path)
+
+
+@mark.asyncio
+async def test_start_options() -> None:
+    payer = Keypair.from_seed(bytes([7] * 32))
+    rent = Rent(burn_percent=0, exemption_threshold=2.0, lamports_per_byte_year=10)
+    context = await start(payer=payer, payer_lamports=123_000_000, rent=rent)
+    client = context.banks_client
+    assert context.payer == payer
+    assert await client.get_balance(payer.pubkey()) == 123_000_000
+    assert await client.get_rent() == rent
+    other = await start(payer_lamports=5_000_000)
+    assert await other.banks_client.get_balance(other.payer.pubkey()) == 5_000_000
+
+
+@mark.asyncio
+async def test_feature_set() -> None:
+    last_restart_slot = Pubkey.from_string("HooKD5NC9QNxk25QuzCssB8ecrEzGt6eXEPBUxWp1LaR")
+    remaining_cus = Pubkey.from_string("5TuppMutoyzhUSfuYdhgzD47F92GL1g89KpCZQKqedxP")
+    context = await start()
+    assert last_restart_slot in context.get_active_features()
+    context = await start(deactivated_features=[last_restart_slot])
+    active = context.get_active_features()
+    assert last_restart_slot not in active
+    assert remaining_cus in active
+    context = await start(activated_features=[remaining_cus])
+    assert set(context.get_active_features()) == {remaining_cus}
+    with raises(ValueError):
+        await start(deactivated_features=[Pubkey.new_unique()])
+    with raises(ValueError):
+        await start(
+            activated_features=[remaining_cus], deactivated_features=[remaining_cus]
+        )
+
+
+def test_sync_client() -> None:
+    context = start_sync()
+    client = context.sync_banks_client
+    payer = context.payer
+    receiver = Pubkey.new_unique()
+    blockhash, _ = client.get_latest_blockhash()
+    ix = transfer(
+        {"from_pubkey": payer.pubkey(), "to_pubkey": receiver, "lamports": 1_000_000}
+    )
+    msg = Message.new_with_blockhash([ix], payer.pubkey(), blockhash)
+    tx = VersionedTransaction(msg, [payer])
+    simulated = client.simulate_transaction(tx)
+    assert simulated.result is None
+    meta = client.process_transaction(tx)
+    assert meta.log_messages
+    assert client.get_balance(receiver) == 1_000_000
+    account = client.get_account(receiver)
+    assert account is not None
+    assert account.lamports == 1_000_000
+    status = client.get_transaction_status(tx.signatures[0])
+    assert status is not None
+    assert client.get_slot() == status.slot
+    assert isinstance(client.get_rent(), Rent)
+    assert isinstance(client.get_clock(), Clock)
+
+
+@mark.asyncio
+async def test_get_program_accounts() -> None:
+    program_id = Pubkey.new_unique()
+    genesis_address = Pubkey.new_unique()
+    context = await start(
+        accounts=[
+            (
+                genesis_address,
+                Account(lamports=1_000_000, data=bytes([1, 2, 3]), owner=program_id),
+            )
+        ]
+    )
+    client = context.banks_client
+    payer = context.payer
+    written_address = Pubkey.new_unique()
+    context.set_account(
+        written_address,
+        Account(lamports=1_000_000, data=bytes([1, 9, 9, 9]), owner=program_id),
+    )
+    created = Keypair()
+    ix = create_account(
+        {
+            "from_pubkey": payer.pubkey(),
+            "to_pubkey": created.pubkey(),
+            "lamports": 1_000_000,
+            "space": 4,
+            "owner": program_id,
+        }
+    )
+    msg = Message.new_with_blockhash([ix], payer.pubkey(), context.last_blockhash)
+    await client.process_transaction(VersionedTransaction(msg, [payer, created]))
+    owned = await client.get_program_accounts(program_id)
+    assert {address for address, _ in owned} == {
+        genesis_address,
+        written_address,
+        created.pubkey(),
+    }
+    sized = await client.get_program_accounts(program_id, [4])
+    assert {address for address, _ in sized} == {written_address, created.pubkey()}
+    matched = await client.get_program_accounts(
+        program_id, [4, Memcmp(offset=0, bytes_=bytes([1]))]
+    )
+    assert [address for address, _ in matched] == [written_address]
+    assert matched[0][1].data == bytes([1, 9, 9, 9])
+    missing = Pubkey.new_unique()
+    accounts = await client.get_multiple_accounts([genesis_address, missing])
+    assert accounts[0] is not None
+    assert accounts[0].data == bytes([1, 2, 3])
+    assert accounts[1] is None
+    sync_owned = context.sync_banks_client.get_program_accounts(program_id)
+    assert sync_owned == owned
+
+
+@mark.asyncio
+async def test_get_program_accounts_genesis() -> None:
+    context = await start()
+    vote_program = Pubkey.from_string("Vote111111111111111111111111111111111111111")
+    vote_accounts = await context.banks_client.get_program_accounts(vote_program)
+    assert len(vote_accounts) == 1
+    # the bundled SPL programs are stored after genesis
+    loader = Pubkey.from_string("BPFLoader2111111111111111111111111111111111")
+    upgradeable_loader = Pubkey.from_string(
+        "BPFLoaderUpgradeab1e11111111111111111111111"
+    )
+    token_program = Pubkey.from_string("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA")
+    token_2022_program = Pubkey.from_string(
+        "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+    )
+    loaded = await context.banks_client.get_program_accounts(loader)
+    assert token_program in {address for address, _ in loaded}
+    upgradeable = await context.banks_client.get_program_accounts(upgradeable_loader)
+    token_2022_programdata = Pubkey.find_program_address(
+        [bytes(token_2022_program)], upgradeable_loader
+    )[0]
+    assert {token_2022_program, token_2022_programdata} <= {
+        address for address, _ in upgradeable
+    }
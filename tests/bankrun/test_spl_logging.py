@@ -1,6 +1,7 @@
 from pytest import mark
 from solders.bankrun import start
 from solders.instruction import AccountMeta, Instruction
+from solders.instruction_decoder import build_instruction_tree_from_logs
 from solders.message import Message
 from solders.pubkey import Pubkey
 from solders.transaction import VersionedTransaction
@@ -29,3 +30,12 @@ async def test_logging() -> None:
     assert (
         meta.compute_units_consumed < 10_000
     )  # not being precise here in case it changes
+    parsed = meta.parse_logs()
+    assert parsed.success
+    (invocation,) = parsed.invocations
+    assert invocation.program_id == program_id
+    assert invocation.logs[0] == "static string"
+    assert invocation.compute_units_consumed == meta.compute_units_consumed
+    (root,) = build_instruction_tree_from_logs(tx, parsed)
+    assert root.program_id == program_id
+    assert root.children == []
@@ -0,0 +1,76 @@
+from hashlib import sha256
+
+from pytest import raises
+from solders.account import Account
+from solders.account_classifier import (
+    AccountClassifier,
+    anchor_account_discriminator,
+    discriminator_memcmp,
+)
+from solders.pubkey import Pubkey
+from solders.rpc.filter import Memcmp
+from solders.rpc.responses import RpcKeyedAccount
+
+TOKEN_PROGRAM_ID = Pubkey.from_string("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA")
+TOKEN_2022_PROGRAM_ID = Pubkey.from_string(
+    "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+)
+STAKE_PROGRAM_ID = Pubkey.from_string("Stake11111111111111111111111111111111111111")
+
+
+def test_anchor_discriminator() -> None:
+    expected = sha256(b"account:Counter").digest()[:8]
+    assert anchor_account_discriminator("Counter") == expected
+    assert discriminator_memcmp(expected) == Memcmp(0, expected)
+
+
+def test_classify_anchor() -> None:
+    program_id = Pubkey.new_unique()
+    classifier = AccountClassifier()
+    classifier.register_anchor("Counter", owner=program_id)
+    classifier.register("Config", b"\x07", offset=2)
+    data = anchor_account_discriminator("Counter") + bytes(8)
+    account = Account(1, data, program_id)
+    assert classifier.classify(account) == "Counter"
+    assert classifier.classify(RpcKeyedAccount(Pubkey.new_unique(), account)) == (
+        "Counter"
+    )
+    # owner-scoped types don't match other owners or bare data
+    assert classifier.classify(Account(1, data, Pubkey.new_unique())) is None
+    assert classifier.classify(data) is None
+    assert classifier.classify(data, owner=program_id) == "Counter"
+    assert classifier.classify(bytes([0, 0, 7])) == "Config"
+    assert classifier.memcmp("Counter") == Memcmp(
+        0, anchor_account_discriminator("Counter")
+    )
+    assert classifier.filters("Config") == [Memcmp(2, b"\x07")]
+    with raises(ValueError, match="Unknown"):
+        classifier.memcmp("Missing")
+
+
+def test_classify_native() -> None:
+    classifier = AccountClassifier()
+    assert classifier.classify(Account(1, bytes(82), TOKEN_PROGRAM_ID)) == "Token::Mint"
+    assert (
+        classifier.classify(Account(1, bytes(165), TOKEN_PROGRAM_ID))
+        == "Token::Account"
+    )
+    # an account with the ImmutableOwner extension: account type, then an empty TLV entry
+    immutable_owner = (7).to_bytes(2, "little") + (0).to_bytes(2, "little")
+    extended = bytes(165) + bytes([2]) + immutable_owner
+    assert (
+        classifier.classify(Account(1, extended, TOKEN_2022_PROGRAM_ID))
+        == "Token2022::Account"
+    )
+    # a multisig whose sixth signer happens to have the account type byte set
+    multisig = bytes(165) + bytes([2]) + bytes(189)
+    assert (
+        classifier.classify(Account(1, multisig, TOKEN_2022_PROGRAM_ID))
+        == "Token2022::Multisig"
+    )
+    stake = (2).to_bytes(4, "little") + bytes(196)
+    assert classifier.classify(Account(1, stake, STAKE_PROGRAM_ID)) == "Stake::Stake"
+    assert classifier.filters("Token::Account") == [165]
+    with raises(ValueError, match="data size"):
+        classifier.memcmp("Token::Mint")
+    assert AccountClassifier(include_native=False).names() == []
@@ -0,0 +1,322 @@
+import json
+from base64 import b64encode
+from hashlib import sha256
+
+from pytest import raises
+from solders.anchor_idl import EventDecoder, Idl
+from solders.errors import InstructionDecodeError
+from solders.instruction import AccountMeta, Instruction
+from solders.pubkey import Pubkey
+from solders.system_program import ID as SYSTEM_PROGRAM_ID
+
+PROGRAM_ID = Pubkey.from_string("Fg6PaFpoGXkYsidMpWTK6W2BeZ7FEfcYkg476zPFsLnS")
+
+LEGACY_IDL = {
+    "version": "0.1.0",
+    "name": "my_program",
+    "instructions": [
+        {
+            "name": "setData",
+            "accounts": [
+                {"name": "dataAccount", "isMut": True, "isSigner": False},
+                {
+                    "name": "auth",
+                    "accounts": [
+                        {"name": "authority", "isMut": False, "isSigner": True},
+                    ],
+                },
+                {
+                    "name": "referrer",
+                    "isMut": False,
+                    "isSigner": False,
+                    "isOptional": True,
+                },
+            ],
+            "args": [
+                {"name": "amount", "type": "u128"},
+                {"name": "label", "type": "string"},
+                {"name": "mode", "type": {"defined": "Mode"}},
+                {"name": "tags", "type": {"vec": "u16"}},
+                {"name": "owner", "type": {"option": "publicKey"}},
+                {"name": "seed", "type": {"array": ["u8", 4]}},
+            ],
+        }
+    ],
+    "accounts": [
+        {
+            "name": "DataAccount",
+            "type": {
+                "kind": "struct",
+                "fields": [
+                    {"name": "value", "type": "i64"},
+                    {"name": "mode", "type": {"defined": "Mode"}},
+                ],
+            },
+        }
+    ],
+    "types": [
+        {
+            "name": "Mode",
+            "type": {
+                "kind": "enum",
+                "variants": [
+                    {"name": "Off"},
+                    {"name": "Fixed", "fields": ["u8", "bool"]},
+                    {"name": "Ranged", "fields": [{"name": "lo", "type": "u32"}]},
+                ],
+            },
+        }
+    ],
+    "metadata": {"address": str(PROGRAM_ID)},
+}
+
+NEW_IDL = {
+    "address": str(PROGRAM_ID),
+    "metadata": {"name": "my_program", "version": "0.1.0", "spec": "0.1.0"},
+    "instructions": [
+        {
+            "name": "initialize",
+            "discriminator": [1, 2, 3, 4, 5, 6, 7, 8],
+            "accounts": [
+                {"name": "counter", "writable": True, "signer": True},
+                {"name": "payer", "writable": True, "signer": True},
+                {
+                    "name": "system_program",
+                    "address": "11111111111111111111111111111111",
+                },
+            ],
+            "args": [{"name": "start", "type": {"defined": {"name": "Start"}}}],
+        }
+    ],
+    "accounts": [{"name": "Counter", "discriminator": [9, 9, 9, 9, 9, 9, 9, 9]}],
+    "types": [
+        {
+            "name": "Start",
+            "type": {"kind": "struct", "fields": ["u64"]},
+        },
+        {
+            "name": "Counter",
+            "type": {
+                "kind": "struct",
+                "fields": [
+                    {"name": "count", "type": "u64"},
+                    {"name": "authority", "type": "pubkey"},
+                ],
+            },
+        },
+    ],
+}
+
+
+def sighash(namespace: str, name: str) -> bytes:
+    return sha256(f"{namespace}:{name}".encode()).digest()[:8]
+
+
+def test_legacy_idl() -> None:
+    idl = Idl.from_json(json.dumps(LEGACY_IDL))
+    assert idl.is_legacy
+    assert idl.address == PROGRAM_ID
+    assert idl.name == "my_program"
+    assert idl.instruction_names() == ["setData"]
+    assert idl.account_names() == ["DataAccount"]
+    assert idl.type_names() == ["DataAccount", "Mode"]
+    assert idl.instruction_discriminator("set_data") == sighash("global", "set_data")
+    assert idl.account_discriminator("DataAccount") == sighash(
+        "account", "DataAccount"
+    )
+
+
+def test_legacy_instruction_roundtrip() -> None:
+    idl = Idl.from_json(json.dumps(LEGACY_IDL))
+    owner = Pubkey.new_unique()
+    args = {
+        "amount": 2**100,
+        "label": "hello",
+        "mode": {"Fixed": (7, True)},
+        "tags": [1, 2, 3],
+        "owner": owner,
+        "seed": [1, 2, 3, 4],
+    }
+    data = idl.encode_instruction_data("setData", args)
+    assert data[:8] == sighash("global", "set_data")
+    assert data[8:24] == (2**100).to_bytes(16, "little")
+    name, decoded = idl.decode_instruction(data)
+    assert name == "setData"
+    assert decoded == args
+    args["owner"] = None
+    args["mode"] = "Off"
+    _, decoded_none = idl.decode_instruction(idl.encode_instruction_data("setData", args))
+    assert decoded_none["owner"] is None
+    assert decoded_none["mode"] == {"Off": None}
+    with raises(InstructionDecodeError):
+        idl.decode_instruction(bytes(8))
+    with raises(ValueError):
+        idl.encode_instruction_data("setData", {"amount": 1})
+
+
+def test_build_instruction() -> None:
+    idl = Idl.from_json(json.dumps(LEGACY_IDL))
+    data_account = Pubkey.new_unique()
+    authority = Pubkey.new_unique()
+    extra = AccountMeta(Pubkey.new_unique(), is_signer=False, is_writable=True)
+    args = {
+        "amount": 1,
+        "label": "",
+        "mode": {"Ranged": {"lo": 5}},
+        "tags": [],
+        "owner": None,
+        "seed": bytes(4),
+    }
+    ix = idl.build_instruction(
+        "set_data",
+        args,
+        {"data_account": data_account, "auth": {"authority": authority}},
+        remaining_accounts=[extra],
+    )
+    assert ix.program_id == PROGRAM_ID
+    assert ix.accounts == [
+        AccountMeta(data_account, is_signer=False, is_writable=True),
+        AccountMeta(authority, is_signer=True, is_writable=False),
+        AccountMeta(PROGRAM_ID, is_signer=False, is_writable=False),
+        extra,
+    ]
+    assert ix.data == idl.encode_instruction_data("setData", args)
+    with raises(ValueError, match="Missing account"):
+        idl.build_instruction("setData", args, {"auth": {"authority": authority}})
+
+
+def test_legacy_account_roundtrip() -> None:
+    idl = Idl.from_json(json.dumps(LEGACY_IDL))
+    value = {"value": -5, "mode": {"Off": None}}
+    data = idl.encode_account("DataAccount", value)
+    assert idl.decode_account(data) == ("DataAccount", value)
+    # accounts are often allocated with extra space
+    assert idl.decode_account(data + bytes(10), "DataAccount") == ("DataAccount", value)
+    with raises(ValueError):
+        idl.decode_account(bytes(16))
+    assert idl.decode_type("Mode", idl.encode_type("Mode", "Off")) == {"Off": None}
+
+
+def test_new_idl() -> None:
+    idl = Idl.from_json(json.dumps(NEW_IDL))
+    assert not idl.is_legacy
+    assert idl.version == "0.1.0"
+    counter = Pubkey.new_unique()
+    payer = Pubkey.new_unique()
+    ix = idl.build_instruction(
+        "initialize", {"start": [42]}, {"counter": counter, "payer": payer}
+    )
+    assert ix.data == bytes([1, 2, 3, 4, 5, 6, 7, 8]) + (42).to_bytes(8, "little")
+    assert ix.accounts[2] == AccountMeta(
+        SYSTEM_PROGRAM_ID, is_signer=False, is_writable=False
+    )
+    assert idl.decode_instruction(ix.data) == ("initialize", {"start": (42,)})
+    authority = Pubkey.new_unique()
+    data = idl.encode_account("Counter", {"count": 3, "authority": authority})
+    assert data[:8] == bytes([9] * 8)
+    assert idl.decode_account(data) == (
+        "Counter",
+        {"count": 3, "authority": authority},
+    )
+
+
+def test_unsupported_generics() -> None:
+    raw = json.loads(json.dumps(NEW_IDL))
+    raw["instructions"][0]["args"][0]["type"] = {
+        "defined": {"name": "Start", "generics": [{"kind": "type", "type": "u8"}]}
+    }
+    with raises(ValueError, match="generic"):
+        Idl.from_json(json.dumps(raw))
+
+
+def test_recursive_types() -> None:
+    def defined(name: str) -> dict:
+        return {"defined": {"name": name}}
+
+    raw = json.loads(json.dumps(NEW_IDL))
+    raw["types"] += [
+        {"name": "Ping", "type": {"kind": "type", "alias": defined("Pong")}},
+        {"name": "Pong", "type": {"kind": "type", "alias": defined("Ping")}},
+        {
+            "name": "Node",
+            "type": {
+                "kind": "struct",
+                "fields": [{"name": "children", "type": {"vec": defined("Node")}}],
+            },
+        },
+    ]
+    idl = Idl.from_json(json.dumps(raw))
+    with raises(ValueError, match="levels deep"):
+        idl.encode_type("Ping", 1)
+    with raises(ValueError, match="levels deep"):
+        idl.decode_type("Ping", bytes(8))
+    tree = {"children": [{"children": []}, {"children": [{"children": []}]}]}
+    assert idl.decode_type("Node", idl.encode_type("Node", tree)) == tree
+    # Each level of a single-child chain is a four byte length of 1.
+    with raises(ValueError, match="levels deep"):
+        idl.decode_type("Node", (1).to_bytes(4, "little") * 100_000)
+
+
+def test_decode_events() -> None:
+    raw = json.loads(json.dumps(LEGACY_IDL))
+    raw["events"] = [
+        {
+            "name": "DataSet",
+            "fields": [
+                {"name": "value", "type": "i64", "index": False},
+                {"name": "mode", "type": {"defined": "Mode"}, "index": False},
+            ],
+        }
+    ]
+    idl = Idl.from_json(json.dumps(raw))
+    assert idl.event_names() == ["DataSet"]
+    decoder = EventDecoder()
+    decoder.add_idl(idl)
+    other = Pubkey.new_unique()
+    decoder.register(b"\x05" * 8, "Other", lambda data: data[0], program_id=other)
+    first = sighash("event", "DataSet") + (7).to_bytes(8, "little") + bytes([0])
+    second = sighash("event", "DataSet") + (-1).to_bytes(8, "little", signed=True)
+    second += bytes([1, 3, 1])
+    logs = [
+        f"Program {PROGRAM_ID} invoke [1]",
+        f"Program data: {b64encode(first).decode()}",
+        f"Program {other} invoke [2]",
+        f"Program data: {b64encode(bytes([5] * 8 + [9])).decode()}",
+        f"Program {other} success",
+        f"Program data: {b64encode(second).decode()}",
+        f"Program {PROGRAM_ID} success",
+    ]
+    events = decoder.decode_logs(logs)
+    assert [(e.name, e.data, e.program_id) for e in events] == [
+        ("DataSet", {"value": 7, "mode": {"Off": None}}, PROGRAM_ID),
+        ("Other", 9, other),
+        ("DataSet", {"value": -1, "mode": {"Fixed": (3, True)}}, PROGRAM_ID),
+    ]
+    # the same payload emitted by an unregistered program is ignored
+    assert decoder.decode_logs([logs[2], logs[1], logs[4]]) == []
+    tag = bytes([0xE4, 0x45, 0xA5, 0x2E, 0x51, 0xCB, 0x9A, 0x1D])
+    ixs = [
+        Instruction(PROGRAM_ID, tag + first, []),
+        Instruction(PROGRAM_ID, first, []),
+    ]
+    assert decoder.decode_instructions(ixs) == events[:1]
+
+
+def test_register_event_schema() -> None:
+    decoder = EventDecoder()
+    decoder.register(
+        bytes([1, 2]),
+        "Ping",
+        {"kind": "struct", "fields": [{"name": "n", "type": "u16"}]},
+    )
+    program_id = Pubkey.new_unique()
+    logs = [
+        f"Program {program_id} invoke [1]",
+        f"Program data: {b64encode(bytes([1, 2, 0, 1])).decode()}",
+        f"Program {program_id} success",
+    ]
+    assert [(e.name, e.data) for e in decoder.decode_logs(logs)] == [
+        ("Ping", {"n": 256})
+    ]
+    with raises(ValueError):
+        decoder.register(bytes([3]), "Bad", {"kind": "nope"})
@@ -0,0 +1,87 @@
+from pytest import raises
+from solders.account_decoder import UiTokenAmount
+from solders.hash import Hash
+from solders.keypair import Keypair
+from solders.message import Message
+from solders.pubkey import Pubkey
+from solders.system_program import TransferParams, transfer
+from solders.transaction import VersionedTransaction
+from solders.transaction_status import (
+    BalanceChanges,
+    EncodedTransactionWithStatusMeta,
+    UiLoadedAddresses,
+    UiTransactionStatusMeta,
+    UiTransactionTokenBalance,
+)
+
+MINT = Pubkey.new_unique()
+
+
+def token_balance(index: int, owner: Pubkey, amount: int) -> UiTransactionTokenBalance:
+    ui_amount = UiTokenAmount(amount / 100, 2, str(amount), str(amount / 100))
+    return UiTransactionTokenBalance(index, MINT, ui_amount, owner, None)
+
+
+def test_balance_changes_with_loaded_addresses() -> None:
+    payer, receiver, token_acc = Pubkey.new_unique(), Pubkey.new_unique(), Pubkey.new_unique()
+    loaded_writable, loaded_readonly = Pubkey.new_unique(), Pubkey.new_unique()
+    alice, bob = Pubkey.new_unique(), Pubkey.new_unique()
+    meta = UiTransactionStatusMeta(
+        err=None,
+        fee=5000,
+        pre_balances=[1_000_000, 0, 10, 20, 30],
+        post_balances=[894_000, 100_000, 10, 21, 30],
+        pre_token_balances=[token_balance(2, alice, 500)],
+        post_token_balances=[token_balance(2, alice, 300), token_balance(3, bob, 200)],
+        loaded_addresses=UiLoadedAddresses([loaded_writable], [loaded_readonly]),
+    )
+    changes = meta.balance_changes([payer, receiver, token_acc])
+    assert changes.fee_payer == payer
+    assert changes.fee == 5000
+    assert [c.pubkey for c in changes.sol] == [
+        payer,
+        receiver,
+        token_acc,
+        loaded_writable,
+        loaded_readonly,
+    ]
+    payer_change = changes.sol_change(payer)
+    assert payer_change is not None
+    assert payer_change.delta == -106_000
+    assert payer_change.delta_excluding_fee == -101_000
+    receiver_change = changes.sol_change(receiver)
+    assert receiver_change is not None
+    assert receiver_change.delta == receiver_change.delta_excluding_fee == 100_000
+    (alice_change,) = changes.token_changes(alice)
+    assert alice_change.mint == MINT
+    assert alice_change.decimals == 2
+    assert alice_change.delta == -200
+    assert alice_change.ui_delta == -2.0
+    (bob_change,) = changes.token_changes(bob)
+    assert (bob_change.pre_amount, bob_change.post_amount) == (0, 200)
+    assert BalanceChanges.from_json(changes.to_json()) == changes
+    with raises(ValueError):
+        meta.balance_changes([payer])
+
+
+def test_encoded_transaction_balance_changes() -> None:
+    payer = Keypair()
+    receiver = Pubkey.new_unique()
+    ix = transfer(
+        TransferParams(from_pubkey=payer.pubkey(), to_pubkey=receiver, lamports=10)
+    )
+    msg = Message.new_with_blockhash([ix], payer.pubkey(), Hash.default())
+    tx = VersionedTransaction(msg, [payer])
+    meta = UiTransactionStatusMeta(
+        err=None,
+        fee=5000,
+        pre_balances=[100_000, 0, 1],
+        post_balances=[94_990, 10, 1],
+    )
+    encoded = EncodedTransactionWithStatusMeta(tx, meta, None)
+    changes = encoded.balance_changes()
+    assert changes == meta.balance_changes(list(msg.account_keys))
+    assert changes.sol[1].delta == 10
+    assert changes.token == []
+    with raises(ValueError):
+        EncodedTransactionWithStatusMeta(tx, None, None).balance_changes()
@@ -0,0 +1,83 @@
+from pytest import mark, raises
+from solders.borsh import BorshSchema
+from solders.hash import Hash
+from solders.instruction import AccountMeta, Instruction
+from solders.pubkey import Pubkey
+from solders.signature import Signature
+
+
+@mark.parametrize(
+    "obj", [Pubkey.new_unique(), Hash.new_unique(), Signature.new_unique()]
+)
+def test_fixed_size_borsh(obj) -> None:
+    assert obj.to_borsh() == bytes(obj)
+    assert type(obj).from_borsh(obj.to_borsh()) == obj
+
+
+def test_instruction_borsh() -> None:
+    program_id = Pubkey.new_unique()
+    meta = AccountMeta(Pubkey.new_unique(), is_signer=True, is_writable=False)
+    ix = Instruction(program_id, bytes([1, 2, 3]), [meta])
+    expected = (
+        bytes(program_id)
+        + (1).to_bytes(4, "little")
+        + bytes(meta.pubkey)
+        + bytes([1, 0])
+        + (3).to_bytes(4, "little")
+        + bytes([1, 2, 3])
+    )
+    assert ix.to_borsh() == expected
+    assert Instruction.from_borsh(expected) == ix
+    assert AccountMeta.from_borsh(meta.to_borsh()) == meta
+    with raises(ValueError):
+        Instruction.from_borsh(expected + b"\x00")
+
+
+def test_schema_roundtrip() -> None:
+    owner = Pubkey.new_unique()
+    schema = BorshSchema(
+        {
+            "kind": "struct",
+            "fields": [
+                {"name": "amount", "type": "u128"},
+                {"name": "delta", "type": "i128"},
+                {"name": "owner", "type": {"option": "pubkey"}},
+                {"name": "seed", "type": {"array": ["u8", 3]}},
+                {"name": "orders", "type": {"vec": {"defined": "Order"}}},
+            ],
+        },
+        {
+            "Order": {
+                "kind": "enum",
+                "variants": [
+                    {"name": "Cancel"},
+                    {"name": "Limit", "fields": [{"name": "price", "type": "u64"}]},
+                ],
+            }
+        },
+    )
+    value = {
+        "amount": 2**100,
+        "delta": -(2**90),
+        "owner": owner,
+        "seed": [1, 2, 3],
+        "orders": ["Cancel", {"Limit": {"price": 7}}],
+    }
+    data = schema.encode(value)
+    assert schema.decode(data) == {
+        **value,
+        "orders": [{"Cancel": None}, {"Limit": {"price": 7}}],
+    }
+    with raises(ValueError, match="left over"):
+        schema.decode(data + b"\x00")
+    assert schema.decode(data + b"\x00", strict=False)["owner"] == owner
+
+
+def test_schema_primitive() -> None:
+    schema = BorshSchema({"vec": "string"})
+    data = schema.encode(["a", "bc"])
+    assert data == bytes([2, 0, 0, 0, 1, 0, 0, 0, 97, 2, 0, 0, 0, 98, 99])
+    assert schema.decode(data) == ["a", "bc"]
+    assert BorshSchema("u16").decode(bytes([1, 1])) == 257
+    with raises(ValueError):
+        BorshSchema("u16").encode(-1)
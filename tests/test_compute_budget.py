@@ -1,8 +1,12 @@
+from pytest import raises
 from solders.compute_budget import (
+    ID,
+    decode_instruction,
     request_heap_frame,
     set_compute_unit_limit,
     set_compute_unit_price,
 )
+from solders.errors import InstructionDecodeError
 from solders.instruction import Instruction
 
 
@@ -10,3 +14,21 @@ def test_compute_budget() -> None:
     assert isinstance(request_heap_frame(2048), Instruction)
     assert isinstance(set_compute_unit_limit(1_000_000), Instruction)
     assert isinstance(set_compute_unit_price(1000), Instruction)
+
+
+def test_decode_instruction() -> None:
+    assert decode_instruction(set_compute_unit_limit(1_000_000)) == (
+        "SetComputeUnitLimit",
+        {"units": 1_000_000},
+    )
+    assert decode_instruction(set_compute_unit_price(1000)) == (
+        "SetComputeUnitPrice",
+        {"micro_lamports": 1000},
+    )
+    assert decode_instruction(request_heap_frame(2048)) == (
+        "RequestHeapFrame",
+        {"bytes": 2048},
+    )
+    bad = Instruction(ID, b"\xff", [])
+    with raises(InstructionDecodeError):
+        decode_instruction(bad)
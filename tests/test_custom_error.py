@@ -0,0 +1,165 @@
+import json
+
+from pytest import raises
+from solders.custom_error import (
+    CustomErrorResolver,
+    ResolvedError,
+    get_default_resolver,
+    set_default_resolver,
+)
+from solders.pubkey import Pubkey
+from solders.rpc.errors import SendTransactionPreflightFailure
+from solders.rpc.responses import RpcSimulateTransactionResult
+from solders.system_program import ID as SYSTEM_PROGRAM_ID
+from solders.transaction_status import (
+    InstructionErrorCustom,
+    InstructionErrorFieldless,
+    TransactionErrorInstructionError,
+)
+
+TOKEN_PROGRAM_ID = Pubkey.from_string("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA")
+TOKEN_2022_PROGRAM_ID = Pubkey.from_string(
+    "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb"
+)
+
+
+def test_builtin_tables() -> None:
+    resolver = CustomErrorResolver()
+    assert resolver.resolve(TOKEN_PROGRAM_ID, 1) == ResolvedError(
+        TOKEN_PROGRAM_ID, 1, "TokenError::InsufficientFunds", "Insufficient funds"
+    )
+    system_err = resolver.resolve(SYSTEM_PROGRAM_ID, 1)
+    assert system_err is not None
+    assert system_err.name == "SystemError::ResultWithNegativeLamports"
+    token_2022_err = resolver.resolve(TOKEN_2022_PROGRAM_ID, 37)
+    assert token_2022_err is not None
+    assert token_2022_err.name == "TokenError::NonTransferable"
+    # Token-2022 only codes are unknown to the original token program.
+    assert resolver.resolve(TOKEN_PROGRAM_ID, 37) is None
+    # native programs don't fall back to Anchor errors
+    assert resolver.resolve(SYSTEM_PROGRAM_ID, 2000) is None
+    assert CustomErrorResolver.empty().resolve(TOKEN_PROGRAM_ID, 1) is None
+
+
+def test_anchor_fallback() -> None:
+    resolver = CustomErrorResolver()
+    program_id = Pubkey.new_unique()
+    # unregistered programs aren't assumed to be Anchor programs
+    assert resolver.resolve(program_id, 3012) is None
+    resolver.register(program_id, 6000, "MyError::Bad", "bad")
+    assert resolver.resolve(program_id, 3012) is None
+    resolver.register_anchor_program(program_id)
+    resolved = resolver.resolve(program_id, 3012)
+    assert resolved is not None
+    assert resolved.name == "AnchorError::AccountNotInitialized"
+    registered = resolver.resolve(program_id, 6000)
+    assert registered is not None
+    assert registered.name == "MyError::Bad"
+    assert resolver.resolve(program_id, 6001) is None
+
+
+def test_register_anchor_idl() -> None:
+    program_id = Pubkey.new_unique()
+    legacy_idl = {
+        "version": "0.1.0",
+        "name": "my_program",
+        "instructions": [],
+        "errors": [{"code": 6000, "name": "Unauthorized", "msg": "Not allowed"}],
+        "metadata": {"address": str(program_id)},
+    }
+    new_idl = {
+        "address": str(program_id),
+        "metadata": {"name": "my_program", "version": "0.1.0", "spec": "0.1.0"},
+        "instructions": [],
+        "errors": [{"code": 6000, "name": "Unauthorized", "msg": "Not allowed"}],
+    }
+    for idl in (legacy_idl, new_idl):
+        resolver = CustomErrorResolver()
+        assert resolver.register_anchor_idl(json.dumps(idl)) == program_id
+        assert program_id in resolver
+        resolved = resolver.resolve(program_id, 6000)
+        assert resolved is not None
+        assert resolved.describe() == "MyProgramError::Unauthorized: Not allowed"
+        fallback = resolver.resolve(program_id, 2000)
+        assert fallback is not None
+        assert fallback.name == "AnchorError::ConstraintMut"
+    del new_idl["address"]
+    with raises(ValueError):
+        CustomErrorResolver().register_anchor_idl(json.dumps(new_idl))
+    other = Pubkey.new_unique()
+    assert (
+        CustomErrorResolver().register_anchor_idl(json.dumps(new_idl), other) == other
+    )
+
+
+def test_register() -> None:
+    resolver = CustomErrorResolver.empty()
+    program_id = Pubkey.new_unique()
+    resolver.register(program_id, 42, "MyError::Bad", "bad")
+    assert resolver.program_ids() == [program_id]
+    assert len(resolver) == 1
+    resolved = resolver.resolve(program_id, 42)
+    assert resolved is not None
+    assert resolved.message == "bad"
+    assert resolver.unregister(program_id)
+    assert not resolver.unregister(program_id)
+
+
+def test_instruction_error() -> None:
+    err = TransactionErrorInstructionError(0, InstructionErrorCustom(1))
+    assert (
+        err.describe(TOKEN_PROGRAM_ID)
+        == "Error processing Instruction 0: TokenError::InsufficientFunds: Insufficient funds"
+    )
+    resolved = err.resolve(TOKEN_PROGRAM_ID)
+    assert resolved is not None
+    assert resolved.code == 1
+    assert err.resolve(TOKEN_PROGRAM_ID, CustomErrorResolver.empty()) is None
+    assert (
+        err.describe(TOKEN_PROGRAM_ID, CustomErrorResolver.empty())
+        == "Error processing Instruction 0: custom program error: 0x1"
+    )
+    fieldless = TransactionErrorInstructionError(
+        1, InstructionErrorFieldless.InvalidArgument
+    )
+    assert fieldless.resolve(TOKEN_PROGRAM_ID) is None
+
+
+def test_default_resolver() -> None:
+    program_id = Pubkey.new_unique()
+    resolver = get_default_resolver()
+    resolver.register(program_id, 6001, "MyError::Custom", "custom")
+    err = TransactionErrorInstructionError(0, InstructionErrorCustom(6001))
+    assert err.resolve(program_id) is None
+    original = get_default_resolver()
+    set_default_resolver(resolver)
+    try:
+        assert err.describe(program_id).endswith("MyError::Custom: custom")
+    finally:
+        set_default_resolver(original)
+
+
+def test_describe_from_logs() -> None:
+    outer = Pubkey.new_unique()
+    err = TransactionErrorInstructionError(0, InstructionErrorCustom(1))
+    logs = [
+        f"Program {outer} invoke [1]",
+        f"Program {TOKEN_PROGRAM_ID} invoke [2]",
+        "Program log: Error: insufficient funds",
+        f"Program {TOKEN_PROGRAM_ID} failed: custom program error: 0x1",
+        f"Program {outer} failed: custom program error: 0x1",
+    ]
+    # the error is named after the inner token program, not the top-level program
+    failure = SendTransactionPreflightFailure(
+        "Transaction simulation failed",
+        RpcSimulateTransactionResult(err=err, logs=logs),
+    )
+    assert (
+        failure.describe()
+        == "Error processing Instruction 0: TokenError::InsufficientFunds: Insufficient funds"
+    )
+    # without logs naming the failing program, the raw error is described
+    no_logs = SendTransactionPreflightFailure(
+        "Transaction simulation failed", RpcSimulateTransactionResult(err=err)
+    )
+    assert no_logs.describe() == "Error processing Instruction 0: custom program error: 0x1"
@@ -0,0 +1,220 @@
+from typing import Any, List, Tuple
+
+from based58 import b58encode
+from pytest import raises
+from solders import system_program as sp
+from solders.compute_budget import ID as COMPUTE_BUDGET_ID
+from solders.compute_budget import set_compute_unit_limit
+from solders.errors import InstructionDecodeError
+from solders.hash import Hash
+from solders.instruction import AccountMeta, Instruction
+from solders.instruction_decoder import (
+    DecodedInstruction,
+    InstructionDecoderRegistry,
+    InstructionNode,
+    UnknownInstruction,
+    build_instruction_tree,
+    build_instruction_tree_from_logs,
+)
+from solders.keypair import Keypair
+from solders.log_parser import parse_logs
+from solders.message import Message, MessageV0
+from solders.pubkey import Pubkey
+from solders.transaction import Transaction, VersionedTransaction
+from solders.transaction_status import (
+    EncodedTransactionWithStatusMeta,
+    ParsedAccount,
+    ParsedInstruction,
+    UiCompiledInstruction,
+    UiInnerInstructions,
+    UiParsedMessage,
+    UiTransaction,
+    UiTransactionStatusMeta,
+)
+
+MY_PROGRAM = Pubkey.new_unique()
+
+
+def my_decoder(data: bytes, accounts: List[Pubkey]) -> Tuple[str, Any]:
+    if data[0] != 7:
+        raise InstructionDecodeError("bad tag")
+    return "DoThing", {"amount": data[1], "user": accounts[0]}
+
+
+def test_builtin_decoders() -> None:
+    registry = InstructionDecoderRegistry()
+    assert sp.ID in registry
+    assert COMPUTE_BUDGET_ID in registry
+    assert len(registry) == 3
+    assert len(InstructionDecoderRegistry.empty()) == 0
+    sender, receiver = Pubkey.new_unique(), Pubkey.new_unique()
+    ix = sp.transfer(
+        sp.TransferParams(from_pubkey=sender, to_pubkey=receiver, lamports=5)
+    )
+    decoded = registry.decode_instruction(ix)
+    assert isinstance(decoded, DecodedInstruction)
+    assert decoded.program_id == sp.ID
+    assert decoded.name == "Transfer"
+    assert decoded.params["lamports"] == 5
+    assert decoded.accounts == [sender, receiver]
+
+
+def test_custom_decoder() -> None:
+    registry = InstructionDecoderRegistry()
+    user = Pubkey.new_unique()
+    ix = Instruction(MY_PROGRAM, bytes([7, 42]), [AccountMeta(user, True, False)])
+    unknown = registry.decode_instruction(ix)
+    assert unknown == UnknownInstruction(MY_PROGRAM, bytes([7, 42]), [user])
+    assert unknown.error is None
+    registry.register(MY_PROGRAM, my_decoder)
+    assert MY_PROGRAM in registry.program_ids()
+    decoded = registry.decode_instruction(ix)
+    assert isinstance(decoded, DecodedInstruction)
+    assert decoded.name == "DoThing"
+    assert decoded.params == {"amount": 42, "user": user}
+    bad = registry.decode_instruction(Instruction(MY_PROGRAM, bytes([1]), []))
+    assert isinstance(bad, UnknownInstruction)
+    assert bad.error == "bad tag"
+    assert registry.unregister(MY_PROGRAM)
+    assert not registry.unregister(MY_PROGRAM)
+    with raises(TypeError):
+        registry.register(MY_PROGRAM, 1)  # type: ignore
+
+
+def test_decoder_exceptions_propagate() -> None:
+    def broken(data: bytes, accounts: List[Pubkey]) -> Tuple[str, Any]:
+        raise RuntimeError("boom")
+
+    registry = InstructionDecoderRegistry.empty()
+    registry.register(MY_PROGRAM, broken)
+    with raises(RuntimeError):
+        registry.decode_instruction(Instruction(MY_PROGRAM, b"", []))
+
+
+def test_decode_transaction() -> None:
+    registry = InstructionDecoderRegistry()
+    registry.register(MY_PROGRAM, my_decoder)
+    payer = Keypair()
+    receiver = Pubkey.new_unique()
+    ixs = [
+        set_compute_unit_limit(200_000),
+        sp.transfer(
+            sp.TransferParams(
+                from_pubkey=payer.pubkey(), to_pubkey=receiver, lamports=10
+            )
+        ),
+        Instruction(MY_PROGRAM, bytes([7, 1]), [AccountMeta(receiver, False, True)]),
+        Instruction(Pubkey.new_unique(), b"\x01\x02", []),
+    ]
+    blockhash = Hash.default()
+    legacy = Transaction([payer], Message(ixs, payer.pubkey()), blockhash)
+    versioned = VersionedTransaction(
+        MessageV0.try_compile(payer.pubkey(), ixs, [], blockhash), [payer]
+    )
+    for tx in (legacy, versioned):
+        decoded = registry.decode_transaction(tx)
+        assert [type(d) for d in decoded] == [
+            DecodedInstruction,
+            DecodedInstruction,
+            DecodedInstruction,
+            UnknownInstruction,
+        ]
+        first, second, third, fourth = decoded
+        assert isinstance(first, DecodedInstruction)
+        assert (first.name, first.params) == (
+            "SetComputeUnitLimit",
+            {"units": 200_000},
+        )
+        assert isinstance(second, DecodedInstruction)
+        assert second.params["to_pubkey"] == receiver
+        assert isinstance(third, DecodedInstruction)
+        assert third.params == {"amount": 1, "user": receiver}
+        assert isinstance(fourth, UnknownInstruction)
+        assert fourth.data == b"\x01\x02"
+
+
+def test_decode_json_parsed_transaction() -> None:
+    payer = Keypair()
+    receiver = Pubkey.new_unique()
+    info = {"source": str(payer.pubkey()), "destination": str(receiver)}
+    parsed = ParsedInstruction(
+        "system", sp.ID, {"type": "transfer", "info": {**info, "lamports": 10}}
+    )
+    message = UiParsedMessage(
+        [
+            ParsedAccount(payer.pubkey(), True, True),
+            ParsedAccount(receiver, True, False),
+            ParsedAccount(sp.ID, False, False),
+        ],
+        Hash.default(),
+        [parsed],
+        None,
+    )
+    tx = UiTransaction([payer.sign_message(b"")], message)
+    (decoded,) = InstructionDecoderRegistry().decode_transaction(tx)
+    assert isinstance(decoded, DecodedInstruction)
+    assert (decoded.program_id, decoded.name) == (sp.ID, "transfer")
+    assert decoded.params == {**info, "lamports": 10}
+    assert decoded.accounts == []
+
+
+def test_build_instruction_tree() -> None:
+    payer = Keypair()
+    receiver = Pubkey.new_unique()
+    outer_ix = Instruction(
+        MY_PROGRAM,
+        bytes([7, 1]),
+        [AccountMeta(receiver, False, True), AccountMeta(sp.ID, False, False)],
+    )
+    msg = Message.new_with_blockhash([outer_ix], payer.pubkey(), Hash.default())
+    tx = VersionedTransaction(msg, [payer])
+    keys = msg.account_keys
+    inner_transfer = sp.transfer(
+        sp.TransferParams(from_pubkey=payer.pubkey(), to_pubkey=receiver, lamports=3)
+    )
+    compiled_transfer = UiCompiledInstruction(
+        keys.index(sp.ID),
+        bytes([keys.index(payer.pubkey()), keys.index(receiver)]),
+        b58encode(bytes(inner_transfer.data)).decode(),
+        2,
+    )
+    meta = UiTransactionStatusMeta(
+        err=None,
+        fee=5000,
+        pre_balances=[0] * len(keys),
+        post_balances=[0] * len(keys),
+        inner_instructions=[UiInnerInstructions(0, [compiled_transfer])],
+    )
+    (root,) = build_instruction_tree(EncodedTransactionWithStatusMeta(tx, meta, None))
+    assert root.program_id == MY_PROGRAM
+    assert root.stack_height == 1
+    assert root.data == bytes([7, 1])
+    (child,) = root.children
+    assert child.program_id == sp.ID
+    assert child.stack_height == 2
+    assert child.accounts == [payer.pubkey(), receiver]
+    assert child.data == bytes(inner_transfer.data)
+    assert root.find(sp.ID) == [child]
+    assert root.walk_all() == [root, child]
+    assert InstructionNode.from_json(root.to_json()) == root
+
+
+def test_build_instruction_tree_from_logs() -> None:
+    payer = Keypair()
+    outer_ix = Instruction(MY_PROGRAM, bytes([7, 1]), [])
+    msg = Message.new_with_blockhash([outer_ix], payer.pubkey(), Hash.default())
+    tx = Transaction.new_unsigned(msg)
+    logs = parse_logs(
+        [
+            f"Program {MY_PROGRAM} invoke [1]",
+            f"Program {sp.ID} invoke [2]",
+            f"Program {sp.ID} success",
+            f"Program {MY_PROGRAM} success",
+        ]
+    )
+    (root,) = build_instruction_tree_from_logs(tx, logs)
+    assert root.program_id == MY_PROGRAM
+    (child,) = root.children
+    assert child.program_id == sp.ID
+    assert child.stack_height == 2
+    assert child.data is None
@@ -0,0 +1,80 @@
+from base64 import b64encode
+
+from solders.log_parser import ParsedLogs, parse_logs
+from solders.pubkey import Pubkey
+from solders.transaction_status import UiTransactionStatusMeta
+
+OUTER = Pubkey.new_unique()
+TOKEN = Pubkey.from_string("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA")
+SYSTEM = Pubkey.from_string("11111111111111111111111111111111")
+
+LOGS = [
+    f"Program {OUTER} invoke [1]",
+    "Program log: Instruction: Swap",
+    f"Program {TOKEN} invoke [2]",
+    "Program log: Instruction: Transfer",
+    f"Program {TOKEN} consumed 4645 of 180000 compute units",
+    f"Program {TOKEN} success",
+    f"Program data: {b64encode(b'event').decode()} {b64encode(b'x').decode()}",
+    f"Program return: {OUTER} {b64encode(b'ret').decode()}",
+    f"Program {OUTER} consumed 25000 of 200000 compute units",
+    f"Program {OUTER} success",
+    f"Program {SYSTEM} invoke [1]",
+    f"Program {SYSTEM} success",
+]
+
+
+def test_parse_logs() -> None:
+    parsed = parse_logs(LOGS)
+    assert not parsed.truncated
+    assert parsed.success is True
+    assert parsed.other_logs == []
+    outer, system = parsed.invocations
+    assert outer.program_id == OUTER
+    assert outer.depth == 1
+    assert outer.logs == ["Instruction: Swap"]
+    assert outer.data == [[b"event", b"x"]]
+    assert outer.return_data == b"ret"
+    assert outer.compute_units_consumed == 25000
+    assert outer.compute_units_budget == 200000
+    (token,) = outer.inner
+    assert token.program_id == TOKEN
+    assert token.depth == 2
+    assert token.compute_units_consumed == 4645
+    assert token.success is True
+    assert system.compute_units_consumed is None
+    assert [ix.program_id for ix in parsed.flatten()] == [OUTER, TOKEN, SYSTEM]
+    assert parsed.program_logs() == ["Instruction: Swap", "Instruction: Transfer"]
+    assert parsed.program_logs(TOKEN) == ["Instruction: Transfer"]
+    assert ParsedLogs.from_bytes(bytes(parsed)) == parsed
+    assert ParsedLogs.from_json(parsed.to_json()) == parsed
+
+
+def test_parse_failed_and_truncated_logs() -> None:
+    logs = [
+        f"Program {OUTER} invoke [1]",
+        f"Program {TOKEN} invoke [2]",
+        "Program log: Error: insufficient funds",
+        f"Program {TOKEN} consumed 3000 of 190000 compute units",
+        f"Program {TOKEN} failed: custom program error: 0x1",
+        f"Program {OUTER} consumed 10000 of 200000 compute units",
+        f"Program {OUTER} failed: custom program error: 0x1",
+    ]
+    parsed = parse_logs(logs)
+    assert parsed.success is False
+    (outer,) = parsed.invocations
+    assert outer.error == "custom program error: 0x1"
+    assert outer.inner[0].success is False
+    truncated = parse_logs([f"Program {OUTER} invoke [1]", "Log truncated"])
+    assert truncated.truncated
+    assert truncated.success is None
+    assert truncated.invocations[0].success is None
+
+
+def test_parse_ui_meta_logs() -> None:
+    meta = UiTransactionStatusMeta(
+        err=None, fee=5000, pre_balances=[], post_balances=[], log_messages=LOGS
+    )
+    parsed = meta.parse_logs()
+    assert parsed is not None
+    assert parsed == parse_logs(LOGS)
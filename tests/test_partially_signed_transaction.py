@@ -0,0 +1,104 @@
+import json
+
+from pytest import raises
+from solders.errors import SignerError
+from solders.hash import Hash
+from solders.instruction import AccountMeta, Instruction
+from solders.keypair import Keypair
+from solders.message import MessageV0
+from solders.pubkey import Pubkey
+from solders.transaction import PartiallySignedTransaction, VersionedTransaction
+
+
+def _two_signer_message(payer: Keypair, cosigner: Keypair) -> MessageV0:
+    ix = Instruction(
+        Pubkey.new_unique(), b"", [AccountMeta(cosigner.pubkey(), True, False)]
+    )
+    return MessageV0.try_compile(payer.pubkey(), [ix], [], Hash.new_unique())
+
+
+def test_collect_signatures() -> None:
+    payer = Keypair()
+    cosigner = Keypair()
+    msg = _two_signer_message(payer, cosigner)
+    nonce_account = Pubkey.new_unique()
+    partial = PartiallySignedTransaction(msg, nonce_account, 1000)
+    assert partial.signers == [payer.pubkey(), cosigner.pubkey()]
+    assert partial.missing_signers() == partial.signers
+    partial.sign([payer])
+    assert partial.missing_signers() == [cosigner.pubkey()]
+    assert not partial.is_fully_signed()
+    with raises(SignerError):
+        partial.to_transaction()
+    received = PartiallySignedTransaction.from_bytes(bytes(partial))
+    assert received == partial
+    assert PartiallySignedTransaction.from_json(partial.to_json()) == partial
+    assert received.nonce_account == nonce_account
+    assert received.last_valid_block_height == 1000
+    received.sign([cosigner])
+    assert received.is_fully_signed()
+    tx = received.to_transaction()
+    assert tx == VersionedTransaction(msg, [payer, cosigner])
+    tx.verify_and_hash_message()
+
+
+def test_merge() -> None:
+    payer = Keypair()
+    cosigner = Keypair()
+    msg = _two_signer_message(payer, cosigner)
+    first = PartiallySignedTransaction(msg)
+    second = PartiallySignedTransaction(msg, last_valid_block_height=5)
+    first.sign([payer])
+    cosigner_sig = VersionedTransaction(msg, [payer, cosigner]).signatures[1]
+    second.add_signature(cosigner.pubkey(), cosigner_sig)
+    first.merge(second)
+    assert first.is_fully_signed()
+    assert first.last_valid_block_height == 5
+    other_msg = _two_signer_message(payer, cosigner)
+    with raises(SignerError):
+        first.merge(PartiallySignedTransaction(other_msg))
+
+
+def test_rejects_bad_signatures() -> None:
+    payer = Keypair()
+    cosigner = Keypair()
+    msg = _two_signer_message(payer, cosigner)
+    partial = PartiallySignedTransaction(msg)
+    with raises(SignerError):
+        partial.sign([Keypair()])
+    with raises(SignerError):
+        partial.add_signature(cosigner.pubkey(), cosigner.sign_message(b"foo"))
+    assert partial.signatures == [None, None]
+
+
+def test_from_transaction() -> None:
+    payer = Keypair()
+    cosigner = Keypair()
+    msg = _two_signer_message(payer, cosigner)
+    tx = VersionedTransaction(msg, [payer, cosigner])
+    sigs = tx.signatures
+    tx.signatures = [sigs[0], cosigner.sign_message(b"foo")]
+    partial = PartiallySignedTransaction.from_transaction(tx)
+    assert partial.signatures == [sigs[0], None]
+    assert partial.missing_signers() == [cosigner.pubkey()]
+
+
+def test_rejects_tampered_envelopes() -> None:
+    payer = Keypair()
+    cosigner = Keypair()
+    msg = _two_signer_message(payer, cosigner)
+    partial = PartiallySignedTransaction(msg)
+    partial.sign([payer])
+    raw = json.loads(partial.to_json())
+    dropped_slot = {**raw, "signatures": raw["signatures"][:1]}
+    stranger = list(bytes(Pubkey.new_unique()))
+    wrong_signer = {**raw, "signers": [raw["signers"][0], stranger]}
+    forged = {**raw, "signatures": [list(bytes(payer.sign_message(b"foo"))), None]}
+    for tampered in (dropped_slot, wrong_signer, forged):
+        with raises(ValueError):
+            PartiallySignedTransaction.from_json(json.dumps(tampered))
+    # the payer's signature is near the end of the bincode encoding
+    corrupted = bytearray(bytes(partial))
+    corrupted[-20] ^= 1
+    with raises(ValueError):
+        PartiallySignedTransaction.from_bytes(bytes(corrupted))
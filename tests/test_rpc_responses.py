@@ -17,6 +17,13 @@ from solders.rpc.errors import (
     InvalidParamsMessage,
     NodeUnhealthy,
     NodeUnhealthyMessage,
+    RpcBlockhashNotFound,
+    RpcError,
+    RpcInvalidParams,
+    RpcMinContextSlotNotReached,
+    RpcNodeUnhealthy,
+    RpcPreflightFailure,
+    RpcTransactionHistoryNotAvailable,
     SendTransactionPreflightFailureMessage,
 )
 from solders.rpc.responses import (
@@ -138,19 +145,24 @@ from solders.rpc.responses import (
 from solders.signature import Signature
 from solders.transaction import VersionedTransaction
 from solders.transaction_status import (
+    EncodedTransactionWithStatusMeta,
     InstructionErrorCustom,
+    ParsedAccountSource,
     ParsedInstruction,
     Reward,
     RewardType,
     TransactionConfirmationStatus,
+    TransactionDetails,
     TransactionErrorInstructionError,
     TransactionStatus,
     UiAccountsList,
     UiCompiledInstruction,
+    UiConfirmedBlock,
     UiLoadedAddresses,
     UiParsedMessage,
     UiRawMessage,
     UiTransaction,
+    UiTransactionEncoding,
     UiTransactionStatusMeta,
 )
 from solders.transaction_status import ParsedAccount as ParsedAccountTxStatus
@@ -1930,7 +1942,8 @@ def test_get_transaction(path: str) -> None:
         pre_token_balances=[],
         post_token_balances=[],
         rewards=[],
-        loaded_addresses=UiLoadedAddresses([], []),
+        # jsonParsed meta omits loaded addresses, which are in the account keys.
+        loaded_addresses=None if "parsed" in path else UiLoadedAddresses([], []),
         return_data=None,
         compute_units_consumed=None,
     )
@@ -1973,7 +1986,9 @@ def test_get_transaction(path: str) -> None:
             writable_vals = [True, True, False, False, False]
             signer_vals = [True, False, False, False, False]
             expected_parsed_accounts = [
-                ParsedAccountTxStatus(pubkey, writable, signer)
+                ParsedAccountTxStatus(
+                    pubkey, writable, signer, ParsedAccountSource.Transaction
+                )
                 for pubkey, writable, signer in zip(
                     expected_account_keys, writable_vals, signer_vals
                 )
@@ -2030,6 +2045,86 @@ def test_get_transaction(path: str) -> None:
         # don't need so many assertions here since we already have tests for Message
 
 
+def test_decode_transaction() -> None:
+    def load(path: str) -> EncodedTransactionWithStatusMeta:
+        raw = (Path(__file__).parent / "data" / path).read_text()
+        parsed = GetTransactionResp.from_json(raw)
+        assert parsed.value is not None
+        return parsed.value.transaction
+
+    from_json = load("get_transaction_json_encoding.json").decode_transaction()
+    from_base64 = load("get_transaction_base64_encoding.json").decode_transaction()
+    assert from_json == from_base64
+    assert all(from_json.verify_with_results())
+    with raises(ValueError, match="jsonParsed"):
+        load("get_transaction_json_parsed_encoding.json").decode_transaction()
+
+
+@mark.parametrize("encoding", ["json", "base64"])
+def test_encode_transaction(encoding: str) -> None:
+    raw = (
+        Path(__file__).parent / "data" / f"get_transaction_{encoding}_encoding.json"
+    ).read_text()
+    resp = GetTransactionResp.from_json(raw)
+    assert resp.value is not None
+    original = resp.value.transaction
+    ui_encoding = (
+        UiTransactionEncoding.Json if encoding == "json" else UiTransactionEncoding.Base64
+    )
+    encoded = EncodedTransactionWithStatusMeta.from_transaction(
+        original.decode_transaction(), original.meta, ui_encoding
+    )
+    assert encoded == original
+    parsed = EncodedTransactionWithStatusMeta.from_transaction(
+        original.decode_transaction(), original.meta, UiTransactionEncoding.JsonParsed
+    )
+    raw_parsed = (
+        Path(__file__).parent / "data" / "get_transaction_json_parsed_encoding.json"
+    ).read_text()
+    expected_parsed = GetTransactionResp.from_json(raw_parsed).value
+    assert expected_parsed is not None
+    assert parsed == expected_parsed.transaction
+    accounts_only = EncodedTransactionWithStatusMeta.from_transaction(
+        original.decode_transaction(),
+        original.meta,
+        ui_encoding,
+        transaction_details=TransactionDetails.Accounts,
+    )
+    assert isinstance(accounts_only.transaction, UiAccountsList)
+
+
+@mark.parametrize("encoding", ["json", "base64"])
+def test_encode_block(encoding: str) -> None:
+    raw = (
+        Path(__file__).parent / "data" / f"get_block_{encoding}_encoding.json"
+    ).read_text()
+    original = GetBlockResp.from_json(raw).value
+    assert original.transactions is not None
+    assert original.rewards is not None
+    ui_encoding = (
+        UiTransactionEncoding.Json if encoding == "json" else UiTransactionEncoding.Base64
+    )
+    txs = [(tx.decode_transaction(), tx.meta) for tx in original.transactions]
+
+    def encode(details: TransactionDetails) -> UiConfirmedBlock:
+        return UiConfirmedBlock.from_transactions(
+            original.previous_blockhash,
+            original.blockhash,
+            original.parent_slot,
+            txs,
+            original.rewards or [],
+            original.block_time,
+            original.block_height,
+            ui_encoding,
+            transaction_details=details,
+        )
+
+    assert encode(TransactionDetails.Full) == original
+    signatures_only = encode(TransactionDetails.Signatures)
+    assert signatures_only.transactions is None
+    assert signatures_only.signatures == [tx.signatures[0] for tx, _ in txs]
+
+
 def test_get_transaction_count() -> None:
     raw = '{ "jsonrpc": "2.0", "result": 268, "id": 1 }'
     parsed = GetTransactionCountResp.from_json(raw)
@@ -2633,3 +2728,46 @@ def test_parse_transaction_error() -> None:
     raw_full = '{"jsonrpc":"2.0","error":{"code":-32002,"message":"Transaction simulation failed: Attempt to debit an account but found no record of a prior credit.","data":{"accounts":null,"err":"AccountNotFound","logs":[],"unitsConsumed":0}},"id":0}'
     parsed_full = SimulateTransactionResp.from_json(raw_full)
     assert isinstance(parsed_full, SendTransactionPreflightFailureMessage)
+
+
+def test_raise_for_error() -> None:
+    raw_ok = '{ "jsonrpc": "2.0", "result": 1233, "id": 1 }'
+    ok = GetBlockHeightResp.from_json(raw_ok)
+    assert ok.raise_for_error() is ok
+    raw_invalid = '{"jsonrpc":"2.0","error":{"code":-32602,"message":"Invalid param: WrongSize"},"id":1}'
+    with raises(RpcInvalidParams, match="Invalid param: WrongSize") as invalid:
+        GetAccountInfoResp.from_json(raw_invalid).raise_for_error()
+    assert isinstance(invalid.value.error, InvalidParamsMessage)
+    assert invalid.value.data is None
+    raw_unhealthy = '{"jsonrpc":"2.0","error":{"code":-32005,"message":"Node is behind by 42 slots","data":{"numSlotsBehind":42}},"id":1}'
+    with raises(RpcNodeUnhealthy) as unhealthy:
+        GetHealthResp.from_json(raw_unhealthy).raise_for_error()
+    assert unhealthy.value.data == NodeUnhealthy(42)
+    raw_min_context = '{"jsonrpc":"2.0","error":{"code":-32016,"message":"Minimum context slot has not been reached","data":{"contextSlot":10}},"id":1}'
+    with raises(RpcMinContextSlotNotReached):
+        GetBalanceResp.from_json(raw_min_context).raise_for_error()
+    raw_history = '{"jsonrpc":"2.0","error":{"code":-32011,"message":"Transaction history is not available from this node"},"id":1}'
+    with raises(RpcTransactionHistoryNotAvailable):
+        GetTransactionResp.from_json(raw_history).raise_for_error()
+    raw_preflight = '{"jsonrpc":"2.0","error":{"code":-32002,"message":"Transaction simulation failed: Attempt to debit an account but found no record of a prior credit.","data":{"accounts":null,"err":"AccountNotFound","logs":[],"unitsConsumed":0}},"id":0}'
+    with raises(RpcPreflightFailure) as preflight:
+        SendTransactionResp.from_json(raw_preflight).raise_for_error()
+    assert not isinstance(preflight.value, RpcBlockhashNotFound)
+    assert isinstance(preflight.value.data, RpcSimulateTransactionResult)
+    token = "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"
+    raw_custom = (
+        '{"jsonrpc":"2.0","error":{"code":-32002,"message":"Transaction simulation failed: '
+        'Error processing Instruction 0: custom program error: 0x1","data":{"accounts":null,'
+        '"err":{"InstructionError":[0,{"Custom":1}]},"logs":["Program %s invoke [1]",'
+        '"Program %s failed: custom program error: 0x1"],"unitsConsumed":0}},"id":0}'
+    ) % (token, token)
+    with raises(
+        RpcPreflightFailure,
+        match="Instruction 0: TokenError::InsufficientFunds: Insufficient funds",
+    ):
+        SendTransactionResp.from_json(raw_custom).raise_for_error()
+    raw_blockhash = '{"jsonrpc":"2.0","error":{"code":-32002,"message":"Transaction simulation failed: Blockhash not found","data":{"accounts":null,"err":"BlockhashNotFound","logs":[],"unitsConsumed":0}},"id":0}'
+    with raises(RpcBlockhashNotFound) as blockhash:
+        SendTransactionResp.from_json(raw_blockhash).raise_for_error()
+    assert isinstance(blockhash.value, RpcPreflightFailure)
+    assert isinstance(blockhash.value, RpcError)
@@ -1,7 +1,9 @@
 import base64
 from typing import List
 
+from pytest import raises
 from solders import system_program as sp
+from solders.errors import InstructionDecodeError
 from solders.hash import Hash
 from solders.instruction import Instruction
 from solders.keypair import Keypair
@@ -644,3 +646,107 @@ def test_advance_nonce_and_transfer() -> None:
     assert txn == js_expected_txn
     # XXX:  Cli message serialization do not sort on account metas producing discrepency
     # assert txn == cli_expected_txn
+
+
+def test_nonce_data() -> None:
+    authority = Pubkey.new_unique()
+    nonce = Hash.new_unique()
+    raw = (
+        (1).to_bytes(4, "little")  # Versions::Current
+        + (1).to_bytes(4, "little")  # State::Initialized
+        + bytes(authority)
+        + bytes(nonce)
+        + (5000).to_bytes(8, "little")
+    )
+    assert len(raw) == sp.NONCE_STATE_SIZE
+    data = sp.NonceData.from_bytes(raw)
+    assert data == sp.NonceData(authority, nonce, 5000)
+    assert bytes(data) == raw
+    assert data.authority == authority
+    assert data.durable_nonce == nonce
+    assert data.lamports_per_signature == 5000
+    assert not data.is_legacy
+    assert data.verify_recent_blockhash(nonce)
+    assert not data.verify_recent_blockhash(Hash.new_unique())
+    legacy = sp.NonceData.from_bytes(bytes(4) + raw[4:])
+    assert legacy.is_legacy
+    assert not legacy.verify_recent_blockhash(nonce)
+    with raises(ValueError):
+        sp.NonceData.from_bytes((1).to_bytes(4, "little") + bytes(76))
+
+
+def test_new_signed_with_nonce() -> None:
+    payer = Keypair()
+    nonce_account = Pubkey.new_unique()
+    nonce_data = sp.NonceData(payer.pubkey(), Hash.new_unique(), 5000)
+    to = Pubkey.new_unique()
+    ix = sp.transfer(
+        sp.TransferParams(from_pubkey=payer.pubkey(), to_pubkey=to, lamports=1)
+    )
+    tx = Transaction.new_signed_with_nonce(
+        [ix],
+        payer.pubkey(),
+        [payer],
+        nonce_account,
+        payer.pubkey(),
+        nonce_data.durable_nonce,
+    )
+    tx.verify()
+    assert tx.uses_durable_nonce() is not None
+    assert nonce_data.verify_recent_blockhash(tx.message.recent_blockhash)
+    assert tx.message.program_id(0) == sp.ID
+    assert tx.key(0, 0) == nonce_account
+
+
+def test_decode_instruction() -> None:
+    transfer_params = sp.TransferParams(
+        from_pubkey=Pubkey.new_unique(), to_pubkey=Pubkey.new_unique(), lamports=5
+    )
+    upgrade_params = sp.UpgradeNonceAccountParams(nonce_pubkey=Pubkey.new_unique())
+    seed_params = sp.TransferWithSeedParams(
+        from_pubkey=Pubkey.new_unique(),
+        from_base=Pubkey.new_unique(),
+        from_seed="seed",
+        from_owner=Pubkey.new_unique(),
+        to_pubkey=Pubkey.new_unique(),
+        lamports=123,
+    )
+    cases = [
+        ("Transfer", sp.transfer(transfer_params), transfer_params),
+        (
+            "UpgradeNonceAccount",
+            sp.upgrade_nonce_account(upgrade_params),
+            upgrade_params,
+        ),
+        ("TransferWithSeed", sp.transfer_with_seed(seed_params), seed_params),
+    ]
+    for name, ix, params in cases:
+        assert sp.decode_instruction(ix) == (name, params)
+    assert sp.decode_upgrade_nonce_account(
+        sp.upgrade_nonce_account(upgrade_params)
+    ) == upgrade_params
+
+
+def test_decode_compiled_instruction() -> None:
+    payer = Keypair()
+    to = Pubkey.new_unique()
+    params = sp.TransferParams(from_pubkey=payer.pubkey(), to_pubkey=to, lamports=7)
+    msg = Message([sp.transfer(params)], payer.pubkey())
+    compiled = msg.instructions[0]
+    assert sp.decode_instruction(compiled, msg.account_keys) == ("Transfer", params)
+    with raises(InstructionDecodeError):
+        sp.decode_instruction(compiled)
+
+
+def test_decode_instruction_errors() -> None:
+    with raises(InstructionDecodeError):
+        sp.decode_instruction(Instruction(Pubkey.new_unique(), b"", []))
+    with raises(InstructionDecodeError):
+        sp.decode_instruction(Instruction(sp.ID, bytes([255, 0, 0, 0]), []))
+    transfer_data = sp.transfer(
+        sp.TransferParams(
+            from_pubkey=Pubkey.new_unique(), to_pubkey=Pubkey.new_unique(), lamports=1
+        )
+    ).data
+    with raises(InstructionDecodeError):
+        sp.decode_instruction(Instruction(sp.ID, transfer_data, []))